@@ -13,8 +13,17 @@ static ALLOCATOR: LockedHeap = LockedHeap::empty();
 
 pub fn init(free_memory_bottom: usize, free_memory_top: usize) {
     let heap_start = free_memory_bottom as *mut u8;
-    let heap_size = free_memory_top - free_memory_bottom;
+    // A bogus PHIT HOB must not wrap into a huge heap over memory in use
+    let heap_size = free_memory_top.checked_sub(free_memory_bottom).expect("PHIT free memory top is below its bottom.");
     unsafe {
         ALLOCATOR.lock().init(heap_start, heap_size);
     }
 }
+
+/// Returns the number of bytes still free on the heap. Failed allocations
+/// panic with the requested size, so this gives the other half of the picture.
+/// Returns `None` rather than spinning when the heap is locked, as it is called
+/// from the panic handler.
+pub fn remaining() -> Option<usize> {
+    ALLOCATOR.try_lock().map(|heap| heap.free())
+}
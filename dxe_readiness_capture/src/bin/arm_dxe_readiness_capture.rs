@@ -0,0 +1,95 @@
+//! Dxe Readiness Capture Tool - AArch64 with PL011 UART auto-detection
+//!
+//! Generic AArch64 binary for platforms whose PL011 UART base is not known in
+//! advance. The bases in `PL011_CANDIDATE_ADDRESSES` are probed in order and
+//! the first one whose identification registers read back as a PL011 is used.
+//! Add the UART base of a new platform to the list to have it detected.
+//!
+//! ## License
+//!
+//! Copyright (c) Microsoft Corporation.
+//!
+//! SPDX-License-Identifier: Apache-2.0
+//!
+
+// no_std and no_main are applicable only when building as an EFI application.
+// Tests/other std targets are built as normal Rust binaries, which require main
+// and link to std.
+#![cfg_attr(target_os = "uefi", no_std)]
+#![cfg_attr(target_os = "uefi", no_main)]
+
+cfg_if::cfg_if! {
+    if #[cfg(all(target_os = "uefi", target_arch = "aarch64"))] {
+        use log::LevelFilter;
+        use core::sync::atomic::{AtomicPtr, Ordering};
+        use patina::{log::{Format, SerialLogger}, serial::uart::UartPl011};
+        use core::ffi::c_void;
+        use dxe_readiness_capture::core_start;
+
+        static mut LOGGER: Option<SerialLogger<UartPl011>> = None;
+
+        const PL011_CANDIDATE_ADDRESSES: &[usize] = &[
+            0x0900_0000, // QEMU virt
+            0x6000_0000, // QEMU SBSA
+            0x1C09_0000, // Arm FVP and Juno motherboard UART0
+            0x7FF8_0000, // Juno SoC UART
+        ];
+        // Used when no candidate identifies as a PL011, so that a platform
+        // with a UART at a listed base but without identification registers
+        // still gets its log.
+        const FALLBACK_UART_ADDRESS: usize = 0x0900_0000;
+
+        // UARTPeriphID0-3 and UARTPCellID0-3, one byte per 32-bit register.
+        const PL011_PERIPH_ID_REG: usize = 0xFE0;
+        const PL011_PERIPH_ID: [u32; 3] = [0x11, 0x10, 0x04]; // Part 0x011, designer 0x41 (Arm)
+        const PL011_PERIPH_ID_MASK: [u32; 3] = [0xFF, 0xFF, 0x0F]; // Revision is not checked
+        const PL011_PCELL_ID_REG: usize = 0xFF0;
+        const PL011_PCELL_ID: [u32; 4] = [0x0D, 0xF0, 0x05, 0xB1];
+
+        fn read_id_reg(mmio_base: usize, offset: usize) -> u32 {
+            let register: AtomicPtr<u32> = AtomicPtr::new((mmio_base + offset) as *mut u32);
+            unsafe { core::ptr::read_volatile(register.load(Ordering::Relaxed)) & 0xFF }
+        }
+
+        fn is_pl011(mmio_base: usize) -> bool {
+            let periph_id_matches = PL011_PERIPH_ID.iter().zip(PL011_PERIPH_ID_MASK).enumerate().all(|(i, (id, mask))| {
+                read_id_reg(mmio_base, PL011_PERIPH_ID_REG + i * 4) & mask == *id
+            });
+            periph_id_matches
+                && PL011_PCELL_ID.iter().enumerate().all(|(i, id)| read_id_reg(mmio_base, PL011_PCELL_ID_REG + i * 4) == *id)
+        }
+
+        fn detect_uart_address() -> Option<usize> {
+            PL011_CANDIDATE_ADDRESSES.iter().copied().find(|&mmio_base| is_pl011(mmio_base))
+        }
+
+        #[allow(static_mut_refs)]
+        fn init_logger(uart_address: usize) {
+            let logger_ref: &'static SerialLogger<'static, UartPl011> = unsafe {
+                LOGGER = Some(SerialLogger::new(
+                    Format::Standard,
+                    &[],
+                    log::LevelFilter::Trace,
+                    UartPl011::new(uart_address),
+                ));
+                LOGGER.as_ref().unwrap()
+            };
+            let _ = log::set_logger(logger_ref).map(|()| log::set_max_level(LevelFilter::Info));
+        }
+
+        #[unsafe(export_name = "efi_main")]
+        pub extern "efiapi" fn _start(physical_hob_list: *const c_void) -> ! {
+            let uart_address = detect_uart_address();
+            init_logger(uart_address.unwrap_or(FALLBACK_UART_ADDRESS));
+            match uart_address {
+                Some(uart_address) => log::info!("PL011 UART detected at 0x{:X}", uart_address),
+                None => log::warn!("No PL011 UART detected, using 0x{:X}", FALLBACK_UART_ADDRESS),
+            }
+            core_start(physical_hob_list, "ARM");
+            log::info!("Dead Loop");
+            loop { core::hint::spin_loop(); }
+        }
+    } else {
+        fn main() {}
+    }
+}
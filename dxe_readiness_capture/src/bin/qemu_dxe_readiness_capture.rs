@@ -35,7 +35,7 @@ cfg_if::cfg_if! {
         #[unsafe(export_name = "efi_main")]
         pub extern "efiapi" fn _start(physical_hob_list: *const c_void) -> ! {
             init_logger();
-            core_start(physical_hob_list);
+            core_start(physical_hob_list, "QEMU SBSA");
             log::info!("Dead Loop");
             loop { core::hint::spin_loop(); }
         }
@@ -60,7 +60,7 @@ cfg_if::cfg_if! {
         #[unsafe(export_name = "efi_main")]
         pub extern "efiapi" fn _start(physical_hob_list: *const c_void) -> ! {
             init_logger();
-            core_start(physical_hob_list);
+            core_start(physical_hob_list, "QEMU Q35");
             log::info!("Dead Loop");
             loop { core::hint::spin_loop(); }
         }
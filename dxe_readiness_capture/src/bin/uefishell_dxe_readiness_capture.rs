@@ -33,7 +33,7 @@ cfg_if::cfg_if! {
             system::with_config_table(|t| {
                 let config_entry = t.iter().find(|ct| ct.guid == hob_list_guid).unwrap();
                 let physical_hob_list = config_entry.address;
-                core_start(physical_hob_list);
+                core_start(physical_hob_list, "UEFI Shell");
             });
 
             Status::SUCCESS
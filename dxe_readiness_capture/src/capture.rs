@@ -15,23 +15,79 @@
 mod fv;
 mod hob;
 
+use base64::{Engine, engine::general_purpose::STANDARD};
 use core::{ffi::c_void, mem, str};
 use patina::pi::serializable::{serializable_fv::FirmwareVolumeSerDe, serializable_hob::HobSerDe};
 
-use crate::{CaptureResult, DxeReadinessCaptureSerDe, allocator};
+use crate::{CaptureMetadata, CaptureResult, DxeReadinessCaptureSerDe, SCHEMA_VERSION, allocator};
 use alloc::{
     format,
     string::{String, ToString},
     vec::Vec,
 };
-use patina::pi::hob::{HANDOFF, HobList, PhaseHandoffInformationTable, header, header::Hob};
+use patina::pi::{
+    BootMode,
+    hob::{HANDOFF, HobList, PhaseHandoffInformationTable, header, header::Hob},
+};
+
+/// JSON fragment of the capture in progress: the HOB list followed by the FVs
+/// parsed so far. The panic handler logs it, so that a fault while parsing an
+/// FV still leaves the HOB list and the FVs before it in the log.
+static PARTIAL_CAPTURE: spin::Mutex<Option<String>> = spin::Mutex::new(None);
+
+/// Appends the JSON of an FV to the capture in progress.
+pub(crate) fn record_partial_fv(fv: &FirmwareVolumeSerDe) {
+    if let Some(partial) = PARTIAL_CAPTURE.lock().as_mut()
+        && let Ok(fv_json) = serde_json::to_string(fv)
+    {
+        if !partial.ends_with('[') {
+            partial.push(',');
+        }
+        partial.push_str(&fv_json);
+    }
+}
+
+/// Logs the capture in progress, if any. The lock is only tried, as the panic
+/// may have happened while it was held.
+pub(crate) fn log_partial_capture() {
+    match PARTIAL_CAPTURE.try_lock() {
+        Some(partial) => {
+            if let Some(partial) = partial.as_ref() {
+                log::error!("Partial capture: {}", partial);
+            }
+        }
+        None => log::error!("Partial capture is locked and cannot be logged"),
+    }
+}
+
+/// Returns whether the validator checks HOBs of this type. The validation
+/// relevant types are Handoff, Resource Descriptor (V1 and V2), Memory
+/// Allocation, CPU, Firmware Volume, GUID Extension and Memory Type
+/// Information. An `UnknownHob` carries nothing the validator can check, and
+/// its type and length are recorded in `unknown_hobs` regardless. With the
+/// `filter_hobs` feature, only validation relevant HOBs are captured.
+pub(crate) fn is_validation_relevant(hob: &HobSerDe) -> bool {
+    match hob {
+        HobSerDe::Handoff { .. }
+        | HobSerDe::ResourceDescriptor(_)
+        | HobSerDe::ResourceDescriptorV2 { .. }
+        | HobSerDe::MemoryAllocation { .. }
+        | HobSerDe::Cpu { .. }
+        | HobSerDe::FirmwareVolume { .. }
+        | HobSerDe::GuidExtension { .. }
+        | HobSerDe::MemoryTypeInformation { .. } => true,
+        HobSerDe::UnknownHob => false,
+    }
+}
 
 pub struct CaptureApp<'a> {
+    pub(crate) physical_hob_list: *const c_void,
     pub(crate) hob_list: HobList<'a>,
+    platform_name: &'a str,
 }
 
-impl CaptureApp<'_> {
-    pub fn new(physical_hob_list: *const c_void) -> Self {
+impl<'a> CaptureApp<'a> {
+    pub fn new(physical_hob_list: *const c_void, platform_name: &'a str) -> Self {
         let (free_memory_bottom, free_memory_top) =
             Self::read_phit_hob(physical_hob_list).expect("PHIT HOB was not found.");
 
@@ -45,7 +101,7 @@ impl CaptureApp<'_> {
         let mut hob_list = HobList::default();
         hob_list.discover_hobs(physical_hob_list);
 
-        Self { hob_list }
+        Self { physical_hob_list, hob_list, platform_name }
     }
 
     fn read_phit_hob(physical_hob_list: *const c_void) -> Option<(usize, usize)> {
@@ -73,12 +129,111 @@ impl CaptureApp<'_> {
         assert_eq!(hob_len, hob_size, "Trying to cast hob of length {hob_len} into a pointer of size {hob_size}");
     }
 
+    /// Returns the boot mode of the PHIT HOB in readable form. It is read as a
+    /// raw value, as a platform may store a mode `BootMode` does not define.
+    fn boot_mode(&self) -> Option<String> {
+        self.hob_list.iter().find_map(|hob| match hob {
+            patina::pi::hob::Hob::Handoff(phit_hob) => {
+                let raw_mode = unsafe { core::ptr::addr_of!(phit_hob.boot_mode).cast::<u32>().read_unaligned() };
+                Some(match BootMode::try_from(raw_mode) {
+                    Ok(boot_mode) => boot_mode.to_string(),
+                    Err(()) => format!("Unknown ({:#x})", raw_mode),
+                })
+            }
+            _ => None,
+        })
+    }
+
+    /// Returns the current time. Only the UEFI shell application can read it,
+    /// as the runtime services are not available when replacing the DXE core.
+    fn timestamp() -> Option<String> {
+        #[cfg(feature = "uefishell")]
+        return uefi::runtime::get_time().ok().map(|time| time.to_string());
+        #[cfg(not(feature = "uefishell"))]
+        return None;
+    }
+
+    /// Captures the HOB and FV lists and serializes them into pretty JSON.
     pub fn capture(&self) -> CaptureResult<String> {
+        let capture = self.capture_data()?;
+        let json = serde_json::to_string_pretty(&capture)
+            .map_err(|err| format!("Failed to serialize the capture data into JSON: {}", err))?;
+        #[cfg(feature = "self_check")]
+        Self::self_check("JSON", serde_json::from_str::<DxeReadinessCaptureSerDe>(&json));
+        Ok(json)
+    }
+
+    /// Captures the HOB and FV lists and serializes them into CBOR, base64
+    /// encoded so that the capture can be written to a text log on one line.
+    pub fn capture_cbor(&self) -> CaptureResult<String> {
+        let capture = self.capture_data()?;
+        let bytes =
+            capture.to_cbor().map_err(|err| format!("Failed to serialize the capture data into CBOR: {}", err))?;
+        #[cfg(feature = "self_check")]
+        Self::self_check("CBOR", ciborium::from_reader::<DxeReadinessCaptureSerDe, _>(bytes.as_slice()));
+        Ok(STANDARD.encode(bytes))
+    }
+
+    /// Captures the HOB and FV lists into `buf` instead of a log, for platforms
+    /// without a usable serial port. The capture is compact JSON, or raw CBOR
+    /// with the cbor feature. Returns the number of bytes written. When `buf`
+    /// is too small, it is left untouched and the error gives the needed size.
+    pub fn capture_to_buffer(&self, buf: &mut [u8]) -> CaptureResult<usize> {
+        let capture = self.capture_data()?;
+        #[cfg(feature = "cbor")]
+        let bytes =
+            capture.to_cbor().map_err(|err| format!("Failed to serialize the capture data into CBOR: {}", err))?;
+        #[cfg(not(feature = "cbor"))]
+        let bytes = serde_json::to_vec(&capture)
+            .map_err(|err| format!("Failed to serialize the capture data into JSON: {}", err))?;
+        #[cfg(all(feature = "self_check", feature = "cbor"))]
+        Self::self_check("CBOR", ciborium::from_reader::<DxeReadinessCaptureSerDe, _>(bytes.as_slice()));
+        #[cfg(all(feature = "self_check", not(feature = "cbor")))]
+        Self::self_check("JSON", serde_json::from_slice::<DxeReadinessCaptureSerDe>(&bytes));
+
+        let Some(dest) = buf.get_mut(..bytes.len()) else {
+            return Err(format!(
+                "Capture buffer is too small: {} bytes needed, {} bytes available",
+                bytes.len(),
+                buf.len()
+            ));
+        };
+        dest.copy_from_slice(&bytes);
+        Ok(bytes.len())
+    }
+
+    /// Logs a warning when the serialized capture did not deserialize back,
+    /// as the validator would then reject it. Only built with the `self_check`
+    /// feature, which catches such capture bugs on the device.
+    #[cfg(feature = "self_check")]
+    fn self_check<E: core::fmt::Debug>(format: &str, round_trip: Result<DxeReadinessCaptureSerDe, E>) {
+        match round_trip {
+            Ok(_) => log::info!("Self check: the {} capture deserializes", format),
+            Err(err) => log::warn!("Self check failed: the {} capture does not deserialize: {:?}", format, err),
+        }
+    }
+
+    fn capture_data(&self) -> CaptureResult<DxeReadinessCaptureSerDe> {
         let serializable_hob_list: Vec<HobSerDe> = self.capture_hob()?;
+        *PARTIAL_CAPTURE.lock() = serde_json::to_string(&serializable_hob_list)
+            .ok()
+            .map(|hob_json| format!("{{\"schema_version\":{},\"hob_list\":{},\"fv_list\":[", SCHEMA_VERSION, hob_json));
         let serializable_fv_list: Vec<FirmwareVolumeSerDe> = self.capture_fv()?;
-
-        let capture = DxeReadinessCaptureSerDe { hob_list: serializable_hob_list, fv_list: serializable_fv_list };
-        serde_json::to_string_pretty(&capture)
-            .map_err(|err| format!("Failed to serialize the capture data into JSON: {}", err))
+        *PARTIAL_CAPTURE.lock() = None;
+        let pe_characteristics = self.capture_pe_characteristics(&serializable_fv_list);
+
+        let mut capture = DxeReadinessCaptureSerDe {
+            schema_version: SCHEMA_VERSION,
+            metadata: Some(CaptureMetadata::new(self.platform_name, Self::timestamp(), self.boot_mode())),
+            hob_list: serializable_hob_list,
+            fv_list: serializable_fv_list,
+            checksum: None,
+            unknown_hobs: self.capture_unknown_hobs(),
+            guid_hob_data: self.capture_guid_hob_data(),
+            pe_characteristics,
+        };
+        capture.checksum =
+            Some(capture.compute_checksum().map_err(|err| format!("Failed to compute the capture checksum: {}", err))?);
+        Ok(capture)
     }
 }
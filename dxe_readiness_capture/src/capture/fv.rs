@@ -6,10 +6,32 @@
 //!
 //! SPDX-License-Identifier: Apache-2.0
 //!
-use alloc::vec::Vec;
-use patina::pi::{fw_fs::FirmwareVolume, hob::Hob, serializable::serializable_fv::FirmwareVolumeSerDe};
+use alloc::{string::ToString, vec::Vec};
+use patina::pi::{
+    fw_fs::{FfsSectionType, FirmwareVolume},
+    hob::Hob,
+    serializable::serializable_fv::FirmwareVolumeSerDe,
+};
 
-use crate::{CaptureResult, capture::CaptureApp};
+use crate::{
+    CaptureResult, PeCharacteristicsSerDe,
+    capture::{CaptureApp, record_partial_fv},
+};
+
+/// Section type recorded for Pe32 sections whose PE header could not be parsed.
+const UNPARSEABLE_PE32_SECTION_TYPE: &str = "Pe32 (unparseable)";
+
+/// Relabels Pe32 sections that carry no `pe_info`. The conversion from
+/// `FirmwareVolume` leaves `pe_info` empty when the image cannot be parsed
+/// (e.g. a truncated optional header), so these sections are marked to keep
+/// them apart from well formed images in the capture.
+fn mark_unparseable_pe32_sections(fv: &mut FirmwareVolumeSerDe) {
+    for section in fv.files.iter_mut().flat_map(|file| file.sections.iter_mut()) {
+        if section.section_type == "Pe32" && section.pe_info.is_none() {
+            section.section_type = UNPARSEABLE_PE32_SECTION_TYPE.to_string();
+        }
+    }
+}
 
 impl CaptureApp<'_> {
     pub(crate) fn capture_fv(&self) -> CaptureResult<Vec<FirmwareVolumeSerDe>> {
@@ -18,10 +40,19 @@ impl CaptureApp<'_> {
             .iter()
             .filter_map(|hob| {
                 if let &Hob::FirmwareVolume(&fv) = hob {
-                    let mut fv_serde = FirmwareVolumeSerDe::from(
-                        unsafe { FirmwareVolume::new_from_address(fv.base_address) }.unwrap(),
-                    );
+                    // A malformed FV (e.g. a partially programmed flash region) must not
+                    // abort the capture, so it is skipped.
+                    let firmware_volume = match unsafe { FirmwareVolume::new_from_address(fv.base_address) } {
+                        Ok(firmware_volume) => firmware_volume,
+                        Err(status) => {
+                            log::warn!("Skipping invalid FV at 0x{:X}: {:?}", fv.base_address, status);
+                            return None;
+                        }
+                    };
+                    let mut fv_serde = FirmwareVolumeSerDe::from(firmware_volume);
                     fv_serde.fv_base_address = fv.base_address;
+                    mark_unparseable_pe32_sections(&mut fv_serde);
+                    record_partial_fv(&fv_serde);
                     Some(fv_serde)
                 } else {
                     None
@@ -31,4 +62,39 @@ impl CaptureApp<'_> {
 
         Ok(fv_list)
     }
+
+    /// Returns the COFF characteristics of every PE32 section of `fv_list`
+    /// that has a `pe_info`. `PeHeaderInfo` leaves them out, so the FVs are
+    /// walked again in the order `FirmwareVolumeSerDe::from` lists their files
+    /// and sections, skipping the same unreadable entries, so that the indices
+    /// match `fv_list`.
+    pub(crate) fn capture_pe_characteristics(&self, fv_list: &[FirmwareVolumeSerDe]) -> Vec<PeCharacteristicsSerDe> {
+        let mut pe_characteristics = Vec::new();
+        for fv_serde in fv_list {
+            let Ok(firmware_volume) = (unsafe { FirmwareVolume::new_from_address(fv_serde.fv_base_address) }) else {
+                continue;
+            };
+            for (file_index, file) in firmware_volume.file_iter().filter_map(Result::ok).enumerate() {
+                for (section_index, section) in file.section_iter().filter_map(Result::ok).enumerate() {
+                    let has_pe_info = fv_serde
+                        .files
+                        .get(file_index)
+                        .and_then(|file| file.sections.get(section_index))
+                        .is_some_and(|section| section.pe_info.is_some());
+                    if !has_pe_info || section.section_type() != Some(FfsSectionType::Pe32) {
+                        continue;
+                    }
+                    if let Ok(pe) = goblin::pe::PE::parse(section.section_data()) {
+                        pe_characteristics.push(PeCharacteristicsSerDe {
+                            fv_base_address: fv_serde.fv_base_address,
+                            file_index,
+                            section_index,
+                            characteristics: pe.header.coff_header.characteristics,
+                        });
+                    }
+                }
+            }
+        }
+        pe_characteristics
+    }
 }
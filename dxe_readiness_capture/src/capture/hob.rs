@@ -7,13 +7,80 @@
 //! SPDX-License-Identifier: Apache-2.0
 //!
 use alloc::vec::Vec;
-use patina::pi::serializable::serializable_hob::HobSerDe;
+use patina::pi::{
+    hob::{Hob, header},
+    serializable::{format_guid, serializable_hob::HobSerDe},
+};
 
-use crate::{CaptureResult, capture::CaptureApp};
+use crate::{
+    CaptureResult, GuidHobDataSerDe, UnknownHobSerDe,
+    capture::{CaptureApp, is_validation_relevant},
+};
 
 impl CaptureApp<'_> {
+    /// Serializes the HOB list. With the `filter_hobs` feature, HOBs the
+    /// validator does not check are left out to shorten the capture.
     pub(crate) fn capture_hob(&self) -> CaptureResult<Vec<HobSerDe>> {
-        let fv_list: Vec<HobSerDe> = self.hob_list.iter().map(HobSerDe::from).collect();
+        let fv_list: Vec<HobSerDe> = self
+            .hob_list
+            .iter()
+            .map(HobSerDe::from)
+            .filter(|hob| cfg!(not(feature = "filter_hobs")) || is_validation_relevant(hob))
+            .collect();
         Ok(fv_list)
     }
+
+    /// Returns the header of every HOB that `capture_hob` records as
+    /// `UnknownHob`, so that the type codes the tool does not understand yet
+    /// are not lost.
+    pub(crate) fn capture_unknown_hobs(&self) -> Vec<UnknownHobSerDe> {
+        self.read_hob_headers()
+            .into_iter()
+            .filter(|(hob, _)| matches!(HobSerDe::from(*hob), HobSerDe::UnknownHob))
+            .map(|(_, header)| UnknownHobSerDe { hob_type: header.r#type, length: header.length })
+            .collect()
+    }
+
+    /// Returns the data payload of every GUID Extension HOB, so that the
+    /// validator can inspect GUID HOB contents. The Memory Type Information
+    /// HOB is included, as `MemoryTypeInformation` drops every entry from the
+    /// first one with an out of range memory type.
+    pub(crate) fn capture_guid_hob_data(&self) -> Vec<GuidHobDataSerDe> {
+        self.hob_list
+            .iter()
+            .filter_map(|hob| match hob {
+                Hob::GuidHob(guid_hob, data) => {
+                    Some(GuidHobDataSerDe { name: format_guid(&guid_hob.name), data: data.to_vec() })
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Pairs every HOB of `hob_list` with its header. `Hob::Misc` only keeps
+    /// the type of a HOB, so its length has to be read from the HOB list
+    /// itself. The walk stops at the end of `hob_list`, on a header that does
+    /// not match its entry, and on the same malformed lengths `discover_hobs`
+    /// stops on, so it never reads past the HOBs that were discovered.
+    fn read_hob_headers(&self) -> Vec<(&Hob<'_>, header::Hob)> {
+        let mut headers = Vec::with_capacity(self.hob_list.len());
+        let mut hob_header = self.physical_hob_list as *const header::Hob;
+        for hob in self.hob_list.iter() {
+            let current_header = match hob {
+                Hob::Misc(_) => unsafe { *hob_header },
+                _ => hob.header(),
+            };
+            if current_header.r#type != hob.header().r#type {
+                break;
+            }
+            headers.push((hob, current_header));
+
+            let length = current_header.length as usize;
+            match (hob_header as usize).checked_add(length) {
+                Some(next_hob) if length >= size_of::<header::Hob>() => hob_header = next_hob as *const header::Hob,
+                _ => break,
+            }
+        }
+        headers
+    }
 }
@@ -22,46 +22,203 @@ cfg_if::cfg_if! {
         use core::{ffi::c_void, panic::PanicInfo};
         use patina_stacktrace::StackTrace;
         use capture::CaptureApp;
-        use alloc::string::String;
 
         pub type CaptureResult<T> = Result<T, String>;
 
         #[panic_handler]
         fn panic(info: &PanicInfo) -> ! {
             log::error!("{}", info);
+            if cfg!(not(feature = "uefishell")) && let Some(remaining) = allocator::remaining() {
+                log::error!("Heap bytes remaining: {}", remaining);
+            }
 
             if let Err(err) = unsafe { StackTrace::dump() } {
                 log::error!("StackTrace: {}", err);
             }
+            capture::log_partial_capture();
 
             loop { core::hint::spin_loop(); }
         }
 
         // Called by platform-specific binaries after initializing the logger.
-        pub fn core_start(physical_hob_list: *const c_void) {
+        // `platform_name` is recorded in the capture metadata.
+        pub fn core_start(physical_hob_list: *const c_void, platform_name: &str) {
             log::info!("Dxe Readiness Capture Tool");
 
-            let app = CaptureApp::new(physical_hob_list);
+            let app = CaptureApp::new(physical_hob_list, platform_name);
+
+            // The cbor feature trades the readable JSON for a much smaller
+            // base64 encoded CBOR capture, for platforms with a slow UART.
+            #[cfg(feature = "cbor")]
+            let capture = app.capture_cbor();
+            #[cfg(not(feature = "cbor"))]
+            let capture = app.capture();
 
-            if let Ok(json_str) = app.capture() {
-                log::info!("{}", json_str);
+            if let Ok(capture_str) = capture {
+                log::info!("{}", capture_str);
             } else {
                 log::info!("Failed to dump HOB list to JSON");
             }
         }
+
+        // Alternative to `core_start` for platforms that read the capture back
+        // from memory. Writes the capture into `buf` and returns its length.
+        pub fn capture_to_buffer(physical_hob_list: *const c_void, platform_name: &str, buf: &mut [u8]) -> CaptureResult<usize> {
+            log::info!("Dxe Readiness Capture Tool");
+
+            let app = CaptureApp::new(physical_hob_list, platform_name);
+            app.capture_to_buffer(buf)
+        }
     }
 }
 
 extern crate alloc;
 
-use alloc::vec::Vec;
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
 use patina::pi::serializable::{serializable_fv::FirmwareVolumeSerDe, serializable_hob::HobSerDe};
 use serde::{Deserialize, Serialize};
 
+/// Version of the capture format. Bump this whenever a change to the format
+/// would keep an older validator from reading the capture correctly.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Prefix of the checksums produced by `DxeReadinessCaptureSerDe::compute_checksum`,
+/// naming the algorithm used.
+const CHECKSUM_PREFIX: &str = "fnv1a-64:";
+
+/// Describes what produced a capture, so that captures of different platforms
+/// can be told apart when triaging them.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct CaptureMetadata {
+    pub tool_version: String,
+    pub target_arch: String,
+    pub platform_name: String,
+    // Time of the capture, only known when the platform provides a clock
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<String>,
+    // Boot mode of the PHIT HOB, e.g. "Boot On S3 Resume (0x11)". Absent in
+    // captures produced before it was recorded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub boot_mode: Option<String>,
+}
+
+impl CaptureMetadata {
+    pub fn new(platform_name: &str, timestamp: Option<String>, boot_mode: Option<String>) -> Self {
+        let target_arch = if cfg!(target_arch = "x86_64") {
+            "x86_64"
+        } else if cfg!(target_arch = "aarch64") {
+            "aarch64"
+        } else {
+            "unknown"
+        };
+
+        Self {
+            tool_version: env!("CARGO_PKG_VERSION").to_string(),
+            target_arch: target_arch.to_string(),
+            platform_name: platform_name.to_string(),
+            timestamp,
+            boot_mode,
+        }
+    }
+}
+
+/// Raw header of a HOB that `HobSerDe` has no variant for and records as
+/// `UnknownHob`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownHobSerDe {
+    pub hob_type: u16,
+    // Length of the HOB including its header, 0 in captures produced before
+    // it was recorded
+    #[serde(default)]
+    pub length: u16,
+}
+
+/// Data payload of a GUID Extension HOB. `HobSerDe::GuidExtension` only keeps
+/// the GUID, so the payload is recorded alongside it.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct GuidHobDataSerDe {
+    pub name: String,
+    pub data: Vec<u8>,
+}
+
+/// COFF header characteristics of a parsed PE32 section, which
+/// `PeHeaderInfo` does not record. The section is identified by the base
+/// address of its FV and its position within `fv_list`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PeCharacteristicsSerDe {
+    #[serde(with = "patina::pi::serializable::hex_format")]
+    pub fv_base_address: u64,
+    // Index of the file in `FirmwareVolumeSerDe::files`
+    pub file_index: usize,
+    // Index of the section in `FirmwareFileSerDe::sections`
+    pub section_index: usize,
+    pub characteristics: u16,
+}
+
 /// This structure represents the actual capture data that will be serialized
 /// to JSON.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct DxeReadinessCaptureSerDe {
+    // Version of the capture format, 0 in captures produced before it was
+    // recorded
+    #[serde(default)]
+    pub schema_version: u32,
+    // Absent in captures produced by older versions of the capture tool
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<CaptureMetadata>,
     pub hob_list: Vec<HobSerDe>,
     pub fv_list: Vec<FirmwareVolumeSerDe>,
+    // Checksum of every other field of the capture, absent in captures
+    // produced by older versions of the capture tool
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<String>,
+    // Header of every HOB `HobSerDe` has no variant for, in HOB list order.
+    // Recorded even when the `filter_hobs` feature leaves the `UnknownHob`
+    // entries out of `hob_list`. Absent in captures produced by older versions
+    // of the capture tool
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unknown_hobs: Vec<UnknownHobSerDe>,
+    // Payload of every GUID Extension HOB, including the Memory Type
+    // Information HOB, in HOB list order. Absent in captures produced by older versions of the capture tool
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub guid_hob_data: Vec<GuidHobDataSerDe>,
+    // COFF characteristics of every PE32 section with a `pe_info` in
+    // `fv_list`. Absent in captures produced by older versions of the capture
+    // tool
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pe_characteristics: Vec<PeCharacteristicsSerDe>,
+}
+
+impl DxeReadinessCaptureSerDe {
+    /// Computes the checksum of the capture, minus the `checksum` field
+    /// itself, over its compact JSON serialization. It detects captures
+    /// corrupted in transport, e.g. when copied out of a serial log, and is not
+    /// meant to detect tampering.
+    pub fn compute_checksum(&self) -> Result<String, serde_json::Error> {
+        let mut value = serde_json::to_value(self)?;
+        if let Some(fields) = value.as_object_mut() {
+            fields.remove("checksum");
+        }
+        let canonical = serde_json::to_string(&value)?;
+        Ok(format!("{}{:016x}", CHECKSUM_PREFIX, fnv1a_64(canonical.as_bytes())))
+    }
+
+    /// Serializes the capture into CBOR (RFC 8949). It holds the same data as
+    /// the JSON form in a fraction of the size, which matters when the capture
+    /// is dumped over a slow UART.
+    pub fn to_cbor(&self) -> Result<Vec<u8>, String> {
+        let mut bytes = Vec::new();
+        ciborium::into_writer(self, &mut bytes).map_err(|err| err.to_string())?;
+        Ok(bytes)
+    }
+}
+
+/// 64-bit FNV-1a hash. Used for checksums and fingerprints because, unlike the
+/// std hashers, its output is guaranteed not to change between releases.
+pub fn fnv1a_64(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3))
 }
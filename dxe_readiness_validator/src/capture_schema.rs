@@ -0,0 +1,419 @@
+//! JSON Schema of the capture format, for platform teams that produce captures
+//! with their own tools instead of the capture EFI application.
+//!
+//! The capture types live in `patina` and `dxe_readiness_capture` and cannot
+//! derive `JsonSchema`, so they are mirrored here. The tests convert a capture
+//! into the mirror types, destructuring every field and variant, and check
+//! that both serialize to the same JSON, so the mirrors cannot drift from the
+//! capture types unnoticed.
+//!
+//! ## License
+//!
+//! Copyright (c) Microsoft Corporation.
+//!
+//! SPDX-License-Identifier: Apache-2.0
+//!
+// The mirror types are only constructed by the tests.
+#![allow(dead_code)]
+
+use schemars::{JsonSchema, schema_for};
+use serde::Serialize;
+
+/// A number serialized as a `0x` prefixed hexadecimal string.
+#[derive(Serialize, JsonSchema)]
+#[serde(transparent)]
+struct Hex(#[schemars(pattern(r"^0x[0-9a-fA-F]+$"))] String);
+
+/// DXE readiness capture.
+#[derive(Serialize, JsonSchema)]
+#[schemars(title = "DXE Readiness Capture")]
+struct DxeReadinessCaptureSerDe {
+    /// Version of the capture format, 0 when absent
+    #[serde(default)]
+    schema_version: u32,
+    /// What produced the capture
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    metadata: Option<CaptureMetadata>,
+    hob_list: Vec<HobSerDe>,
+    fv_list: Vec<FirmwareVolumeSerDe>,
+    /// Checksum of every other field of the capture, e.g. `fnv1a-64:0123456789abcdef`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    checksum: Option<String>,
+    /// Header of every HOB without a `hob_list` type, in HOB list order, also
+    /// when a filtered capture leaves the `unknown_hob` entries out of `hob_list`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    unknown_hobs: Vec<UnknownHobSerDe>,
+    /// Payload of every GUID Extension HOB, including the one parsed into
+    /// `memory_type_information`, in HOB list order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    guid_hob_data: Vec<GuidHobDataSerDe>,
+    /// COFF characteristics of every `Pe32` section with a `pe_info`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pe_characteristics: Vec<PeCharacteristicsSerDe>,
+}
+
+#[derive(Serialize, JsonSchema)]
+struct CaptureMetadata {
+    tool_version: String,
+    target_arch: String,
+    platform_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    timestamp: Option<String>,
+    /// Boot mode of the PHIT HOB, e.g. `Boot On S3 Resume (0x11)`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    boot_mode: Option<String>,
+}
+
+/// HOB, tagged with its type.
+#[derive(Serialize, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum HobSerDe {
+    Handoff {
+        version: u32,
+        memory_top: Hex,
+        memory_bottom: Hex,
+        free_memory_top: Hex,
+        free_memory_bottom: Hex,
+        end_of_hob_list: Hex,
+    },
+    MemoryAllocation {
+        alloc_descriptor: MemAllocDescriptorSerDe,
+    },
+    ResourceDescriptor(ResourceDescriptorSerDe),
+    ResourceDescriptorV2 {
+        v1: ResourceDescriptorSerDe,
+        attributes: u64,
+    },
+    GuidExtension {
+        name: String,
+    },
+    MemoryTypeInformation {
+        entries: Vec<MemoryTypeInfoEntrySerDe>,
+    },
+    FirmwareVolume {
+        base_address: Hex,
+        length: u64,
+    },
+    Cpu {
+        size_of_memory_space: u8,
+        size_of_io_space: u8,
+    },
+    UnknownHob,
+}
+
+#[derive(Serialize, JsonSchema)]
+struct UnknownHobSerDe {
+    hob_type: u16,
+    /// Length of the HOB including its header, 0 when not recorded
+    #[serde(default)]
+    length: u16,
+}
+
+#[derive(Serialize, JsonSchema)]
+struct GuidHobDataSerDe {
+    name: String,
+    data: Vec<u8>,
+}
+
+/// Identifies a section by the base address of its FV and its file and
+/// section index within `fv_list`.
+#[derive(Serialize, JsonSchema)]
+struct PeCharacteristicsSerDe {
+    fv_base_address: Hex,
+    file_index: usize,
+    section_index: usize,
+    characteristics: u16,
+}
+
+#[derive(Serialize, JsonSchema)]
+struct MemoryTypeInfoEntrySerDe {
+    memory_type: u32,
+    number_of_pages: u32,
+}
+
+#[derive(Serialize, JsonSchema)]
+struct MemAllocDescriptorSerDe {
+    name: String,
+    memory_base_address: Hex,
+    memory_length: u64,
+    memory_type: u32,
+}
+
+#[derive(Serialize, JsonSchema)]
+struct ResourceDescriptorSerDe {
+    owner: String,
+    resource_type: u32,
+    resource_attribute: Hex,
+    physical_start: Hex,
+    resource_length: Hex,
+}
+
+#[derive(Serialize, JsonSchema)]
+struct FirmwareVolumeSerDe {
+    fv_name: String,
+    fv_length: Hex,
+    fv_base_address: Hex,
+    fv_attributes: Hex,
+    files: Vec<FirmwareFileSerDe>,
+}
+
+#[derive(Serialize, JsonSchema)]
+struct FirmwareFileSerDe {
+    name: String,
+    file_type: String,
+    length: Hex,
+    attributes: Hex,
+    sections: Vec<FirmwareSectionSerDe>,
+}
+
+#[derive(Serialize, JsonSchema)]
+struct FirmwareSectionSerDe {
+    section_type: String,
+    length: Hex,
+    compression_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pe_info: Option<PeHeaderInfo>,
+}
+
+#[derive(Serialize, JsonSchema)]
+struct PeHeaderInfo {
+    section_alignment: u32,
+    machine: u16,
+    subsystem: u16,
+}
+
+/// Returns the JSON Schema (draft 2020-12) of a capture as pretty JSON.
+pub fn capture_json_schema() -> String {
+    serde_json::to_string_pretty(&schema_for!(DxeReadinessCaptureSerDe)).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dxe_readiness_capture as capture;
+    use patina::pi::serializable::{serializable_fv as fv, serializable_hob as hob};
+    use std::fmt::LowerHex;
+
+    const Q35_CAPTURE: &str = include_str!("tests/data/q35_capture.json");
+
+    fn hex(value: impl LowerHex) -> Hex {
+        Hex(format!("0x{:x}", value))
+    }
+
+    // The conversions destructure every field and match every variant, so a
+    // change to the capture types fails to compile until the mirrors follow.
+
+    impl From<&capture::DxeReadinessCaptureSerDe> for DxeReadinessCaptureSerDe {
+        fn from(value: &capture::DxeReadinessCaptureSerDe) -> Self {
+            let capture::DxeReadinessCaptureSerDe {
+                schema_version,
+                metadata,
+                hob_list,
+                fv_list,
+                checksum,
+                unknown_hobs,
+                guid_hob_data,
+                pe_characteristics,
+            } = value;
+            Self {
+                schema_version: *schema_version,
+                metadata: metadata.as_ref().map(Into::into),
+                hob_list: hob_list.iter().map(Into::into).collect(),
+                fv_list: fv_list.iter().map(Into::into).collect(),
+                checksum: checksum.clone(),
+                unknown_hobs: unknown_hobs
+                    .iter()
+                    .map(|&capture::UnknownHobSerDe { hob_type, length }| UnknownHobSerDe { hob_type, length })
+                    .collect(),
+                guid_hob_data: guid_hob_data
+                    .iter()
+                    .map(|capture::GuidHobDataSerDe { name, data }| GuidHobDataSerDe {
+                        name: name.clone(),
+                        data: data.clone(),
+                    })
+                    .collect(),
+                pe_characteristics: pe_characteristics
+                    .iter()
+                    .map(
+                        |&capture::PeCharacteristicsSerDe {
+                             fv_base_address,
+                             file_index,
+                             section_index,
+                             characteristics,
+                         }| PeCharacteristicsSerDe {
+                            fv_base_address: hex(fv_base_address),
+                            file_index,
+                            section_index,
+                            characteristics,
+                        },
+                    )
+                    .collect(),
+            }
+        }
+    }
+
+    impl From<&capture::CaptureMetadata> for CaptureMetadata {
+        fn from(value: &capture::CaptureMetadata) -> Self {
+            let capture::CaptureMetadata { tool_version, target_arch, platform_name, timestamp, boot_mode } =
+                value.clone();
+            Self { tool_version, target_arch, platform_name, timestamp, boot_mode }
+        }
+    }
+
+    impl From<&hob::HobSerDe> for HobSerDe {
+        fn from(value: &hob::HobSerDe) -> Self {
+            match value {
+                hob::HobSerDe::Handoff {
+                    version,
+                    memory_top,
+                    memory_bottom,
+                    free_memory_top,
+                    free_memory_bottom,
+                    end_of_hob_list,
+                } => Self::Handoff {
+                    version: *version,
+                    memory_top: hex(memory_top),
+                    memory_bottom: hex(memory_bottom),
+                    free_memory_top: hex(free_memory_top),
+                    free_memory_bottom: hex(free_memory_bottom),
+                    end_of_hob_list: hex(end_of_hob_list),
+                },
+                hob::HobSerDe::MemoryAllocation { alloc_descriptor } => {
+                    Self::MemoryAllocation { alloc_descriptor: alloc_descriptor.into() }
+                }
+                hob::HobSerDe::ResourceDescriptor(resource) => Self::ResourceDescriptor(resource.into()),
+                hob::HobSerDe::ResourceDescriptorV2 { v1, attributes } => {
+                    Self::ResourceDescriptorV2 { v1: v1.into(), attributes: *attributes }
+                }
+                hob::HobSerDe::GuidExtension { name } => Self::GuidExtension { name: name.clone() },
+                hob::HobSerDe::MemoryTypeInformation { entries } => Self::MemoryTypeInformation {
+                    entries: entries
+                        .iter()
+                        .map(|&hob::MemoryTypeInfoEntrySerDe { memory_type, number_of_pages }| {
+                            MemoryTypeInfoEntrySerDe { memory_type, number_of_pages }
+                        })
+                        .collect(),
+                },
+                hob::HobSerDe::FirmwareVolume { base_address, length } => {
+                    Self::FirmwareVolume { base_address: hex(base_address), length: *length }
+                }
+                hob::HobSerDe::Cpu { size_of_memory_space, size_of_io_space } => {
+                    Self::Cpu { size_of_memory_space: *size_of_memory_space, size_of_io_space: *size_of_io_space }
+                }
+                hob::HobSerDe::UnknownHob => Self::UnknownHob,
+            }
+        }
+    }
+
+    impl From<&hob::MemAllocDescriptorSerDe> for MemAllocDescriptorSerDe {
+        fn from(value: &hob::MemAllocDescriptorSerDe) -> Self {
+            let hob::MemAllocDescriptorSerDe { name, memory_base_address, memory_length, memory_type } = value;
+            Self {
+                name: name.clone(),
+                memory_base_address: hex(memory_base_address),
+                memory_length: *memory_length,
+                memory_type: *memory_type,
+            }
+        }
+    }
+
+    impl From<&hob::ResourceDescriptorSerDe> for ResourceDescriptorSerDe {
+        fn from(value: &hob::ResourceDescriptorSerDe) -> Self {
+            let hob::ResourceDescriptorSerDe {
+                owner,
+                resource_type,
+                resource_attribute,
+                physical_start,
+                resource_length,
+            } = value;
+            Self {
+                owner: owner.clone(),
+                resource_type: *resource_type,
+                resource_attribute: hex(resource_attribute),
+                physical_start: hex(physical_start),
+                resource_length: hex(resource_length),
+            }
+        }
+    }
+
+    impl From<&fv::FirmwareVolumeSerDe> for FirmwareVolumeSerDe {
+        fn from(value: &fv::FirmwareVolumeSerDe) -> Self {
+            let fv::FirmwareVolumeSerDe { fv_name, fv_length, fv_base_address, fv_attributes, files } = value;
+            Self {
+                fv_name: fv_name.clone(),
+                fv_length: hex(fv_length),
+                fv_base_address: hex(fv_base_address),
+                fv_attributes: hex(fv_attributes),
+                files: files.iter().map(Into::into).collect(),
+            }
+        }
+    }
+
+    impl From<&fv::FirmwareFileSerDe> for FirmwareFileSerDe {
+        fn from(value: &fv::FirmwareFileSerDe) -> Self {
+            let fv::FirmwareFileSerDe { name, file_type, length, attributes, sections } = value;
+            Self {
+                name: name.clone(),
+                file_type: file_type.clone(),
+                length: hex(length),
+                attributes: hex(attributes),
+                sections: sections.iter().map(Into::into).collect(),
+            }
+        }
+    }
+
+    impl From<&fv::FirmwareSectionSerDe> for FirmwareSectionSerDe {
+        fn from(value: &fv::FirmwareSectionSerDe) -> Self {
+            let fv::FirmwareSectionSerDe { section_type, length, compression_type, pe_info } = value;
+            Self {
+                section_type: section_type.clone(),
+                length: hex(length),
+                compression_type: compression_type.clone(),
+                pe_info: pe_info.map(|fv::PeHeaderInfo { section_alignment, machine, subsystem }| PeHeaderInfo {
+                    section_alignment,
+                    machine,
+                    subsystem,
+                }),
+            }
+        }
+    }
+
+    #[test]
+    fn test_mirror_types_serialize_like_the_capture() {
+        let mut capture: capture::DxeReadinessCaptureSerDe = serde_json::from_str(Q35_CAPTURE).unwrap();
+        // HOB types missing from the Q35 capture
+        capture.hob_list.extend([
+            hob::HobSerDe::ResourceDescriptor(hob::ResourceDescriptorSerDe {
+                owner: "00000000-0000-0000-0000-000000000000".to_string(),
+                resource_type: 0,
+                resource_attribute: 0x7,
+                physical_start: 0x1000,
+                resource_length: 0x1000,
+            }),
+            hob::HobSerDe::MemoryTypeInformation {
+                entries: vec![hob::MemoryTypeInfoEntrySerDe { memory_type: 6, number_of_pages: 0x20 }],
+            },
+            hob::HobSerDe::UnknownHob,
+        ]);
+        capture.unknown_hobs = vec![capture::UnknownHobSerDe { hob_type: 0x9, length: 0x20 }];
+        capture.guid_hob_data =
+            vec![capture::GuidHobDataSerDe { name: "123e4567-e89b-12d3-a456-426614174000".to_string(), data: vec![1] }];
+        capture.pe_characteristics = vec![capture::PeCharacteristicsSerDe {
+            fv_base_address: 0x1000,
+            file_index: 0,
+            section_index: 1,
+            characteristics: 0x0003,
+        }];
+        let mirror = DxeReadinessCaptureSerDe::from(&capture);
+        assert_eq!(serde_json::to_value(&mirror).unwrap(), serde_json::to_value(&capture).unwrap());
+    }
+
+    #[test]
+    fn test_capture_json_schema() {
+        let schema: serde_json::Value = serde_json::from_str(&capture_json_schema()).unwrap();
+        assert_eq!(schema["$schema"], "https://json-schema.org/draft/2020-12/schema");
+        assert_eq!(schema["required"], serde_json::json!(["hob_list", "fv_list"]));
+        assert_eq!(schema["$defs"]["HobSerDe"]["oneOf"].as_array().unwrap().len(), 9);
+        assert_eq!(schema["$defs"]["Hex"]["pattern"], "^0x[0-9a-fA-F]+$");
+    }
+}
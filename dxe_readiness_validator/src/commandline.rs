@@ -6,10 +6,233 @@
 //!
 //! SPDX-License-Identifier: Apache-2.0
 //!
-use clap::Parser;
+use crate::{
+    profile::{ARM64_RUNTIME_DRIVER_ALIGNMENT, Profile},
+    validation_kind::Severity,
+};
+use clap::{Parser, ValueEnum};
+use patina::base::UEFI_PAGE_SIZE;
+
+/// Format used to print the validation results.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human readable tables
+    #[default]
+    Text,
+    /// Machine readable JSON, one document per capture file
+    Json,
+    /// SARIF 2.1.0 for code-scanning tools, one document per capture file
+    Sarif,
+    /// JUnit XML for CI test dashboards, one document for all capture files
+    Junit,
+    /// GitHub flavored Markdown for pasting into issues
+    Markdown,
+    /// Self-contained HTML page for sharing, one document per capture file
+    Html,
+    /// CSV with one row per violation, one header row for all capture files
+    Csv,
+}
+
+/// How the exit code of the validator is computed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum ExitCodeMode {
+    /// A distinct code per failure class
+    #[default]
+    Category,
+    /// The number of violations found
+    Count,
+}
 
 #[derive(Default, Parser, Debug)]
 pub struct CommandLine {
-    #[arg(short, long, help = "File path of the capture.json")]
-    pub filename: Option<String>,
+    #[arg(
+        short,
+        long,
+        help = "File path of the capture.json, or a directory of captures, may be repeated. Use - for stdin"
+    )]
+    pub filename: Vec<String>,
+
+    #[arg(value_name = "FILE", help = "Additional capture.json file or directory paths")]
+    pub files: Vec<String>,
+
+    #[arg(long, help = "Also look for *.json captures in the subdirectories of a directory given as a file path")]
+    pub recursive: bool,
+
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Text, help = "Format of the validation results")]
+    pub output: OutputFormat,
+
+    #[arg(long, value_name = "KIND", help = "Only report this validation kind, may be repeated")]
+    pub only: Vec<String>,
+
+    #[arg(long, value_name = "KIND", help = "Do not report this validation kind, may be repeated")]
+    pub skip: Vec<String>,
+
+    #[arg(long, value_enum, default_value_t = Profile::Default, help = "Built-in platform profile selecting the rule set")]
+    pub profile: Profile,
+
+    #[arg(
+        long,
+        value_name = "BYTES",
+        value_parser = parse_runtime_alignment,
+        help = "Section alignment required of AArch64 runtime drivers (hex with 0x, or decimal), 64 KiB by default"
+    )]
+    pub runtime_alignment: Option<usize>,
+
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = Severity::Info,
+        help = "Lowest severity of the violations that count towards the exit code"
+    )]
+    pub min_severity: Severity,
+
+    #[arg(long, value_enum, default_value_t = ExitCodeMode::Category, help = "How the exit code is computed")]
+    pub exit_code: ExitCodeMode,
+
+    #[arg(long, value_name = "PATH", help = "File of accepted violation fingerprints to suppress")]
+    pub baseline: Option<String>,
+
+    #[arg(long, help = "Only print the number of violations of each kind in the text output")]
+    pub summary_only: bool,
+
+    #[arg(long, help = "Add a weighted readiness score to the text and JSON output, without affecting the exit code")]
+    pub score: bool,
+
+    #[arg(long, help = "Print the complete HOBs and files involved in each violation below the text tables")]
+    pub explain: bool,
+
+    #[arg(long, help = "Log each validation pass and the number of items it processed to stderr")]
+    pub progress: bool,
+
+    #[arg(long, help = "Stop at the first violation and only print its validation kind")]
+    pub fail_fast: bool,
+
+    #[arg(
+        long,
+        conflicts_with_all = ["fail_fast", "normalize", "diff", "json_schema"],
+        help = "Only print the total number of violations, which is also the exit code"
+    )]
+    pub count_only: bool,
+
+    #[arg(long, help = "Print the capture in canonical order as JSON instead of validating it")]
+    pub normalize: bool,
+
+    #[arg(
+        long,
+        value_name = "BASELINE",
+        help = "Report the HOBs and FV files added, removed or changed since this capture instead of validating"
+    )]
+    pub diff: Option<String>,
+
+    #[arg(long, help = "Print the JSON Schema of the capture format instead of validating")]
+    pub json_schema: bool,
+
+    #[arg(long, help = "Disable colored output")]
+    pub no_color: bool,
+
+    #[arg(long, value_name = "PATH", help = "Write the report to this path instead of stdout")]
+    pub output_file: Option<String>,
+
+    #[arg(long, value_name = "PATH", help = "Write a JUnit XML report to this path")]
+    pub junit_path: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "PATH",
+        help = "Write a JSON manifest of the violation count and status of each capture to this path"
+    )]
+    pub manifest: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "TYPE",
+        help = "Only allow this section compression type, may be repeated: brotli, tiano, lzma, \"lzma f86\", \"lzma parallel\", \"standard uefi\" or an EDK II tool name (BrotliCompress, TianoCompress, LzmaCompress, LzmaF86Compress). Replaces the LZMA rule"
+    )]
+    pub allowed_compression: Vec<String>,
+
+    #[arg(long, help = "Report an empty HOB or FV list as a note and validate the other list instead of failing")]
+    pub allow_empty: bool,
+
+    #[arg(long, help = "Report every overlapping pair of resource descriptors instead of one cluster per group")]
+    pub verbose_overlaps: bool,
+
+    #[arg(
+        long,
+        value_name = "END",
+        value_parser = parse_address,
+        help = "Report gaps in system memory below this address (hex with 0x, or decimal). Off by default as gaps are legal"
+    )]
+    pub require_contiguous: Option<u64>,
+
+    #[arg(long, help = "Report HOBs of a type the capture could not classify, with their type codes when recorded")]
+    pub flag_unknown_hobs: bool,
+
+    #[arg(
+        long,
+        help = "Report captures with V1 resource descriptors but no V2 ones, for platforms that must have migrated"
+    )]
+    pub require_v2: bool,
+}
+
+/// Parses an address given either as `0x` prefixed hexadecimal or as decimal.
+fn parse_address(value: &str) -> Result<u64, String> {
+    let parsed = match value.strip_prefix("0x").or_else(|| value.strip_prefix("0X")) {
+        Some(hex) => u64::from_str_radix(hex, 16),
+        None => value.parse(),
+    };
+    parsed.map_err(|err| format!("invalid address '{}': {}", value, err))
+}
+
+/// Parses a runtime driver section alignment, which must be a power of two
+/// and a multiple of the UEFI page size.
+fn parse_runtime_alignment(value: &str) -> Result<usize, String> {
+    let alignment = usize::try_from(parse_address(value)?).map_err(|err| format!("invalid alignment: {}", err))?;
+    if !alignment.is_power_of_two() || !alignment.is_multiple_of(UEFI_PAGE_SIZE) {
+        return Err(format!(
+            "invalid alignment '{}': must be a power of two and a multiple of the {:#x} byte page size",
+            value, UEFI_PAGE_SIZE
+        ));
+    }
+    Ok(alignment)
+}
+
+impl CommandLine {
+    /// Returns every capture file path given on the command line, `-f` values
+    /// first followed by the positional ones.
+    pub fn filenames(&self) -> impl Iterator<Item = &String> {
+        self.filename.iter().chain(self.files.iter())
+    }
+
+    /// Returns the section alignment required of AArch64 runtime drivers: the
+    /// one given with `--runtime-alignment`, or else the one of the UEFI
+    /// specification.
+    pub fn runtime_driver_alignment(&self) -> usize {
+        self.runtime_alignment.unwrap_or(ARM64_RUNTIME_DRIVER_ALIGNMENT)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_runtime_alignment() {
+        assert_eq!(parse_runtime_alignment("0x1000"), Ok(0x1000));
+        assert_eq!(parse_runtime_alignment("65536"), Ok(0x10000));
+        assert!(parse_runtime_alignment("0").is_err());
+        assert!(parse_runtime_alignment("0x800").is_err());
+        assert!(parse_runtime_alignment("0x3000").is_err());
+        assert!(parse_runtime_alignment("64K").is_err());
+    }
+
+    #[test]
+    fn test_runtime_driver_alignment() {
+        let args = CommandLine::try_parse_from(["validator", "capture.json"]).unwrap();
+        assert_eq!(args.runtime_driver_alignment(), ARM64_RUNTIME_DRIVER_ALIGNMENT);
+
+        let args =
+            CommandLine::try_parse_from(["validator", "--profile", "client", "--runtime-alignment", "0x4000"]).unwrap();
+        assert_eq!(args.runtime_driver_alignment(), 0x4000);
+        assert!(CommandLine::try_parse_from(["validator", "--runtime-alignment", "0x1800"]).is_err());
+    }
 }
@@ -0,0 +1,260 @@
+//! Structural comparison of two captures, used by `--diff` to find what changed
+//! between a known good capture and a new one.
+//!
+//! ## License
+//!
+//! Copyright (c) Microsoft Corporation.
+//!
+//! SPDX-License-Identifier: Apache-2.0
+//!
+use crate::validation_report::new_table;
+use colored::*;
+use dxe_readiness_capture::DxeReadinessCaptureSerDe;
+use patina::pi::serializable::serializable_hob::HobSerDe;
+use serde::Serialize;
+use serde_json::{Value, json};
+use std::{
+    collections::BTreeMap,
+    fmt,
+    io::{self, Write},
+};
+
+/// How an entry differs between the baseline and the new capture.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Change {
+    /// Only in the new capture
+    Added,
+    /// Only in the baseline
+    Removed,
+    /// In both, with different contents
+    Changed,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::Added => write!(f, "Added"),
+            Change::Removed => write!(f, "Removed"),
+            Change::Changed => write!(f, "Changed"),
+        }
+    }
+}
+
+/// A HOB or firmware file that differs between the two captures, with its
+/// pretty JSON on either side.
+#[derive(Debug)]
+pub struct DiffEntry {
+    pub identity: String,
+    pub baseline: Option<String>,
+    pub capture: Option<String>,
+}
+
+/// Differences between a baseline capture and a new capture, grouped by
+/// `Change`.
+#[derive(Debug, Default)]
+pub struct CaptureDiff {
+    entries: BTreeMap<Change, Vec<DiffEntry>>,
+}
+
+/// Returns the identity of a HOB, which stays the same when the HOB changes:
+/// its type and the start address of the range it describes or its GUID.
+fn hob_identity(hob: &HobSerDe) -> String {
+    match hob {
+        HobSerDe::Handoff { .. } => "Handoff".to_string(),
+        HobSerDe::MemoryAllocation { alloc_descriptor } => {
+            format!("Memory Allocation {:#x}", alloc_descriptor.memory_base_address)
+        }
+        HobSerDe::ResourceDescriptor(resource) => format!("Resource Descriptor {:#x}", resource.physical_start),
+        HobSerDe::ResourceDescriptorV2 { v1, .. } => format!("Resource Descriptor V2 {:#x}", v1.physical_start),
+        HobSerDe::GuidExtension { name } => format!("GUID Extension {}", name.to_lowercase()),
+        HobSerDe::MemoryTypeInformation { .. } => "Memory Type Information".to_string(),
+        HobSerDe::FirmwareVolume { base_address, .. } => format!("Firmware Volume {:#x}", base_address),
+        HobSerDe::Cpu { .. } => "CPU".to_string(),
+        HobSerDe::UnknownHob => "Unknown HOB".to_string(),
+    }
+}
+
+/// Returns the HOBs and firmware files of a capture as pretty JSON, keyed by
+/// their identity. Firmware files are identified by their FV and GUID.
+fn capture_entries(capture: &DxeReadinessCaptureSerDe) -> BTreeMap<String, Vec<String>> {
+    fn pretty(value: &impl Serialize) -> String {
+        serde_json::to_string_pretty(value).unwrap_or("serialization failed!".to_string())
+    }
+
+    let mut entries: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for hob in &capture.hob_list {
+        entries.entry(format!("HOB: {}", hob_identity(hob))).or_default().push(pretty(hob));
+    }
+    for fv in &capture.fv_list {
+        for file in &fv.files {
+            let identity = format!("FV: {}\nFile: {}", fv.fv_name.to_lowercase(), file.name.to_lowercase());
+            entries.entry(identity).or_default().push(pretty(file));
+        }
+    }
+    entries
+}
+
+impl CaptureDiff {
+    /// Compares `capture` against `baseline`. Entries sharing an identity,
+    /// such as repeated GUID Extension HOBs, are first matched with identical
+    /// entries on the other side and then paired in capture order.
+    pub fn new(baseline: &DxeReadinessCaptureSerDe, capture: &DxeReadinessCaptureSerDe) -> Self {
+        let mut diff = CaptureDiff::default();
+        let mut new_entries = capture_entries(capture);
+
+        for (identity, mut baseline_values) in capture_entries(baseline) {
+            let mut capture_values = new_entries.remove(&identity).unwrap_or_default();
+            baseline_values.retain(|value| match capture_values.iter().position(|other| other == value) {
+                Some(index) => {
+                    capture_values.remove(index);
+                    false
+                }
+                None => true,
+            });
+
+            let mut capture_values = capture_values.into_iter();
+            for baseline_value in baseline_values {
+                let capture_value = capture_values.next();
+                let change = if capture_value.is_some() { Change::Changed } else { Change::Removed };
+                diff.add(change, &identity, Some(baseline_value), capture_value);
+            }
+            for capture_value in capture_values {
+                diff.add(Change::Added, &identity, None, Some(capture_value));
+            }
+        }
+
+        for (identity, capture_values) in new_entries {
+            for capture_value in capture_values {
+                diff.add(Change::Added, &identity, None, Some(capture_value));
+            }
+        }
+        diff
+    }
+
+    fn add(&mut self, change: Change, identity: &str, baseline: Option<String>, capture: Option<String>) {
+        self.entries.entry(change).or_default().push(DiffEntry { identity: identity.to_string(), baseline, capture });
+    }
+
+    /// Returns the entries of one kind of change.
+    pub fn entries(&self, change: Change) -> &[DiffEntry] {
+        self.entries.get(&change).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Serializes the differences into a JSON document with one array per
+    /// kind of change. HOBs and files are embedded as JSON.
+    pub fn to_json(&self) -> Value {
+        let entries = |change: Change| -> Vec<Value> {
+            self.entries(change)
+                .iter()
+                .map(|entry| {
+                    let parse = |value: &Option<String>| {
+                        value.as_deref().and_then(|value| serde_json::from_str::<Value>(value).ok())
+                    };
+                    json!({
+                        "identity": entry.identity,
+                        "baseline": parse(&entry.baseline),
+                        "capture": parse(&entry.capture),
+                    })
+                })
+                .collect()
+        };
+
+        json!({
+            "added": entries(Change::Added),
+            "removed": entries(Change::Removed),
+            "changed": entries(Change::Changed),
+        })
+    }
+
+    /// Prints one table per kind of change.
+    pub fn pretty_print(&self, out: &mut impl Write) -> io::Result<()> {
+        if self.is_empty() {
+            return writeln!(out, "No differences found.");
+        }
+
+        for (change, entries) in &self.entries {
+            let title = match change {
+                Change::Added => change.to_string().green(),
+                Change::Removed => change.to_string().red(),
+                Change::Changed => change.to_string().yellow(),
+            };
+            writeln!(out, "──────────────────────────────────────────────────────────────────")?;
+            writeln!(out, "{} ({})", title.bold(), entries.len())?;
+
+            let mut table = match change {
+                Change::Added => new_table(vec!["#", "Entry", "Capture"]),
+                Change::Removed => new_table(vec!["#", "Entry", "Baseline"]),
+                Change::Changed => new_table(vec!["#", "Entry", "Baseline", "Capture"]),
+            };
+            for (j, entry) in entries.iter().enumerate() {
+                let mut row = vec![(j + 1).to_string(), entry.identity.clone()];
+                row.extend(entry.baseline.iter().chain(&entry.capture).cloned());
+                table.add_row(row);
+            }
+            writeln!(out, "{table}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use patina::pi::serializable::serializable_hob::ResourceDescriptorSerDe;
+
+    const Q35_CAPTURE: &str = include_str!("tests/data/q35_capture.json");
+
+    fn resource(physical_start: u64, resource_length: u64) -> HobSerDe {
+        HobSerDe::ResourceDescriptor(ResourceDescriptorSerDe {
+            owner: "00000000-0000-0000-0000-000000000000".to_string(),
+            resource_type: 0,
+            resource_attribute: 0,
+            physical_start,
+            resource_length,
+        })
+    }
+
+    #[test]
+    fn test_capture_diff() {
+        let baseline: DxeReadinessCaptureSerDe = serde_json::from_str(Q35_CAPTURE).unwrap();
+        assert!(CaptureDiff::new(&baseline, &baseline).is_empty());
+
+        let mut capture = baseline.clone();
+        capture.hob_list.push(resource(0x1000, 0x1000));
+        capture.hob_list.retain(|hob| !matches!(hob, HobSerDe::Cpu { .. }));
+        capture.fv_list[0].files[0].length += 0x10;
+
+        let diff = CaptureDiff::new(&baseline, &capture);
+        assert_eq!(diff.entries(Change::Added).len(), 1);
+        assert_eq!(diff.entries(Change::Added)[0].identity, "HOB: Resource Descriptor 0x1000");
+        assert_eq!(diff.entries(Change::Removed).len(), 1);
+        assert_eq!(diff.entries(Change::Removed)[0].identity, "HOB: CPU");
+        assert_eq!(diff.entries(Change::Changed).len(), 1);
+        assert!(diff.entries(Change::Changed)[0].identity.starts_with("FV: "));
+
+        let json = diff.to_json();
+        assert_eq!(json["added"][0]["capture"]["physical_start"], "0x1000");
+        assert!(json["removed"][0]["capture"].is_null());
+    }
+
+    #[test]
+    fn test_capture_diff_repeated_identity() {
+        let mut baseline: DxeReadinessCaptureSerDe = serde_json::from_str(Q35_CAPTURE).unwrap();
+        baseline.hob_list = vec![resource(0x1000, 0x1000), resource(0x1000, 0x2000)];
+
+        // Reordering entries that share an identity is not a change
+        let mut capture = baseline.clone();
+        capture.hob_list.reverse();
+        assert!(CaptureDiff::new(&baseline, &capture).is_empty());
+
+        capture.hob_list = vec![resource(0x1000, 0x2000), resource(0x1000, 0x3000), resource(0x1000, 0x4000)];
+        let diff = CaptureDiff::new(&baseline, &capture);
+        assert_eq!(diff.entries(Change::Changed).len(), 1);
+        assert_eq!(diff.entries(Change::Added).len(), 1);
+        assert!(diff.entries(Change::Removed).is_empty());
+    }
+}
@@ -6,11 +6,34 @@
 //!
 //! SPDX-License-Identifier: Apache-2.0
 //!
-use std::fmt;
+use std::{fmt, ops::AddAssign};
+
+/// Number of violations found, split by the kind of structure they were found
+/// in. `cross` counts the violations between the HOB and FV lists.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ViolationCount {
+    pub hob: u32,
+    pub fv: u32,
+    pub cross: u32,
+}
+
+impl ViolationCount {
+    pub fn total(&self) -> u32 {
+        self.hob + self.fv + self.cross
+    }
+}
+
+impl AddAssign for ViolationCount {
+    fn add_assign(&mut self, other: Self) {
+        self.hob += other.hob;
+        self.fv += other.fv;
+        self.cross += other.cross;
+    }
+}
 
 /// Represents possible errors that can occur during the validation app's
 /// execution.
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum ValidationAppError {
     /// Indicates that the command-line arguments are invalid. The `String`
     /// contains the missing or incorrect argument.
@@ -20,6 +43,11 @@ pub enum ValidationAppError {
     /// `String` contains the filename that failed to be read.
     JSONFileNotFound(String),
 
+    /// Indicates that a capture file, stdin or a directory of captures exists
+    /// but could not be read. The first `String` is the path and the second
+    /// contains the error message.
+    CaptureReadFailed(String, String),
+
     /// Indicates that the specified JSON file contain invalid 'utf-8' byte
     /// sequence. The `String` contains the filename that failed to be read. The
     /// other `String` contains the error message.
@@ -30,15 +58,47 @@ pub enum ValidationAppError {
     /// contains the error message.
     JSONSerializationFailed(String, String),
 
+    /// Indicates a failure in deserializing a CBOR capture. The `String`
+    /// contains the filename that failed to deserialize. The other `String`
+    /// contains the error message.
+    CborDeserializationFailed(String, String),
+
     /// Indicates that the parsed data contains an empty HOB list.
     EmptyHobList,
 
     /// Indicates that the parsed data contains an empty Firmware Volume list.
     EmptyFvList,
 
-    /// Indicates that one or more validation rules were violated. The `u32` is
-    /// the number of violations/errors detected.
-    ValidationErrors(u32),
+    /// Indicates that one or more validation rules were violated. The
+    /// `ViolationCount` is the number of violations/errors detected.
+    ValidationErrors(ViolationCount),
+
+    /// Indicates that `--fail-fast` stopped the validation at the first
+    /// violation. `capture_file` is the capture it was found in, `kind` the
+    /// name of its validation kind and `count` is one HOB, FV or cross violation.
+    FirstViolation { capture_file: String, kind: String, count: ViolationCount },
+
+    /// Indicates that one or more capture files could not be parsed or
+    /// validated. The `u32` is the number of failed files.
+    CaptureFilesFailed(u32),
+
+    /// Indicates that a report file could not be written. The first `String`
+    /// is the path of the file and the second contains the error message.
+    ReportWriteFailed(String, String),
+
+    /// Indicates that the baseline file could not be read. The first `String`
+    /// is the path of the file and the second contains the error message.
+    BaselineFileError(String, String),
+
+    /// Indicates that the checksum recorded in a capture does not match its
+    /// contents. The first `String` is the filename, the second the recorded
+    /// checksum and the third the computed one.
+    ChecksumMismatch(String, String, String),
+
+    /// Indicates that a capture was produced with a capture format version
+    /// this validator does not support. `found` is the version of the capture
+    /// and `supported` the one the validator reads.
+    UnsupportedSchemaVersion { found: u32, supported: u32 },
 }
 
 impl fmt::Display for ValidationAppError {
@@ -50,12 +110,18 @@ impl fmt::Display for ValidationAppError {
             ValidationAppError::JSONFileNotFound(path) => {
                 write!(f, "JSON file not found: {}", path)
             }
+            ValidationAppError::CaptureReadFailed(path, err) => {
+                write!(f, "Failed to read the capture {}. Error: {}", path, err)
+            }
             ValidationAppError::JSONFileContentError(path, err) => {
                 write!(f, "Error reading the JSON file {} contents. Error: {}", path, err)
             }
             ValidationAppError::JSONSerializationFailed(path, err) => {
                 write!(f, "Failed to serialize/deserialize JSON: {}. Error: {}", path, err)
             }
+            ValidationAppError::CborDeserializationFailed(path, err) => {
+                write!(f, "Failed to deserialize the CBOR capture: {}. Error: {}", path, err)
+            }
             ValidationAppError::EmptyHobList => {
                 write!(f, "The HOB list is empty.")
             }
@@ -63,7 +129,42 @@ impl fmt::Display for ValidationAppError {
                 write!(f, "The FV list is empty.")
             }
             ValidationAppError::ValidationErrors(violations) => {
-                write!(f, "Found {} validation errors", violations)
+                write!(f, "Found {} validation errors", violations.total())
+            }
+            ValidationAppError::FirstViolation { capture_file, kind, .. } => {
+                write!(f, "Stopped at the first violation in {}: {}", capture_file, kind)
+            }
+            ValidationAppError::CaptureFilesFailed(count) => {
+                write!(f, "Failed to parse or validate {} capture file(s)", count)
+            }
+            ValidationAppError::ReportWriteFailed(path, err) => {
+                write!(f, "Failed to write the report file {}. Error: {}", path, err)
+            }
+            ValidationAppError::BaselineFileError(path, err) => {
+                write!(f, "Error reading the baseline file {}. Error: {}", path, err)
+            }
+            ValidationAppError::ChecksumMismatch(path, expected, found) => {
+                write!(
+                    f,
+                    "Capture {} is corrupted: recorded checksum {} does not match computed checksum {}",
+                    path, expected, found
+                )
+            }
+            ValidationAppError::UnsupportedSchemaVersion { found, supported } if found < supported => {
+                write!(
+                    f,
+                    "The capture format version {} is too old, this validator supports version {}. Recapture the \
+                     platform with the current capture tool.",
+                    found, supported
+                )
+            }
+            ValidationAppError::UnsupportedSchemaVersion { found, supported } => {
+                write!(
+                    f,
+                    "The capture format version {} is newer than version {} supported by this validator. Update the \
+                     validator.",
+                    found, supported
+                )
             }
         }
     }
@@ -0,0 +1,116 @@
+//! Names of well-known firmware file, FV, section and HOB GUIDs, used to make
+//! the GUIDs in the report tables readable.
+//!
+//! ## License
+//!
+//! Copyright (c) Microsoft Corporation.
+//!
+//! SPDX-License-Identifier: Apache-2.0
+//!
+
+/// Well-known GUIDs in lowercase registry format with their EDK II names.
+const GUID_NAMES: &[(&str, &str)] = &[
+    ("00000000-0000-0000-0000-000000000000", "ZeroGuid"),
+    // Apriori files
+    ("1b45cc0a-156a-428a-af62-49864da0e6e6", "PeiAprioriFileNameGuid"),
+    ("fc510ee7-ffdc-11d4-bd41-0080c73c8881", "AprioriGuid"),
+    // Firmware volumes and files
+    ("8c8ce578-8a3d-4f1c-9935-896185c32dd3", "EfiFirmwareFileSystem2Guid"),
+    ("5473c07a-3dcb-4dca-bd6f-1e9689e7349a", "EfiFirmwareFileSystem3Guid"),
+    ("fff12b8d-7696-4c8b-a985-2747075b4f50", "EfiSystemNvDataFvGuid"),
+    ("1ba0062e-c779-4582-8566-336ae8f78f09", "EfiFirmwareVolumeTopFileGuid"),
+    ("d6a2cb7f-6a18-4e2f-b43b-9920a733700a", "DxeCore"),
+    // GUID defined sections
+    ("ee4e5898-3914-4259-9d6e-dc7bd79403cf", "LzmaCustomDecompressGuid"),
+    ("d42ae6bd-1352-4bfb-909a-ca72a6eae889", "LzmaF86CustomDecompressGuid"),
+    ("3d532050-5cda-4fd0-879e-0f7f630d5afb", "BrotliCustomDecompressGuid"),
+    ("a31280ad-481e-41b6-95e8-127f4c984779", "TianoCustomDecompressGuid"),
+    ("a7717414-c616-4977-9420-844712a735bf", "EfiCertTypeRsa2048Sha256Guid"),
+    // HOBs
+    ("4c19049f-4137-4dd3-9c10-8b97a83ffdfa", "EfiMemoryTypeInformationGuid"),
+    ("4ed4bf27-4092-42e9-807d-527b1d00c9bd", "EfiHobMemoryAllocStackGuid"),
+    ("564b33cd-c92a-4593-90bf-2473e43c6322", "EfiHobMemoryAllocBspStoreGuid"),
+    ("f8e21975-0899-4f58-a4be-5525a9c6d77a", "EfiHobMemoryAllocModuleGuid"),
+    ("7739f24c-93d7-11d4-9a3a-0090273fc14d", "EfiHobListGuid"),
+    ("6dadf1d1-d4cc-4910-bb6e-82b1fd80ff3d", "EfiSmmPeiSmramMemoryReserveGuid"),
+];
+
+/// Returns the name of a well-known GUID. The lookup ignores case.
+pub fn guid_name(guid: &str) -> Option<&'static str> {
+    GUID_NAMES.iter().find(|(known, _)| known.eq_ignore_ascii_case(guid)).map(|(_, name)| *name)
+}
+
+/// Formats a GUID followed by its name in parentheses when it is well known,
+/// e.g. `fc510ee7-ffdc-11d4-bd41-0080c73c8881 (AprioriGuid)`. Other GUIDs are
+/// returned unchanged.
+pub fn format_guid_named(guid: &str) -> String {
+    match guid_name(guid) {
+        Some(name) => format!("{} ({})", guid, name),
+        None => guid.to_string(),
+    }
+}
+
+/// Returns whether a string is a GUID in the canonical 8-4-4-4-12 registry
+/// format, e.g. `fc510ee7-ffdc-11d4-bd41-0080c73c8881`. Hex digits may be in
+/// either case.
+pub(crate) fn is_canonical_guid(guid: &str) -> bool {
+    guid.len() == 36
+        && guid.bytes().enumerate().all(|(i, b)| match i {
+            8 | 13 | 18 | 23 => b == b'-',
+            _ => b.is_ascii_hexdigit(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use patina::{OwnedGuid, pi::serializable::format_guid};
+    use r_efi::efi::Guid;
+
+    #[test]
+    fn test_format_guid_named() {
+        assert_eq!(
+            format_guid_named("fc510ee7-ffdc-11d4-bd41-0080c73c8881"),
+            "fc510ee7-ffdc-11d4-bd41-0080c73c8881 (AprioriGuid)"
+        );
+        assert_eq!(
+            format_guid_named("4C19049F-4137-4DD3-9C10-8B97A83FFDFA"),
+            "4C19049F-4137-4DD3-9C10-8B97A83FFDFA (EfiMemoryTypeInformationGuid)"
+        );
+        assert_eq!(format_guid_named("12345678-0000-0000-0000-000000000000"), "12345678-0000-0000-0000-000000000000");
+    }
+
+    #[test]
+    fn test_guid_names_are_unique_lowercase_guids() {
+        for (i, (guid, _)) in GUID_NAMES.iter().enumerate() {
+            assert_eq!(guid.len(), 36, "{}", guid);
+            assert!(guid.chars().all(|c| c == '-' || c.is_ascii_digit() || c.is_ascii_lowercase()), "{}", guid);
+            assert!(GUID_NAMES[i + 1..].iter().all(|(other, _)| other != guid), "{}", guid);
+        }
+    }
+
+    #[test]
+    fn test_is_canonical_guid() {
+        assert!(is_canonical_guid("fc510ee7-ffdc-11d4-bd41-0080c73c8881"));
+        assert!(is_canonical_guid("4C19049F-4137-4DD3-9C10-8B97A83FFDFA"));
+        assert!(!is_canonical_guid(""));
+        assert!(!is_canonical_guid("Brotli Compressed"));
+        assert!(!is_canonical_guid("fc510ee7ffdc11d4bd410080c73c8881"));
+        assert!(!is_canonical_guid("fc510ee7-ffdc-11d4-bd41-0080c73c888"));
+        assert!(!is_canonical_guid("fc510ee7-ffdc-11d4-bd41-0080c73c8881 "));
+        assert!(!is_canonical_guid("fc510ee7-ffdc-11d4-bd41-0080c73c888g"));
+        assert!(!is_canonical_guid("fc510ee7-ffdc-11d4bbd41-0080c73c8881"));
+        assert!(!is_canonical_guid("{fc510ee7-ffdc-11d4-bd41-0080c73c88}"));
+    }
+
+    #[test]
+    fn test_format_guid_round_trip() {
+        let guid = Guid::from_fields(0xfc510ee7, 0xffdc, 0x11d4, 0xbd, 0x41, &[0x00, 0x80, 0xc7, 0x3c, 0x88, 0x81]);
+        let formatted = format_guid(&guid);
+        assert_eq!(formatted, "fc510ee7-ffdc-11d4-bd41-0080c73c8881");
+        assert!(is_canonical_guid(&formatted));
+
+        let parsed = OwnedGuid::try_from_string(&formatted).unwrap();
+        assert_eq!(parsed.to_efi_guid(), guid);
+    }
+}
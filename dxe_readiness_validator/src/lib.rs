@@ -0,0 +1,33 @@
+//! Dxe Readiness Validation Library - X64/AArch64
+//!
+//! Runs the DXE readiness validations on a capture in-process. Use
+//! [`validate_capture`] to obtain a [`ValidationReport`] without going through
+//! the command line or writing to stdout.
+//!
+//! ## License
+//!
+//! Copyright (c) Microsoft Corporation.
+//!
+//! SPDX-License-Identifier: Apache-2.0
+//!
+
+mod capture_schema;
+mod commandline;
+mod diff;
+mod errors;
+mod guid_names;
+mod logger;
+mod normalize;
+mod profile;
+mod validate;
+mod validation_kind;
+mod validation_report;
+mod validator;
+
+pub use commandline::{ExitCodeMode, OutputFormat};
+pub use dxe_readiness_capture::DxeReadinessCaptureSerDe;
+pub use errors::{ValidationAppError, ViolationCount};
+pub use logger::init_logger;
+pub use validate::{ValidationApp, ValidationResult, validate_capture};
+pub use validation_kind::{FvValidationKind, HobValidationKind, Severity, ValidationKind};
+pub use validation_report::{HobSummary, MemoryFootprint, ValidationReport};
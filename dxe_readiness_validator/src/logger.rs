@@ -1,4 +1,5 @@
-//! Simple logger implementation for the DXE readiness validator.
+//! Simple logger implementation for the DXE readiness validator. Messages go
+//! to stderr so that they never mix with the report written to stdout.
 //!
 //! ## License
 //!
@@ -6,18 +7,18 @@
 //!
 //! SPDX-License-Identifier: Apache-2.0
 //!
-use log::{Level, LevelFilter, Metadata, Record};
+use log::{LevelFilter, Metadata, Record};
 
 struct SimpleLogger;
 
 impl log::Log for SimpleLogger {
     fn enabled(&self, metadata: &Metadata) -> bool {
-        metadata.level() <= Level::Info
+        metadata.level() <= log::max_level()
     }
 
     fn log(&self, record: &Record) {
         if self.enabled(record.metadata()) {
-            println!("{}", record.args());
+            eprintln!("{}", record.args());
         }
     }
 
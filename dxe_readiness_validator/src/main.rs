@@ -7,18 +7,52 @@
 //! SPDX-License-Identifier: Apache-2.0
 //!
 
-use errors::ValidationAppError;
-use logger::init_logger;
+use dxe_readiness_validator::{ExitCodeMode, ValidationApp, ValidationAppError, init_logger};
 use std::process;
-use validate::ValidationApp;
 
-mod commandline;
-mod errors;
-mod logger;
-mod validate;
-mod validation_kind;
-mod validation_report;
-mod validator;
+/// Exit codes returned by the validator in the default `--exit-code category`
+/// mode, one per failure class so that CI scripts can branch on them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(i32)]
+enum ExitCode {
+    /// Every capture passed validation with no violations.
+    Success = 0,
+    /// Only HOB violations were found.
+    HobViolations = 1,
+    /// Only FV violations were found.
+    FvViolations = 2,
+    /// Both HOB and FV violations, or violations between the HOB and FV
+    /// lists, were found.
+    MixedViolations = 3,
+    /// The command-line arguments are invalid.
+    InvalidCommandLine = 10,
+    /// A capture file could not be read, parsed or validated.
+    CaptureError = 11,
+    /// A capture holds an empty HOB or FV list.
+    EmptyList = 12,
+    /// A baseline or report file could not be read or written.
+    FileError = 13,
+}
+
+/// Exit codes returned by the validator in the `--exit-code count` mode for
+/// errors other than violations. Violations return their number instead, so
+/// these are negative to keep the two apart.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(i32)]
+enum CountModeExitCode {
+    /// A baseline or report file could not be read or written.
+    GenericError = -1,
+    /// The command-line arguments are invalid.
+    InvalidCommandLine = -2,
+    /// The capture file could not be found, opened or read.
+    CaptureNotFound = -3,
+    /// The capture file is not a valid capture: it does not decode or
+    /// deserialize, fails its checksum or has an unsupported format version.
+    /// Also returned when any of several capture files failed.
+    MalformedCapture = -4,
+    /// The capture holds an empty HOB or FV list.
+    EmptyList = -5,
+}
 
 fn main() {
     // The call to run_main() guarantees that all destructors have finished
@@ -34,16 +68,16 @@ fn run_main() -> i32 {
     let mut app = ValidationApp::new();
 
     if let Err(err) = app.parse_json() {
-        println!("{}", err);
-        return map_error(&err);
+        app.print_error(&err);
+        return map_error(&err, app.exit_code_mode());
     }
 
     if let Err(err) = app.validate() {
-        println!("{}", err);
-        return map_error(&err);
+        app.print_error(&err);
+        return map_error(&err, app.exit_code_mode());
     }
 
-    0 // Success
+    ExitCode::Success as i32
 }
 
 /// Maps a `ValidationAppError` to a platform-level exit code.
@@ -52,12 +86,155 @@ fn run_main() -> i32 {
 /// numeric exit codes suitable for CI.
 ///
 /// # Returns
-/// - The number of validation errors as `i32` if the error is
-///   `ValidationErrors`.
-/// - `-1` for all other types of errors, indicating a generic failure.
-fn map_error(err: &ValidationAppError) -> i32 {
-    match err {
-        ValidationAppError::ValidationErrors(count) => *count as i32,
-        _ => -1,
+/// - With `ExitCodeMode::Category`, the `ExitCode` of the failure class.
+/// - With `ExitCodeMode::Count`, the number of validation errors as `i32` if
+///   the error is `ValidationErrors`, and otherwise the negative
+///   `CountModeExitCode` of the failure.
+fn map_error(err: &ValidationAppError, mode: ExitCodeMode) -> i32 {
+    if mode == ExitCodeMode::Count {
+        return match err {
+            ValidationAppError::ValidationErrors(count) | ValidationAppError::FirstViolation { count, .. } => {
+                count.total() as i32
+            }
+            ValidationAppError::InvalidCommandLine(_) => CountModeExitCode::InvalidCommandLine as i32,
+            ValidationAppError::JSONFileNotFound(_) | ValidationAppError::CaptureReadFailed(..) => {
+                CountModeExitCode::CaptureNotFound as i32
+            }
+            ValidationAppError::JSONFileContentError(..)
+            | ValidationAppError::JSONSerializationFailed(..)
+            | ValidationAppError::CborDeserializationFailed(..)
+            | ValidationAppError::ChecksumMismatch(..)
+            | ValidationAppError::UnsupportedSchemaVersion { .. }
+            | ValidationAppError::CaptureFilesFailed(_) => CountModeExitCode::MalformedCapture as i32,
+            ValidationAppError::EmptyHobList | ValidationAppError::EmptyFvList => CountModeExitCode::EmptyList as i32,
+            ValidationAppError::ReportWriteFailed(..) | ValidationAppError::BaselineFileError(..) => {
+                CountModeExitCode::GenericError as i32
+            }
+        };
+    }
+
+    let exit_code = match err {
+        ValidationAppError::InvalidCommandLine(_) => ExitCode::InvalidCommandLine,
+        ValidationAppError::JSONFileNotFound(_)
+        | ValidationAppError::CaptureReadFailed(..)
+        | ValidationAppError::JSONFileContentError(..)
+        | ValidationAppError::JSONSerializationFailed(..)
+        | ValidationAppError::CborDeserializationFailed(..)
+        | ValidationAppError::ChecksumMismatch(..)
+        | ValidationAppError::UnsupportedSchemaVersion { .. }
+        | ValidationAppError::CaptureFilesFailed(_) => ExitCode::CaptureError,
+        ValidationAppError::EmptyHobList | ValidationAppError::EmptyFvList => ExitCode::EmptyList,
+        ValidationAppError::ReportWriteFailed(..) | ValidationAppError::BaselineFileError(..) => ExitCode::FileError,
+        ValidationAppError::ValidationErrors(count) | ValidationAppError::FirstViolation { count, .. } => {
+            // A cross violation involves both the HOB and the FV lists
+            match (count.hob != 0 || count.cross != 0, count.fv != 0 || count.cross != 0) {
+                (true, true) => ExitCode::MixedViolations,
+                (true, false) => ExitCode::HobViolations,
+                (false, true) => ExitCode::FvViolations,
+                (false, false) => ExitCode::Success,
+            }
+        }
+    };
+    exit_code as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dxe_readiness_validator::ViolationCount;
+
+    #[test]
+    fn test_map_error_category() {
+        let violations = |hob, fv| ValidationAppError::ValidationErrors(ViolationCount { hob, fv, cross: 0 });
+        let category = |err: &ValidationAppError| map_error(err, ExitCodeMode::Category);
+
+        assert_eq!(category(&violations(3, 0)), ExitCode::HobViolations as i32);
+        assert_eq!(category(&violations(0, 2)), ExitCode::FvViolations as i32);
+        assert_eq!(category(&violations(1, 1)), ExitCode::MixedViolations as i32);
+        let cross = ValidationAppError::ValidationErrors(ViolationCount { cross: 1, ..Default::default() });
+        assert_eq!(category(&cross), ExitCode::MixedViolations as i32);
+        assert_eq!(category(&ValidationAppError::JSONFileNotFound("x".to_string())), ExitCode::CaptureError as i32);
+        assert_eq!(category(&ValidationAppError::EmptyFvList), ExitCode::EmptyList as i32);
+        let read_failed = ValidationAppError::CaptureReadFailed("x".to_string(), "y".to_string());
+        assert_eq!(category(&read_failed), ExitCode::CaptureError as i32);
+
+        let first = ValidationAppError::FirstViolation {
+            capture_file: "x".to_string(),
+            kind: "InvalidSectionAlignment".to_string(),
+            count: ViolationCount { fv: 1, ..Default::default() },
+        };
+        assert_eq!(category(&first), ExitCode::FvViolations as i32);
+        assert_eq!(map_error(&first, ExitCodeMode::Count), 1);
+        assert_eq!(
+            category(&ValidationAppError::InvalidCommandLine("x".to_string())),
+            ExitCode::InvalidCommandLine as i32
+        );
+    }
+
+    #[test]
+    fn test_map_error_count() {
+        let err = ValidationAppError::ValidationErrors(ViolationCount { hob: 3, fv: 2, cross: 1 });
+        assert_eq!(map_error(&err, ExitCodeMode::Count), 6);
+    }
+
+    #[test]
+    fn test_map_error_count_errors() {
+        let count = |err: ValidationAppError| map_error(&err, ExitCodeMode::Count);
+
+        assert_eq!(count(ValidationAppError::EmptyHobList), CountModeExitCode::EmptyList as i32);
+        assert_eq!(count(ValidationAppError::EmptyFvList), CountModeExitCode::EmptyList as i32);
+        assert_eq!(
+            count(ValidationAppError::JSONFileNotFound("x".to_string())),
+            CountModeExitCode::CaptureNotFound as i32
+        );
+        assert_eq!(
+            count(ValidationAppError::JSONSerializationFailed("x".to_string(), "y".to_string())),
+            CountModeExitCode::MalformedCapture as i32
+        );
+        assert_eq!(
+            count(ValidationAppError::CborDeserializationFailed("x".to_string(), "y".to_string())),
+            CountModeExitCode::MalformedCapture as i32
+        );
+        assert_eq!(
+            count(ValidationAppError::InvalidCommandLine("x".to_string())),
+            CountModeExitCode::InvalidCommandLine as i32
+        );
+        assert_eq!(
+            count(ValidationAppError::CaptureReadFailed("x".to_string(), "y".to_string())),
+            CountModeExitCode::CaptureNotFound as i32
+        );
+        assert_eq!(
+            count(ValidationAppError::JSONFileContentError("x".to_string(), "y".to_string())),
+            CountModeExitCode::MalformedCapture as i32
+        );
+        assert_eq!(
+            count(ValidationAppError::ChecksumMismatch("x".to_string(), "y".to_string(), "z".to_string())),
+            CountModeExitCode::MalformedCapture as i32
+        );
+        assert_eq!(
+            count(ValidationAppError::UnsupportedSchemaVersion { found: 2, supported: 1 }),
+            CountModeExitCode::MalformedCapture as i32
+        );
+        assert_eq!(count(ValidationAppError::CaptureFilesFailed(2)), CountModeExitCode::MalformedCapture as i32);
+        assert_eq!(
+            count(ValidationAppError::ReportWriteFailed("x".to_string(), "y".to_string())),
+            CountModeExitCode::GenericError as i32
+        );
+        assert_eq!(
+            count(ValidationAppError::BaselineFileError("x".to_string(), "y".to_string())),
+            CountModeExitCode::GenericError as i32
+        );
+
+        // Every error class returns its own negative code
+        let codes = [
+            CountModeExitCode::GenericError,
+            CountModeExitCode::InvalidCommandLine,
+            CountModeExitCode::CaptureNotFound,
+            CountModeExitCode::MalformedCapture,
+            CountModeExitCode::EmptyList,
+        ]
+        .map(|code| code as i32);
+        assert!(codes.iter().all(|code| *code < 0));
+        assert!(codes.iter().enumerate().all(|(i, code)| !codes[i + 1..].contains(code)));
     }
 }
@@ -0,0 +1,119 @@
+//! Canonical ordering of a capture, so that two captures can be compared with
+//! a plain `diff`.
+//!
+//! ## License
+//!
+//! Copyright (c) Microsoft Corporation.
+//!
+//! SPDX-License-Identifier: Apache-2.0
+//!
+use dxe_readiness_capture::DxeReadinessCaptureSerDe;
+use patina::pi::serializable::serializable_hob::HobSerDe;
+
+/// Sort key of a HOB: the rank of its `HobSerDe` variant, the start and length
+/// of the range it describes, if any, and its GUID name, if any.
+fn hob_key(hob: &HobSerDe) -> (u8, u64, u64, String) {
+    match hob {
+        HobSerDe::Handoff { memory_bottom, memory_top, .. } => {
+            (0, *memory_bottom, memory_top.wrapping_sub(*memory_bottom), String::new())
+        }
+        HobSerDe::MemoryAllocation { alloc_descriptor } => {
+            (1, alloc_descriptor.memory_base_address, alloc_descriptor.memory_length, String::new())
+        }
+        HobSerDe::ResourceDescriptor(resource) => (2, resource.physical_start, resource.resource_length, String::new()),
+        HobSerDe::ResourceDescriptorV2 { v1: resource, .. } => {
+            (3, resource.physical_start, resource.resource_length, String::new())
+        }
+        HobSerDe::GuidExtension { name } => (4, 0, 0, name.to_lowercase()),
+        HobSerDe::MemoryTypeInformation { .. } => (5, 0, 0, String::new()),
+        HobSerDe::FirmwareVolume { base_address, length } => (6, *base_address, *length, String::new()),
+        HobSerDe::Cpu { .. } => (7, 0, 0, String::new()),
+        HobSerDe::UnknownHob => (8, 0, 0, String::new()),
+    }
+}
+
+/// Puts a capture in its canonical order:
+///
+/// - HOBs are grouped by `HobSerDe` variant, in the order the variants are
+///   declared, so the Handoff HOB stays first.
+/// - Within a group, HOBs describing a range are sorted by start address, then
+///   by length. GUID Extension HOBs are sorted by their lowercase GUID.
+/// - FVs are sorted by base address, then by name, and the files of every FV
+///   by their lowercase GUID.
+/// - Sections, Memory Type Information entries and the metadata are left as
+///   captured.
+///
+/// Remaining ties are broken by the compact JSON of the entries, so the result
+/// does not depend on the captured order at all. The checksum no longer
+/// matches the reordered lists, so it is recomputed when the capture carries
+/// one.
+pub fn normalize_capture(capture: &mut DxeReadinessCaptureSerDe) -> Result<(), serde_json::Error> {
+    capture.hob_list.sort_by_cached_key(|hob| (hob_key(hob), serde_json::to_string(hob).unwrap_or_default()));
+
+    capture.fv_list.sort_by(|fv1, fv2| (fv1.fv_base_address, &fv1.fv_name).cmp(&(fv2.fv_base_address, &fv2.fv_name)));
+    for fv in &mut capture.fv_list {
+        fv.files.sort_by_cached_key(|file| (file.name.to_lowercase(), serde_json::to_string(file).unwrap_or_default()));
+    }
+
+    if capture.checksum.is_some() {
+        capture.checksum = Some(capture.compute_checksum()?);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use patina::pi::serializable::serializable_hob::ResourceDescriptorSerDe;
+
+    const Q35_CAPTURE: &str = include_str!("tests/data/q35_capture.json");
+
+    fn resource(physical_start: u64) -> HobSerDe {
+        HobSerDe::ResourceDescriptor(ResourceDescriptorSerDe {
+            owner: "00000000-0000-0000-0000-000000000000".to_string(),
+            resource_type: 0,
+            resource_attribute: 0,
+            physical_start,
+            resource_length: 0x1000,
+        })
+    }
+
+    #[test]
+    fn test_normalize_capture_order() {
+        let mut capture: DxeReadinessCaptureSerDe = serde_json::from_str(Q35_CAPTURE).unwrap();
+        capture.hob_list = vec![
+            resource(0x3000),
+            HobSerDe::GuidExtension { name: "B0000000-0000-0000-0000-000000000000".to_string() },
+            resource(0x1000),
+            HobSerDe::GuidExtension { name: "a0000000-0000-0000-0000-000000000000".to_string() },
+        ];
+        normalize_capture(&mut capture).unwrap();
+
+        let keys: Vec<_> = capture.hob_list.iter().map(hob_key).collect();
+        assert!(keys.is_sorted());
+        assert_eq!(keys[0].1, 0x1000);
+        assert_eq!(keys[3].3, "b0000000-0000-0000-0000-000000000000");
+
+        for fv in &capture.fv_list {
+            assert!(fv.files.iter().map(|file| file.name.to_lowercase()).is_sorted());
+        }
+    }
+
+    #[test]
+    fn test_normalize_capture_is_deterministic() {
+        let mut capture: DxeReadinessCaptureSerDe = serde_json::from_str(Q35_CAPTURE).unwrap();
+        capture.checksum = Some(capture.compute_checksum().unwrap());
+        normalize_capture(&mut capture).unwrap();
+        let normalized = serde_json::to_string_pretty(&capture).unwrap();
+
+        // Normalizing a shuffled capture gives the same document
+        capture.hob_list.reverse();
+        capture.fv_list.reverse();
+        for fv in &mut capture.fv_list {
+            fv.files.reverse();
+        }
+        normalize_capture(&mut capture).unwrap();
+        assert_eq!(serde_json::to_string_pretty(&capture).unwrap(), normalized);
+        assert_eq!(capture.checksum, Some(capture.compute_checksum().unwrap()));
+    }
+}
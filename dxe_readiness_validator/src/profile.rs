@@ -0,0 +1,66 @@
+//! Built-in platform profiles, selecting the rule set a product line is
+//! validated against.
+//!
+//! ## License
+//!
+//! Copyright (c) Microsoft Corporation.
+//!
+//! SPDX-License-Identifier: Apache-2.0
+//!
+use clap::ValueEnum;
+
+/// Section alignment the UEFI specification requires of AArch64 runtime
+/// drivers, so that an OS using 64 KiB pages can map them.
+pub const ARM64_RUNTIME_DRIVER_ALIGNMENT: usize = 0x10000;
+
+/// Rule set a capture is validated against. The profile is resolved into the
+/// validation kinds skipped by the `KindFilter` and into the rules enabled in
+/// the validators.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Profile {
+    /// Every validation kind with the thresholds of the Patina requirements
+    #[default]
+    Default,
+    /// Forbids MM entirely: every MM module is reported as MmPresent
+    Client,
+    /// Skips the warnings raised by multi-socket silicon code: DuplicateAllocationName and UnknownGuidedSection
+    Server,
+}
+
+impl Profile {
+    /// Returns the names of the validation kinds the profile does not report.
+    pub fn skipped_kinds(&self) -> &'static [&'static str] {
+        match self {
+            Profile::Default => &[],
+            // MmPresent already reports every MM module, traditional or not.
+            Profile::Client => &["NonStandaloneMmPresent"],
+            // Silicon reference code names per-socket allocations with the same
+            // GUID and carries vendor GUID defined sections with their own
+            // section extractors.
+            Profile::Server => &["DuplicateAllocationName", "UnknownGuidedSection"],
+        }
+    }
+
+    /// Returns whether the profile reports any MM module as a violation.
+    pub fn forbids_mm(&self) -> bool {
+        matches!(self, Profile::Client)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation_kind::VALIDATION_KIND_NAMES;
+
+    #[test]
+    fn test_profiles() {
+        for profile in Profile::value_variants() {
+            for kind in profile.skipped_kinds() {
+                assert!(VALIDATION_KIND_NAMES.contains(kind), "{:?}: {}", profile, kind);
+            }
+        }
+        assert_eq!(Profile::default().skipped_kinds(), &[] as &[&str]);
+        assert!(!Profile::default().forbids_mm());
+        assert!(Profile::Client.forbids_mm());
+    }
+}
@@ -6,72 +6,942 @@
 //!
 //! SPDX-License-Identifier: Apache-2.0
 //!
+mod cross;
 mod fv;
 mod hob;
-use crate::{ValidationAppError, commandline::CommandLine, validation_report::ValidationReport, validator::Validator};
+
+use crate::{
+    ValidationAppError,
+    capture_schema::capture_json_schema,
+    commandline::{CommandLine, ExitCodeMode, OutputFormat},
+    diff::CaptureDiff,
+    errors::ViolationCount,
+    normalize::normalize_capture,
+    validation_kind::{KindFilter, VALIDATION_KIND_NAMES, ValidationKind},
+    validation_report::{
+        Baseline, CSV_HEADER, FailFast, ValidationReport, junit_document, manifest_document, new_table,
+    },
+    validator::Validator,
+};
+use base64::{Engine, engine::general_purpose::STANDARD};
 use clap::{CommandFactory, Parser};
-use dxe_readiness_capture::DxeReadinessCaptureSerDe;
+use colored::*;
+use cross::CrossValidator;
+use dxe_readiness_capture::{DxeReadinessCaptureSerDe, SCHEMA_VERSION};
+use flate2::read::GzDecoder;
 use fv::FvValidator;
 use hob::HobValidator;
-use std::fs;
+pub(crate) use hob::RESOURCE_ATTRIBUTE_DEPENDENCIES;
+use log::LevelFilter;
+use serde::{Deserialize, de::DeserializeOwned};
+use serde_json::Value;
+use std::{
+    env, fs,
+    io::{self, BufWriter, IsTerminal, Read, Write},
+    path::Path,
+    str,
+    time::Instant,
+};
+
+/// Filename that makes the validator read the capture from stdin.
+const STDIN_FILENAME: &str = "-";
+
+/// Magic number at the start of a gzip file (RFC 1952).
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Capture format version this validator reads.
+const SUPPORTED_SCHEMA_VERSION: u32 = SCHEMA_VERSION;
+
+/// Only the format version of a capture, so that it can be checked before the
+/// rest of the capture, whose layout depends on it, is deserialized.
+#[derive(Deserialize)]
+struct CaptureVersion {
+    #[serde(default)]
+    schema_version: u32,
+}
 
 pub type ValidationResult<'a> = Result<ValidationReport<'a>, ValidationAppError>;
 
 pub struct ValidationApp {
     args: CommandLine,
-    data: Option<DxeReadinessCaptureSerDe>,
+    // Parsed capture of each file given on the command line, in order
+    data: Vec<(String, Result<DxeReadinessCaptureSerDe, ValidationAppError>)>,
+    // Validation kinds selected with --only/--skip
+    filter: KindFilter,
+    // Accepted violations loaded from --baseline
+    baseline: Option<Baseline>,
+}
+
+impl Default for ValidationApp {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl ValidationApp {
+    /// Creates the application from the process command line.
     pub fn new() -> Self {
-        Self { args: CommandLine::parse(), data: None }
+        let args = CommandLine::parse();
+        if args.progress {
+            log::set_max_level(LevelFilter::Debug);
+        }
+        Self { args, data: Vec::new(), filter: KindFilter::default(), baseline: None }
     }
 
-    /// Parses a JSON file specified by the command-line arguments and populates
-    /// the internal data.
+    /// Parses every JSON file specified by the command-line arguments and
+    /// populates the internal data. A file that fails to parse does not stop
+    /// the remaining files from being parsed; an error is only returned when
+    /// no file could be parsed at all.
     pub fn parse_json(&mut self) -> Result<(), ValidationAppError> {
-        let Some(ref filename) = self.args.filename else {
+        let filenames: Vec<String> = self.args.filenames().cloned().collect();
+        // The schema does not depend on any capture
+        if self.args.json_schema {
+            return Ok(());
+        }
+        if filenames.is_empty() {
             let _ = CommandLine::command().print_help();
             return Err(ValidationAppError::InvalidCommandLine("'filename'".to_string()));
-        };
+        }
 
-        let file_content = fs::read_to_string(filename).map_err(|err| {
-            if err.kind() == std::io::ErrorKind::NotFound {
-                ValidationAppError::JSONFileNotFound(filename.clone())
-            } else {
-                ValidationAppError::JSONFileContentError(filename.clone(), err.to_string())
-            }
-        })?;
+        if filenames.iter().filter(|filename| *filename == STDIN_FILENAME).count() > 1 {
+            return Err(ValidationAppError::InvalidCommandLine("'-' may only be given once".to_string()));
+        }
 
-        let data = serde_json::from_str::<DxeReadinessCaptureSerDe>(&file_content)
-            .map_err(|err| ValidationAppError::JSONSerializationFailed(filename.clone(), err.to_string()))?;
+        let mut skip = self.args.skip.clone();
+        skip.extend(self.args.profile.skipped_kinds().iter().map(|kind| kind.to_string()));
+        self.filter = KindFilter::new(&self.args.only, &skip)?;
+        self.baseline = self.args.baseline.as_deref().map(Baseline::load).transpose()?;
+
+        self.data = filenames
+            .into_iter()
+            .flat_map(|filename| match capture_paths(&filename, self.args.recursive) {
+                Ok(paths) => paths.into_iter().map(|path| (path.clone(), Self::load_capture(&path))).collect(),
+                Err(err) => vec![(filename, Err(err))],
+            })
+            .collect();
+
+        // The manifest records the failed file, so leave the error to validate()
+        if let [(_, Err(err))] = &self.data[..]
+            && self.args.manifest.is_none()
+        {
+            return Err(err.clone());
+        }
 
-        self.data = Some(data);
         Ok(())
     }
 
+    /// Returns how the exit code is computed. `--count-only` always returns
+    /// the number of violations.
+    pub fn exit_code_mode(&self) -> ExitCodeMode {
+        if self.args.count_only { ExitCodeMode::Count } else { self.args.exit_code }
+    }
+
+    /// Prints an application error. In the machine readable modes and with
+    /// `--normalize`, `--json-schema` or `--count-only` errors go to stderr so
+    /// that stdout only ever carries the report, capture, schema or count.
+    /// With `--count-only`, the count itself stands for the violations found.
+    pub fn print_error(&self, err: &ValidationAppError) {
+        if self.args.count_only && matches!(err, ValidationAppError::ValidationErrors(_)) {
+            return;
+        }
+        match self.args.output {
+            OutputFormat::Text | OutputFormat::Markdown
+                if !self.args.normalize && !self.args.json_schema && !self.args.count_only =>
+            {
+                println!("{}", err)
+            }
+            OutputFormat::Text | OutputFormat::Markdown => eprintln!("{}", err),
+            OutputFormat::Json | OutputFormat::Sarif | OutputFormat::Junit | OutputFormat::Html | OutputFormat::Csv => {
+                eprintln!("{}", err)
+            }
+        }
+    }
+
+    /// Reads and deserializes a single capture file. A filename of `-` reads
+    /// the capture from stdin.
+    fn load_capture(filename: &String) -> Result<DxeReadinessCaptureSerDe, ValidationAppError> {
+        let is_stdin = filename == STDIN_FILENAME;
+        let source = if is_stdin { "<stdin>".to_string() } else { filename.clone() };
+
+        let mut file_content = Vec::new();
+        if is_stdin {
+            io::stdin()
+                .read_to_end(&mut file_content)
+                .map_err(|err| ValidationAppError::CaptureReadFailed(source.clone(), err.to_string()))?;
+        } else {
+            file_content = fs::read(filename).map_err(|err| {
+                if err.kind() == std::io::ErrorKind::NotFound {
+                    ValidationAppError::JSONFileNotFound(filename.clone())
+                } else {
+                    ValidationAppError::CaptureReadFailed(filename.clone(), err.to_string())
+                }
+            })?
+        }
+        let file_content = decode_base64_capture(decompress_gzip_capture(&source, file_content)?);
+
+        let version = deserialize_capture::<CaptureVersion>(&source, &file_content)?;
+        if version.schema_version != SUPPORTED_SCHEMA_VERSION {
+            return Err(ValidationAppError::UnsupportedSchemaVersion {
+                found: version.schema_version,
+                supported: SUPPORTED_SCHEMA_VERSION,
+            });
+        }
+
+        let capture = deserialize_capture::<DxeReadinessCaptureSerDe>(&source, &file_content)?;
+        verify_checksum(&source, &capture)?;
+        Ok(capture)
+    }
+
     /// Validates the contents of the parsed JSON data, including HOBs and
-    /// firmware volumes.
+    /// firmware volumes, for every capture file. Files are validated
+    /// independently so a failure in one does not hide the results of the
+    /// others. With `--normalize`, every capture is printed in canonical order
+    /// instead.
     pub fn validate(&self) -> Result<(), ValidationAppError> {
-        let Some(data) = &self.data else {
-            return Err(ValidationAppError::EmptyHobList);
+        colored::control::set_override(self.should_color());
+
+        let mut out: Box<dyn Write> = match &self.args.output_file {
+            Some(path) => {
+                let file = fs::File::create(path).map_err(|err| self.write_error(err))?;
+                Box::new(BufWriter::new(file))
+            }
+            None => Box::new(io::stdout()),
         };
 
-        let mut validation_report = ValidationReport::new();
+        if self.args.json_schema {
+            writeln!(out, "{}", capture_json_schema()).map_err(|err| self.write_error(err))?;
+            out.flush().map_err(|err| self.write_error(err))?;
+            return Ok(());
+        }
 
-        let hob_validator = HobValidator::new(&data.hob_list);
-        validation_report.append_report(hob_validator.validate()?);
+        if let Some(baseline_path) = &self.args.diff {
+            let result = self.diff_captures(baseline_path, &mut out);
+            out.flush().map_err(|err| self.write_error(err))?;
+            return result;
+        }
 
-        let fv_validator = FvValidator::new(&data.fv_list);
-        validation_report.append_report(fv_validator.validate()?);
+        if self.args.count_only {
+            let result = self.count_violations(&mut out);
+            out.flush().map_err(|err| self.write_error(err))?;
+            return result;
+        }
 
-        validation_report.show_results();
+        if self.args.normalize {
+            let result = self.normalize_captures(&mut out);
+            out.flush().map_err(|err| self.write_error(err))?;
+            return result;
+        }
+
+        // The CSV rows of every capture share a single header row
+        if self.args.output == OutputFormat::Csv {
+            write!(out, "{}\r\n", CSV_HEADER).map_err(|err| self.write_error(err))?;
+        }
+
+        let mut junit_suites = Vec::new();
+        let mut file_results = Vec::new();
+        let result = self.validate_captures(&mut out, &mut junit_suites, &mut file_results);
+
+        // The manifest is written whatever the outcome, so that failures can be
+        // attributed to the capture files they come from
+        if let Some(path) = &self.args.manifest {
+            let document = serde_json::to_string_pretty(&manifest_document(&file_results))
+                .map_err(|err| ValidationAppError::JSONSerializationFailed(path.clone(), err.to_string()))?;
+            fs::write(path, document + "\n")
+                .map_err(|err| ValidationAppError::ReportWriteFailed(path.clone(), err.to_string()))?;
+        }
+
+        // The JUnit report covers every capture, so it is emitted once at the end
+        if self.args.output == OutputFormat::Junit || self.args.junit_path.is_some() {
+            let document = junit_document(&junit_suites);
+            match &self.args.junit_path {
+                Some(path) => fs::write(path, document)
+                    .map_err(|err| ValidationAppError::ReportWriteFailed(path.clone(), err.to_string()))?,
+                None => write!(out, "{}", document).map_err(|err| self.write_error(err))?,
+            }
+        }
+
+        out.flush().map_err(|err| self.write_error(err))?;
+        result
+    }
+
+    /// Decides whether the report may use color. Escape sequences are only
+    /// meaningful on a terminal, so color is off when the report goes to a
+    /// file or a redirected stdout, when `--no-color` is given or when the
+    /// `NO_COLOR` environment variable is set to a non-empty value.
+    fn should_color(&self) -> bool {
+        let no_color_env = env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+        !self.args.no_color && !no_color_env && self.args.output_file.is_none() && io::stdout().is_terminal()
+    }
 
-        let validation_count = validation_report.violation_count() as u32;
-        if validation_count != 0 {
+    /// Maps an error writing the report to the output sink.
+    fn write_error(&self, err: io::Error) -> ValidationAppError {
+        let path = self.args.output_file.clone().unwrap_or("<stdout>".to_string());
+        ValidationAppError::ReportWriteFailed(path, err.to_string())
+    }
+
+    /// Writes the HOBs and FV files that differ between the baseline capture at
+    /// `baseline_path` and the capture given on the command line to `out`.
+    fn diff_captures(&self, baseline_path: &String, out: &mut impl Write) -> Result<(), ValidationAppError> {
+        let [(_, capture)] = &self.data[..] else {
+            return Err(ValidationAppError::InvalidCommandLine("--diff takes a single capture file".to_string()));
+        };
+        let capture = capture.as_ref().map_err(Clone::clone)?;
+        let baseline = Self::load_capture(baseline_path)?;
+
+        let diff = CaptureDiff::new(&baseline, capture);
+        match self.args.output {
+            OutputFormat::Text => diff.pretty_print(out),
+            OutputFormat::Json => writeln!(out, "{}", diff.to_json()),
+            _ => {
+                return Err(ValidationAppError::InvalidCommandLine(
+                    "--diff only supports the text and json output formats".to_string(),
+                ));
+            }
+        }
+        .map_err(|err| self.write_error(err))
+    }
+
+    /// Writes every parsed capture to `out` as pretty JSON in the canonical
+    /// order of `normalize_capture`, one document per capture file.
+    fn normalize_captures(&self, out: &mut impl Write) -> Result<(), ValidationAppError> {
+        let mut failed_files = 0;
+        for (filename, data) in &self.data {
+            let mut capture = match data {
+                Ok(capture) => capture.clone(),
+                Err(err) => {
+                    self.print_error(err);
+                    failed_files += 1;
+                    continue;
+                }
+            };
+
+            let document = normalize_capture(&mut capture)
+                .and_then(|_| serde_json::to_string_pretty(&capture))
+                .map_err(|err| ValidationAppError::JSONSerializationFailed(filename.clone(), err.to_string()))?;
+            writeln!(out, "{}", document).map_err(|err| self.write_error(err))?;
+        }
+
+        if failed_files != 0 {
+            return Err(ValidationAppError::CaptureFilesFailed(failed_files));
+        }
+        Ok(())
+    }
+
+    /// Validates every parsed capture and writes the total number of
+    /// violations at or above the minimum severity to `out` as a single
+    /// integer, whatever the output format. Nothing is written when a capture
+    /// fails to parse or validate, and its error is returned instead.
+    fn count_violations(&self, out: &mut impl Write) -> Result<(), ValidationAppError> {
+        let mut validation_count = ViolationCount::default();
+        for (_, data) in &self.data {
+            let data = data.as_ref().map_err(Clone::clone)?;
+            let mut validation_report = run_validators(data, &self.filter, &self.args, None)?;
+            if let Some(baseline) = &self.baseline {
+                validation_report.apply_baseline(baseline);
+            }
+            validation_count += validation_report.violation_counts_at(self.args.min_severity);
+        }
+
+        writeln!(out, "{}", validation_count.total()).map_err(|err| self.write_error(err))?;
+        if validation_count.total() != 0 {
+            return Err(ValidationAppError::ValidationErrors(validation_count));
+        }
+        Ok(())
+    }
+
+    /// Validates every parsed capture, writing the results to `out`,
+    /// collecting the JUnit test suites of each one into `junit_suites` and
+    /// its violation count, or `None` when it failed, into `file_results`.
+    fn validate_captures(
+        &self,
+        out: &mut impl Write,
+        junit_suites: &mut Vec<String>,
+        file_results: &mut Vec<(String, Option<ViolationCount>)>,
+    ) -> Result<(), ValidationAppError> {
+        let file_result = |result: &Result<ViolationCount, ValidationAppError>| match result {
+            Ok(count) | Err(ValidationAppError::FirstViolation { count, .. }) => Some(*count),
+            Err(_) => None,
+        };
+
+        match &self.data[..] {
+            [] => return Err(ValidationAppError::EmptyHobList),
+            [(filename, data)] => {
+                // A single capture keeps the original error reporting.
+                let result = data
+                    .as_ref()
+                    .map_err(Clone::clone)
+                    .and_then(|data| self.validate_capture(filename, data, out, junit_suites));
+                file_results.push((filename.clone(), file_result(&result)));
+                let validation_count = result?;
+                if validation_count.total() != 0 {
+                    return Err(ValidationAppError::ValidationErrors(validation_count));
+                }
+                return Ok(());
+            }
+            _ => {}
+        }
+
+        let mut validation_count = ViolationCount::default();
+        let mut failed_files = 0;
+        for (filename, data) in &self.data {
+            if self.args.output == OutputFormat::Text {
+                writeln!(out, "Capture: {}", filename).map_err(|err| self.write_error(err))?;
+            }
+
+            let result = data
+                .as_ref()
+                .map_err(Clone::clone)
+                .and_then(|data| self.validate_capture(filename, data, out, junit_suites));
+            file_results.push((filename.clone(), file_result(&result)));
+            match result {
+                Ok(count) => validation_count += count,
+                Err(err @ ValidationAppError::FirstViolation { .. }) => return Err(err),
+                Err(err) => {
+                    self.print_error(&err);
+                    failed_files += 1;
+                }
+            }
+        }
+
+        if self.args.output == OutputFormat::Text {
+            print_batch_summary(file_results, out).map_err(|err| self.write_error(err))?;
+        }
+
+        if failed_files != 0 {
+            return Err(ValidationAppError::CaptureFilesFailed(failed_files));
+        }
+
+        if validation_count.total() != 0 {
             return Err(ValidationAppError::ValidationErrors(validation_count));
         }
 
         Ok(())
     }
+
+    /// Validates a single parsed capture, writes its results to `out` and
+    /// returns the number of violations at or above the minimum severity. With
+    /// `--fail-fast`, the validation stops after the first pass that records
+    /// such a violation, nothing is written and that violation is returned as
+    /// an error instead.
+    fn validate_capture(
+        &self,
+        filename: &str,
+        data: &DxeReadinessCaptureSerDe,
+        out: &mut impl Write,
+        junit_suites: &mut Vec<String>,
+    ) -> Result<ViolationCount, ValidationAppError> {
+        log::debug!("Validating {}", filename);
+        let fail_fast = self.args.fail_fast.then(|| FailFast::new(self.args.min_severity, self.baseline.clone()));
+        let mut validation_report = run_validators(data, &self.filter, &self.args, fail_fast.as_ref())?;
+        validation_report.set_capture_file(filename);
+        validation_report.set_show_score(self.args.score);
+        validation_report.set_explain(self.args.explain);
+
+        if let Some(baseline) = &self.baseline {
+            validation_report.apply_baseline(baseline);
+        }
+
+        if self.args.fail_fast {
+            let first = validation_report
+                .iter_violations()
+                .find(|(_, violation)| violation.severity() >= self.args.min_severity);
+            if let Some((kind, violation)) = first {
+                let count = match violation {
+                    ValidationKind::Hob(_) => ViolationCount { hob: 1, ..Default::default() },
+                    ValidationKind::Fv(_) => ViolationCount { fv: 1, ..Default::default() },
+                    ValidationKind::Cross(_) => ViolationCount { cross: 1, ..Default::default() },
+                };
+                return Err(ValidationAppError::FirstViolation {
+                    capture_file: filename.to_string(),
+                    kind: kind.to_string(),
+                    count,
+                });
+            }
+        }
+
+        validation_report
+            .show_results(self.args.output, self.args.summary_only, out)
+            .map_err(|err| self.write_error(err))?;
+        junit_suites.push(validation_report.to_junit());
+
+        Ok(validation_report.violation_counts_at(self.args.min_severity))
+    }
+}
+
+/// Runs a single validation pass over `items` HOBs or firmware files. With
+/// `--progress`, the pass is logged when it starts and when it finishes, so a
+/// slow pass on a large capture does not look like a hang.
+fn run_pass<'a>(pass: &str, items: usize, validate: impl FnOnce() -> ValidationResult<'a>) -> ValidationResult<'a> {
+    log::debug!("Running {} over {} item(s)", pass, items);
+    let start = Instant::now();
+    let validation_report = validate()?;
+    log::debug!(
+        "Finished {}: {} item(s) processed, {} violation(s), {:.1?}",
+        pass,
+        items,
+        validation_report.violation_count(),
+        start.elapsed()
+    );
+    Ok(validation_report)
+}
+
+/// Runs every HOB, FV and cross validation on a capture with the default settings and
+/// returns the resulting report. This is the entry point for embedding the
+/// validator; it neither parses the command line nor writes any output.
+pub fn validate_capture(data: &DxeReadinessCaptureSerDe) -> ValidationResult<'_> {
+    run_validators(data, &KindFilter::default(), &CommandLine::default(), None)
+}
+
+/// Runs the passes of a validator into a single report. A pass whose
+/// validation kinds are all rejected by the filter is not run at all, and once
+/// a pass records a violation `fail_fast` stops at, the remaining passes are
+/// skipped.
+struct Passes<'a, 'v> {
+    report: ValidationReport<'a>,
+    items: usize,
+    filter: &'v KindFilter,
+    fail_fast: Option<&'v FailFast>,
+    stopped: bool,
+}
+
+impl<'a, 'v> Passes<'a, 'v> {
+    fn new(
+        report: ValidationReport<'a>,
+        items: usize,
+        filter: &'v KindFilter,
+        fail_fast: Option<&'v FailFast>,
+    ) -> Self {
+        Passes { report, items, filter, fail_fast, stopped: false }
+    }
+
+    /// Runs `validate` unless none of the validation `kinds` it reports are
+    /// allowed by the filter.
+    fn run(
+        &mut self,
+        pass: &str,
+        kinds: &[&str],
+        validate: impl FnOnce() -> ValidationResult<'a>,
+    ) -> Result<(), ValidationAppError> {
+        debug_assert!(kinds.iter().all(|kind| VALIDATION_KIND_NAMES.contains(kind)), "{}: {:?}", pass, kinds);
+        if self.stopped {
+            return Ok(());
+        }
+        if !kinds.iter().any(|kind| self.filter.allows(kind)) {
+            log::debug!("Skipping {}, none of its validation kinds are reported", pass);
+            return Ok(());
+        }
+        let mut report = run_pass(pass, self.items, validate)?;
+        report.retain_kinds(self.filter);
+        self.stopped = self.fail_fast.is_some_and(|fail_fast| fail_fast.is_met(&report));
+        self.report.append_report(report);
+        Ok(())
+    }
+
+    fn finish(self) -> ValidationReport<'a> {
+        self.report
+    }
+}
+
+/// Runs the HOB, FV and cross validators on a capture with the validator settings of
+/// `args`, keeping only the validation kinds selected by `filter`. With
+/// `fail_fast`, no further validator runs once one of them has stopped.
+fn run_validators<'a>(
+    data: &'a DxeReadinessCaptureSerDe,
+    filter: &KindFilter,
+    args: &CommandLine,
+    fail_fast: Option<&FailFast>,
+) -> ValidationResult<'a> {
+    let mut validation_report = ValidationReport::new();
+    validation_report.set_metadata(data.metadata.as_ref());
+
+    let hob_validator = HobValidator::new(&data.hob_list)
+        .with_filter(filter.clone())
+        .with_allow_empty(args.allow_empty)
+        .with_verbose_overlaps(args.verbose_overlaps)
+        .with_require_contiguous(args.require_contiguous)
+        .with_require_v2(args.require_v2)
+        .with_flag_unknown_hobs(args.flag_unknown_hobs.then_some(data.unknown_hobs.as_slice()))
+        .with_guid_hob_data(&data.guid_hob_data)
+        .with_fail_fast(fail_fast.cloned());
+    validation_report.append_report(hob_validator.validate()?);
+    if fail_fast.is_some_and(|fail_fast| fail_fast.is_met(&validation_report)) {
+        return Ok(validation_report);
+    }
+
+    let fv_validator = FvValidator::new(&data.fv_list)
+        .with_filter(filter.clone())
+        .with_allowed_compression(&args.allowed_compression)
+        .with_allow_empty(args.allow_empty)
+        .with_runtime_driver_alignment(args.runtime_driver_alignment())
+        .with_forbid_mm(args.profile.forbids_mm())
+        .with_pe_characteristics(&data.pe_characteristics)
+        .with_fail_fast(fail_fast.cloned());
+    validation_report.append_report(fv_validator.validate()?);
+    if fail_fast.is_some_and(|fail_fast| fail_fast.is_met(&validation_report)) {
+        return Ok(validation_report);
+    }
+
+    let cross_validator = CrossValidator::new(data).with_filter(filter.clone()).with_fail_fast(fail_fast.cloned());
+    validation_report.append_report(cross_validator.validate()?);
+
+    Ok(validation_report)
+}
+
+/// Returns true when a capture holds CBOR rather than JSON. A CBOR capture
+/// starts with a map header, which is never valid JSON.
+fn is_cbor_capture(content: &[u8]) -> bool {
+    matches!(content.first(), Some(0xa0..=0xbf))
+}
+
+/// Decompresses a gzipped capture, as archived by CI. Content without the gzip
+/// magic number is returned unchanged.
+fn decompress_gzip_capture(source: &str, content: Vec<u8>) -> Result<Vec<u8>, ValidationAppError> {
+    if !content.starts_with(&GZIP_MAGIC) {
+        return Ok(content);
+    }
+
+    let mut decompressed = Vec::new();
+    GzDecoder::new(&content[..])
+        .read_to_end(&mut decompressed)
+        .map_err(|err| ValidationAppError::JSONFileContentError(source.to_string(), err.to_string()))?;
+    Ok(decompressed)
+}
+
+/// Returns the capture files a path given on the command line stands for: the
+/// path itself, or for a directory the `*.json` files in it, sorted by path.
+/// Subdirectories are only searched when `recursive` is set. A directory that
+/// cannot be read or holds no capture is an error.
+fn capture_paths(path: &str, recursive: bool) -> Result<Vec<String>, ValidationAppError> {
+    if path == STDIN_FILENAME || !Path::new(path).is_dir() {
+        return Ok(vec![path.to_string()]);
+    }
+
+    let mut paths = Vec::new();
+    let mut directories = vec![Path::new(path).to_path_buf()];
+    while let Some(directory) = directories.pop() {
+        let read_error =
+            |err: io::Error| ValidationAppError::CaptureReadFailed(directory.display().to_string(), err.to_string());
+        for entry in fs::read_dir(&directory).map_err(read_error)? {
+            let entry_path = entry.map_err(read_error)?.path();
+            if entry_path.is_dir() {
+                if recursive {
+                    directories.push(entry_path);
+                }
+            } else if entry_path.extension().is_some_and(|extension| extension.eq_ignore_ascii_case("json")) {
+                paths.push(entry_path.display().to_string());
+            }
+        }
+    }
+
+    if paths.is_empty() {
+        return Err(ValidationAppError::JSONFileNotFound(Path::new(path).join("*.json").display().to_string()));
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+/// Prints one row per capture file of a batch with its status: `Pass`
+/// without violations, `Fail` with violations and `Error` when the file could
+/// not be parsed or validated.
+fn print_batch_summary(file_results: &[(String, Option<ViolationCount>)], out: &mut impl Write) -> io::Result<()> {
+    writeln!(out, "{}", "Batch Summary:".bold())?;
+    let mut table = new_table(vec!["Capture", "Status", "Violations"]);
+    for (filename, count) in file_results {
+        let (status, violations) = match count {
+            Some(count) if count.total() == 0 => ("Pass", "0".to_string()),
+            Some(count) => ("Fail", count.total().to_string()),
+            None => ("Error", "-".to_string()),
+        };
+        table.add_row(vec![filename.clone(), status.to_string(), violations]);
+    }
+    writeln!(out, "{table}")
+}
+
+/// Decodes a capture copied out of a serial log by a capture tool built with
+/// the `cbor` feature, which prints the CBOR base64 encoded. Content that is
+/// not base64, such as JSON, raw CBOR or a corrupted capture, is returned
+/// unchanged so that the deserialization reports the error.
+fn decode_base64_capture(content: Vec<u8>) -> Vec<u8> {
+    match content.iter().find(|byte| !byte.is_ascii_whitespace()) {
+        None | Some(b'{') => content,
+        Some(_) if is_cbor_capture(&content) => content,
+        Some(_) => {
+            let text: Vec<u8> = content.iter().copied().filter(|byte| !byte.is_ascii_whitespace()).collect();
+            STANDARD.decode(text).unwrap_or(content)
+        }
+    }
+}
+
+/// Deserializes a capture, or a part of it, from JSON or CBOR depending on the
+/// first byte of the content. CBOR is read into a JSON value first, because
+/// some HOB fields only deserialize from borrowed strings, which the CBOR
+/// reader cannot provide.
+fn deserialize_capture<T: DeserializeOwned>(source: &str, content: &[u8]) -> Result<T, ValidationAppError> {
+    if is_cbor_capture(content) {
+        let cbor_error = |err: String| ValidationAppError::CborDeserializationFailed(source.to_string(), err);
+        let value = ciborium::from_reader::<Value, _>(content).map_err(|err| cbor_error(err.to_string()))?;
+        return serde_path_to_error::deserialize(&value).map_err(|err| cbor_error(path_error_message(&err)));
+    }
+
+    let content = str::from_utf8(content)
+        .map_err(|err| ValidationAppError::JSONFileContentError(source.to_string(), err.to_string()))?;
+    let mut deserializer = serde_json::Deserializer::from_str(content);
+    let capture = serde_path_to_error::deserialize(&mut deserializer)
+        .map_err(|err| ValidationAppError::JSONSerializationFailed(source.to_string(), path_error_message(&err)))?;
+    deserializer
+        .end()
+        .map_err(|err| ValidationAppError::JSONSerializationFailed(source.to_string(), err.to_string()))?;
+    Ok(capture)
+}
+
+/// Formats a deserialization error prefixed with the path of the field that
+/// failed, e.g. `hob_list[42]: missing field `physical_start` at line 1
+/// column 1234`. serde_json errors already carry the line and column.
+fn path_error_message(err: &serde_path_to_error::Error<serde_json::Error>) -> String {
+    match err.path().to_string().as_str() {
+        "." => err.inner().to_string(),
+        path => format!("{}: {}", path, err.inner()),
+    }
+}
+
+/// Checks the checksum recorded in a capture against its contents. Captures
+/// without a checksum, produced by older versions of the capture tool, are
+/// accepted as is.
+fn verify_checksum(source: &str, capture: &DxeReadinessCaptureSerDe) -> Result<(), ValidationAppError> {
+    let Some(expected) = &capture.checksum else {
+        return Ok(());
+    };
+
+    let found = capture
+        .compute_checksum()
+        .map_err(|err| ValidationAppError::JSONSerializationFailed(source.to_string(), err.to_string()))?;
+    if *expected != found {
+        return Err(ValidationAppError::ChecksumMismatch(source.to_string(), expected.clone(), found));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dxe_readiness_capture::{CaptureMetadata, GuidHobDataSerDe};
+    use flate2::{Compression, write::GzEncoder};
+
+    const Q35_CAPTURE: &str = include_str!("tests/data/q35_capture.json");
+
+    #[test]
+    fn test_passes_skip_filtered_kinds() {
+        let filter = KindFilter::new(&[], &["MalformedGuid".to_string()]).unwrap();
+        let mut passes = Passes::new(ValidationReport::new(), 0, &filter, None);
+        let mut ran = Vec::new();
+        passes
+            .run("validate_guid_strings", &["MalformedGuid"], || {
+                ran.push("validate_guid_strings");
+                Ok(ValidationReport::new())
+            })
+            .unwrap();
+        passes
+            .run("validate_guid_hobs", &["MissingRequiredGuidHob", "ProhibitedGuidHob"], || {
+                ran.push("validate_guid_hobs");
+                Ok(ValidationReport::new())
+            })
+            .unwrap();
+        assert_eq!(ran, ["validate_guid_hobs"]);
+    }
+
+    #[test]
+    fn test_verify_checksum() {
+        let mut capture: DxeReadinessCaptureSerDe = serde_json::from_str(Q35_CAPTURE).unwrap();
+        assert!(verify_checksum("q35", &capture).is_ok());
+
+        capture.checksum = Some(capture.compute_checksum().unwrap());
+        let round_tripped: DxeReadinessCaptureSerDe =
+            serde_json::from_str(&serde_json::to_string_pretty(&capture).unwrap()).unwrap();
+        assert!(verify_checksum("q35", &round_tripped).is_ok());
+
+        capture.fv_list.pop();
+        assert!(matches!(verify_checksum("q35", &capture), Err(ValidationAppError::ChecksumMismatch(..))));
+
+        // The sidecars and metadata are covered as well
+        let mut capture: DxeReadinessCaptureSerDe = serde_json::from_str(Q35_CAPTURE).unwrap();
+        capture.checksum = Some(capture.compute_checksum().unwrap());
+        capture.guid_hob_data.push(GuidHobDataSerDe { name: "guid".to_string(), data: vec![0] });
+        assert!(matches!(verify_checksum("q35", &capture), Err(ValidationAppError::ChecksumMismatch(..))));
+        capture.guid_hob_data.clear();
+        assert!(verify_checksum("q35", &capture).is_ok());
+        capture.metadata = Some(CaptureMetadata::default());
+        assert!(matches!(verify_checksum("q35", &capture), Err(ValidationAppError::ChecksumMismatch(..))));
+    }
+
+    #[test]
+    fn test_guid_hob_data_round_trip() {
+        let mut capture: DxeReadinessCaptureSerDe = serde_json::from_str(Q35_CAPTURE).unwrap();
+        assert!(capture.guid_hob_data.is_empty());
+        let guid_hob_data = vec![GuidHobDataSerDe {
+            name: "123e4567-e89b-12d3-a456-426614174000".to_string(),
+            data: vec![0x00, 0x7f, 0x80, 0xff],
+        }];
+        capture.guid_hob_data = guid_hob_data.clone();
+
+        let json = serde_json::to_string(&capture).unwrap();
+        let round_tripped: DxeReadinessCaptureSerDe = deserialize_capture("q35", json.as_bytes()).unwrap();
+        assert_eq!(round_tripped.guid_hob_data, guid_hob_data);
+
+        let cbor = capture.to_cbor().unwrap();
+        let round_tripped: DxeReadinessCaptureSerDe = deserialize_capture("q35", &cbor).unwrap();
+        assert_eq!(round_tripped.guid_hob_data, guid_hob_data);
+    }
+
+    #[test]
+    fn test_validate_capture() {
+        let capture: DxeReadinessCaptureSerDe = serde_json::from_str(Q35_CAPTURE).unwrap();
+        let report = validate_capture(&capture).unwrap();
+        assert_eq!(report.violation_count(), 7);
+
+        let empty = DxeReadinessCaptureSerDe { hob_list: vec![], ..capture };
+        assert!(matches!(validate_capture(&empty), Err(ValidationAppError::EmptyHobList)));
+    }
+
+    #[test]
+    fn test_count_violations() {
+        let capture: DxeReadinessCaptureSerDe = serde_json::from_str(Q35_CAPTURE).unwrap();
+        let mut app = ValidationApp {
+            args: CommandLine { count_only: true, ..Default::default() },
+            data: vec![("a".to_string(), Ok(capture.clone())), ("b".to_string(), Ok(capture))],
+            filter: KindFilter::default(),
+            baseline: None,
+        };
+        assert_eq!(app.exit_code_mode(), ExitCodeMode::Count);
+
+        let mut out = Vec::new();
+        let result = app.count_violations(&mut out);
+        assert!(matches!(result, Err(ValidationAppError::ValidationErrors(count)) if count.total() == 14));
+        assert_eq!(String::from_utf8(out).unwrap(), "14\n");
+
+        app.data.push(("c".to_string(), Err(ValidationAppError::JSONFileNotFound("c".to_string()))));
+        let mut out = Vec::new();
+        assert!(matches!(app.count_violations(&mut out), Err(ValidationAppError::JSONFileNotFound(_))));
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn test_normalize_csv_output() {
+        let capture: DxeReadinessCaptureSerDe = serde_json::from_str(Q35_CAPTURE).unwrap();
+        let path = env::temp_dir().join(format!("dxe_readiness_normalize_csv_{}.json", std::process::id()));
+        let app = ValidationApp {
+            args: CommandLine {
+                normalize: true,
+                output: OutputFormat::Csv,
+                output_file: Some(path.display().to_string()),
+                ..Default::default()
+            },
+            data: vec![("a".to_string(), Ok(capture))],
+            filter: KindFilter::default(),
+            baseline: None,
+        };
+
+        // The normalized capture is JSON, without the CSV header row
+        app.validate().unwrap();
+        let output = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert!(!output.contains(CSV_HEADER));
+        assert!(serde_json::from_str::<DxeReadinessCaptureSerDe>(&output).is_ok());
+    }
+
+    #[test]
+    fn test_deserialize_capture_error_path() {
+        let mut capture: Value = serde_json::from_str(Q35_CAPTURE).unwrap();
+        capture["fv_list"][0]["files"][3]["length"] = Value::String("not a length".to_string());
+        let content = serde_json::to_string_pretty(&capture).unwrap();
+
+        let err = deserialize_capture::<DxeReadinessCaptureSerDe>("q35", content.as_bytes()).unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("fv_list[0].files[3].length: "), "{}", message);
+        assert!(message.contains(" line "), "{}", message);
+
+        let mut capture: Value = serde_json::from_str(Q35_CAPTURE).unwrap();
+        capture["hob_list"][2] = serde_json::json!({ "type": "guid_extension", "name": 7 });
+        let content = serde_json::to_string(&capture).unwrap();
+        let err = deserialize_capture::<DxeReadinessCaptureSerDe>("q35", content.as_bytes()).unwrap_err();
+        assert!(err.to_string().contains("hob_list[2]"), "{}", err);
+    }
+
+    #[test]
+    fn test_deserialize_cbor_capture() {
+        let mut capture: DxeReadinessCaptureSerDe = serde_json::from_str(Q35_CAPTURE).unwrap();
+        capture.checksum = Some(capture.compute_checksum().unwrap());
+        let cbor = capture.to_cbor().unwrap();
+        assert!(is_cbor_capture(&cbor));
+        assert!(cbor.len() < Q35_CAPTURE.len() / 2);
+
+        // Raw CBOR and base64 CBOR as printed to the serial log
+        let base64 = format!("{}\n", STANDARD.encode(&cbor));
+        for content in [cbor.clone(), base64.into_bytes()] {
+            let content = decode_base64_capture(content);
+            let decoded: DxeReadinessCaptureSerDe = deserialize_capture("q35", &content).unwrap();
+            assert!(verify_checksum("q35", &decoded).is_ok());
+            assert_eq!(decoded.hob_list.len(), capture.hob_list.len());
+        }
+
+        let json = decode_base64_capture(Q35_CAPTURE.as_bytes().to_vec());
+        assert!(deserialize_capture::<CaptureVersion>("q35", &json).is_ok());
+        assert!(matches!(
+            deserialize_capture::<CaptureVersion>("q35", &cbor[..cbor.len() / 2]),
+            Err(ValidationAppError::CborDeserializationFailed(..))
+        ));
+        assert!(matches!(
+            deserialize_capture::<CaptureVersion>("q35", &decode_base64_capture(b"not a capture".to_vec())),
+            Err(ValidationAppError::JSONSerializationFailed(..))
+        ));
+    }
+
+    #[test]
+    fn test_decompress_gzip_capture() {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(Q35_CAPTURE.as_bytes()).unwrap();
+        let gzip = encoder.finish().unwrap();
+
+        assert_eq!(decompress_gzip_capture("q35", gzip.clone()).unwrap(), Q35_CAPTURE.as_bytes());
+        assert_eq!(decompress_gzip_capture("q35", Q35_CAPTURE.as_bytes().to_vec()).unwrap(), Q35_CAPTURE.as_bytes());
+        assert!(matches!(
+            decompress_gzip_capture("q35", gzip[..gzip.len() / 2].to_vec()),
+            Err(ValidationAppError::JSONFileContentError(..))
+        ));
+    }
+
+    #[test]
+    fn test_capture_paths() {
+        let root = env::temp_dir().join(format!("dxe_readiness_capture_paths_{}", std::process::id()));
+        let nested = root.join("nested");
+        fs::create_dir_all(&nested).unwrap();
+        for file in [root.join("b.json"), root.join("a.JSON"), root.join("notes.txt"), nested.join("c.json")] {
+            fs::write(file, "{}").unwrap();
+        }
+        let path = |file: &Path| file.display().to_string();
+        let root_path = path(&root);
+
+        assert_eq!(capture_paths(STDIN_FILENAME, false).unwrap(), vec![STDIN_FILENAME]);
+        assert_eq!(capture_paths("missing.json", false).unwrap(), vec!["missing.json"]);
+        assert_eq!(
+            capture_paths(&root_path, false).unwrap(),
+            vec![path(&root.join("a.JSON")), path(&root.join("b.json"))]
+        );
+        assert_eq!(capture_paths(&root_path, true).unwrap().len(), 3);
+        assert!(matches!(capture_paths(&path(&nested), false), Ok(paths) if paths.len() == 1));
+
+        fs::remove_file(nested.join("c.json")).unwrap();
+        assert!(matches!(capture_paths(&path(&nested), true), Err(ValidationAppError::JSONFileNotFound(_))));
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_capture_version() {
+        let version: CaptureVersion = serde_json::from_str(Q35_CAPTURE).unwrap();
+        assert_eq!(version.schema_version, SUPPORTED_SCHEMA_VERSION);
+
+        // Captures from before the version was recorded default to 0
+        let version: CaptureVersion = serde_json::from_str(r#"{"hob_list": [], "fv_list": []}"#).unwrap();
+        assert_eq!(version.schema_version, 0);
+    }
 }
@@ -0,0 +1,192 @@
+//! Validation logic spanning the HOB and Firmware Volume (FV) lists of a
+//! capture.
+//!
+//! ## License
+//!
+//! Copyright (c) Microsoft Corporation.
+//!
+//! SPDX-License-Identifier: Apache-2.0
+//!
+use super::{Passes, ValidationResult};
+use crate::{
+    validation_kind::{CrossValidationKind, KindFilter, ValidationKind},
+    validation_report::{FailFast, ValidationReport},
+    validator::Validator,
+};
+use dxe_readiness_capture::DxeReadinessCaptureSerDe;
+use patina::pi::serializable::serializable_hob::HobSerDe;
+use std::collections::BTreeSet;
+
+/// Performs the validations that correlate the HOB list with the FV list of a
+/// capture, which neither the `HobValidator` nor the `FvValidator` can express
+/// on its own.
+pub struct CrossValidator<'a> {
+    data: &'a DxeReadinessCaptureSerDe,
+    filter: KindFilter,
+    // Set by --fail-fast, the passes stop at the first violation reported
+    fail_fast: Option<FailFast>,
+}
+
+impl<'a> CrossValidator<'a> {
+    pub fn new(data: &'a DxeReadinessCaptureSerDe) -> Self {
+        CrossValidator { data, filter: KindFilter::default(), fail_fast: None }
+    }
+
+    /// Restricts the validation kinds that may report violations.
+    pub fn with_filter(mut self, filter: KindFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Skips the remaining passes once one records a violation `fail_fast`
+    /// stops at.
+    pub fn with_fail_fast(mut self, fail_fast: Option<FailFast>) -> Self {
+        self.fail_fast = fail_fast;
+        self
+    }
+
+    /// Checks that every firmware volume agrees with the FV HOB that
+    /// referenced it. An FV is matched to the HOB with the same base address,
+    /// or else to a HOB whose range contains its base address. A length that
+    /// differs from the HOB, or a base address that only falls within a HOB,
+    /// usually means a relocated or double counted FV.
+    pub(super) fn validate_fv_hob_consistency(&self) -> ValidationResult<'a> {
+        let mut validation_report = ValidationReport::new();
+        let fv_hobs = fv_hob_ranges(&self.data.hob_list);
+
+        for fv in &self.data.fv_list {
+            let hob = fv_hobs.iter().find(|(base_address, _)| *base_address == fv.fv_base_address).or_else(|| {
+                fv_hobs.iter().find(|(base_address, length)| {
+                    (*base_address..base_address.saturating_add(*length)).contains(&fv.fv_base_address)
+                })
+            });
+            if let Some(&(hob_base_address, hob_length)) = hob
+                && (hob_base_address != fv.fv_base_address || hob_length != fv.fv_length as u64)
+            {
+                validation_report.add_violation(ValidationKind::Cross(CrossValidationKind::FvSizeMismatch {
+                    fv,
+                    hob_base_address,
+                    hob_length,
+                }));
+            }
+        }
+
+        Ok(validation_report)
+    }
+
+    /// Checks that every FV HOB has a firmware volume with its base address in
+    /// the capture. The capture tool skips FVs it cannot parse, so a missing
+    /// FV points at a corrupted or partially programmed firmware volume.
+    pub(super) fn validate_fv_hobs_captured(&self) -> ValidationResult<'a> {
+        let mut validation_report = ValidationReport::new();
+        let captured: BTreeSet<u64> = self.data.fv_list.iter().map(|fv| fv.fv_base_address).collect();
+
+        for (base_address, length) in fv_hob_ranges(&self.data.hob_list) {
+            if !captured.contains(&base_address) {
+                validation_report.add_violation(ValidationKind::Cross(CrossValidationKind::UnparsedFirmwareVolume {
+                    base_address,
+                    length,
+                }));
+            }
+        }
+
+        Ok(validation_report)
+    }
+}
+
+/// Returns the base address and length of every FV HOB in `hob_list`.
+fn fv_hob_ranges(hob_list: &[HobSerDe]) -> Vec<(u64, u64)> {
+    hob_list
+        .iter()
+        .filter_map(|hob| match hob {
+            HobSerDe::FirmwareVolume { base_address, length } => Some((*base_address, *length)),
+            _ => None,
+        })
+        .collect()
+}
+
+impl<'a> Validator<'a> for CrossValidator<'a> {
+    fn validate(&self) -> ValidationResult<'a> {
+        let validation_report = ValidationReport::new();
+        // An empty FV list is already reported by the FvValidator, reporting
+        // every FV HOB as not captured would only repeat that finding.
+        if self.data.fv_list.is_empty() {
+            return Ok(validation_report);
+        }
+        let items = self.data.fv_list.len();
+
+        let mut passes = Passes::new(validation_report, items, &self.filter, self.fail_fast.as_ref());
+        passes.run("validate_fv_hob_consistency", &["FvSizeMismatch"], || self.validate_fv_hob_consistency())?;
+        passes.run("validate_fv_hobs_captured", &["UnparsedFirmwareVolume"], || self.validate_fv_hobs_captured())?;
+        Ok(passes.finish())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use patina::pi::serializable::serializable_fv::FirmwareVolumeSerDe;
+
+    fn fv(fv_base_address: u64, fv_length: usize) -> FirmwareVolumeSerDe {
+        FirmwareVolumeSerDe { fv_name: "FV1".to_string(), fv_length, fv_base_address, fv_attributes: 0, files: vec![] }
+    }
+
+    fn capture(hob_list: Vec<HobSerDe>, fv_list: Vec<FirmwareVolumeSerDe>) -> DxeReadinessCaptureSerDe {
+        DxeReadinessCaptureSerDe {
+            schema_version: 0,
+            metadata: None,
+            hob_list,
+            fv_list,
+            checksum: None,
+            unknown_hobs: vec![],
+            guid_hob_data: vec![],
+            pe_characteristics: vec![],
+        }
+    }
+
+    #[test]
+    fn test_validate_fv_hob_consistency() {
+        let hob_list = vec![
+            HobSerDe::FirmwareVolume { base_address: 0x10000, length: 0x10000 },
+            HobSerDe::FirmwareVolume { base_address: 0x40000, length: 0x20000 },
+        ];
+
+        // Matching FV, length mismatch, base inside a HOB and FV without a HOB
+        let fv_list = vec![fv(0x10000, 0x10000), fv(0x40000, 0x10000), fv(0x50000, 0x10000), fv(0x80000, 0x10000)];
+        let data = capture(hob_list, fv_list);
+        let validator = CrossValidator::new(&data);
+        let validation_report = validator.validate_fv_hob_consistency().unwrap();
+        assert_eq!(validation_report.violation_count(), 2);
+        assert!(validation_report.to_json().to_string().contains("FvSizeMismatch"));
+    }
+
+    #[test]
+    fn test_validate_fv_hobs_captured() {
+        let hob_list = vec![
+            HobSerDe::FirmwareVolume { base_address: 0x10000, length: 0x10000 },
+            HobSerDe::FirmwareVolume { base_address: 0x40000, length: 0x20000 },
+        ];
+        let data = capture(hob_list, vec![fv(0x10000, 0x10000)]);
+
+        let validator = CrossValidator::new(&data);
+        let validation_report = validator.validate_fv_hobs_captured().unwrap();
+        assert_eq!(validation_report.violation_count(), 1);
+        assert!(validation_report.to_json().to_string().contains("UnparsedFirmwareVolume"));
+    }
+
+    #[test]
+    fn test_cross_validator() {
+        let hob_list = vec![HobSerDe::FirmwareVolume { base_address: 0x10000, length: 0x10000 }];
+        let data = capture(hob_list.clone(), vec![fv(0x10000, 0x8000)]);
+        let validation_report = CrossValidator::new(&data).validate().unwrap();
+        assert_eq!(validation_report.violation_count(), 1);
+        assert_eq!(validation_report.violation_counts_at(Default::default()).cross, 1);
+
+        let filter = KindFilter::new(&[], &["FvSizeMismatch".to_string()]).unwrap();
+        assert_eq!(CrossValidator::new(&data).with_filter(filter).validate().unwrap().violation_count(), 0);
+
+        // An empty FV list is left to the FvValidator
+        let data = capture(hob_list, vec![]);
+        assert_eq!(CrossValidator::new(&data).validate().unwrap().violation_count(), 0);
+    }
+}
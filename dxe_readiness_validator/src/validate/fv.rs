@@ -6,39 +6,130 @@
 //!
 //! SPDX-License-Identifier: Apache-2.0
 //!
-use super::ValidationResult;
+use super::{Passes, ValidationResult};
 use crate::{
     ValidationAppError,
-    validation_kind::{FvValidationKind, ValidationKind},
-    validation_report::ValidationReport,
+    guid_names::is_canonical_guid,
+    profile::ARM64_RUNTIME_DRIVER_ALIGNMENT,
+    validation_kind::{FvValidationKind, KindFilter, ValidationKind},
+    validation_report::{FailFast, ValidationReport},
     validator::Validator,
 };
-use goblin::pe::{header::COFF_MACHINE_ARM64, subsystem::IMAGE_SUBSYSTEM_EFI_RUNTIME_DRIVER};
+use dxe_readiness_capture::PeCharacteristicsSerDe;
+use goblin::pe::{
+    characteristic::IMAGE_FILE_RELOCS_STRIPPED,
+    header::{COFF_MACHINE_ARM64, COFF_MACHINE_X86_64},
+    subsystem::IMAGE_SUBSYSTEM_EFI_RUNTIME_DRIVER,
+};
 use patina::{
     base::UEFI_PAGE_SIZE,
-    pi::serializable::{format_guid, serializable_fv::FirmwareVolumeSerDe},
+    pi::serializable::{
+        format_guid,
+        serializable_fv::{FirmwareFileSerDe, FirmwareVolumeSerDe},
+    },
 };
 use r_efi::efi::Guid;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// EDK II compression tool names accepted by `--allowed-compression`, with the
+/// compression type the capture records for the sections they produce.
+const COMPRESSION_TOOL_NAMES: &[(&str, &str)] = &[
+    ("BrotliCompress", "Brotli Compressed"),
+    ("TianoCompress", "Tiano Compressed"),
+    ("LzmaCompress", "LZMA Compressed"),
+    ("LzmaF86Compress", "LZMA F86 Compressed"),
+];
 
 /// Performs validation on a list of firmware volumes to check for violations of
 /// Patina requirements.
 pub struct FvValidator<'a> {
     fv_list: &'a Vec<FirmwareVolumeSerDe>,
+    filter: KindFilter,
+    // Set by --fail-fast, the passes stop at the first violation reported
+    fail_fast: Option<FailFast>,
+    // Compression types allowed by --allowed-compression. When not given, only
+    // the LZMA rule applies.
+    allowed_compression: Option<Vec<String>>,
+    // Set by --allow-empty, an empty FV list is a note rather than an error
+    allow_empty: bool,
+    // Section alignment required of AArch64 runtime drivers, set by --runtime-alignment
+    runtime_driver_alignment: usize,
+    // Set by --profile, any MM module is a violation
+    forbid_mm: bool,
+    // COFF characteristics of the PE32 sections, recorded next to the FV list
+    pe_characteristics: &'a [PeCharacteristicsSerDe],
 }
 
 impl<'a> FvValidator<'a> {
     pub fn new(fv_list: &'a Vec<FirmwareVolumeSerDe>) -> Self {
-        FvValidator { fv_list }
+        FvValidator {
+            fv_list,
+            filter: KindFilter::default(),
+            fail_fast: None,
+            allowed_compression: None,
+            allow_empty: false,
+            runtime_driver_alignment: ARM64_RUNTIME_DRIVER_ALIGNMENT,
+            forbid_mm: false,
+            pe_characteristics: &[],
+        }
+    }
+
+    /// Restricts the validation kinds that may report violations.
+    pub fn with_filter(mut self, filter: KindFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Skips the remaining passes once one records a violation `fail_fast`
+    /// stops at.
+    pub fn with_fail_fast(mut self, fail_fast: Option<FailFast>) -> Self {
+        self.fail_fast = fail_fast;
+        self
+    }
+
+    /// Replaces the default LZMA rule with an allowlist of compression types.
+    /// An empty list keeps the default.
+    pub fn with_allowed_compression(mut self, allowed_compression: &[String]) -> Self {
+        self.allowed_compression = (!allowed_compression.is_empty()).then(|| allowed_compression.to_vec());
+        self
+    }
+
+    /// Makes an empty FV list produce an empty report with a note instead of
+    /// an error.
+    pub fn with_allow_empty(mut self, allow_empty: bool) -> Self {
+        self.allow_empty = allow_empty;
+        self
+    }
+
+    /// Overrides the section alignment required of AArch64 runtime drivers.
+    pub fn with_runtime_driver_alignment(mut self, alignment: usize) -> Self {
+        self.runtime_driver_alignment = alignment;
+        self
+    }
+
+    /// Reports every MM module, Standalone or not, for profiles of platforms
+    /// that boot without MM.
+    pub fn with_forbid_mm(mut self, forbid_mm: bool) -> Self {
+        self.forbid_mm = forbid_mm;
+        self
     }
 
-    /// Checks firmware volumes for files that use traditional SMM types and
-    /// reports violations if found.
-    pub(super) fn validate_fv_for_traditional_smm(&self) -> ValidationResult<'_> {
+    /// Supplies the COFF characteristics recorded in `pe_characteristics`.
+    /// Captures from older capture tools carry none, so nothing is checked.
+    pub fn with_pe_characteristics(mut self, pe_characteristics: &'a [PeCharacteristicsSerDe]) -> Self {
+        self.pe_characteristics = pe_characteristics;
+        self
+    }
+
+    /// Checks firmware volumes for files that use combined traditional SMM
+    /// types and reports violations if found. Traditional MM drivers and cores
+    /// are left to `validate_mm_is_standalone`, so each file is reported once.
+    pub(super) fn validate_fv_for_traditional_smm(&self) -> ValidationResult<'a> {
         let mut validation_report = ValidationReport::new();
 
         self.fv_list.iter().for_each(|fv| {
             fv.files.iter().for_each(|file| match file.file_type.as_str() {
-                "CombinedPeimDriver" | "Mm" | "CombinedMmDxe" | "MmCore" => validation_report
+                "CombinedPeimDriver" | "CombinedMmDxe" => validation_report
                     .add_violation(ValidationKind::Fv(FvValidationKind::UsesTraditionalSmm { file, fv })),
                 _ => (),
             });
@@ -47,9 +138,56 @@ impl<'a> FvValidator<'a> {
         Ok(validation_report)
     }
 
+    /// Checks that the MM modules of the platform, if any, are Standalone MM
+    /// modules. Each traditional MM driver or core is pointed at its Standalone
+    /// MM counterpart, which tracks a platform migrating one module at a time.
+    pub(super) fn validate_mm_is_standalone(&self) -> ValidationResult<'a> {
+        let mut validation_report = ValidationReport::new();
+
+        for fv in self.fv_list {
+            for file in fv.files.iter().filter(|file| matches!(file.file_type.as_str(), "Mm" | "MmCore")) {
+                validation_report
+                    .add_violation(ValidationKind::Fv(FvValidationKind::NonStandaloneMmPresent { fv, file }));
+            }
+        }
+
+        Ok(validation_report)
+    }
+
+    /// Checks the order of the sections of every firmware file. The PI spec
+    /// leaves the order open, so only an image section following the
+    /// UserInterface or Version section, which build tools always emit last,
+    /// is reported.
+    pub(super) fn validate_section_ordering(&self) -> ValidationResult<'a> {
+        const IMAGE_SECTION_TYPES: &[&str] = &["Pe32", "Pe32 (unparseable)", "Te", "Pic"];
+        const TERMINATING_SECTION_TYPES: &[&str] = &["UserInterface", "Version"];
+        let mut validation_report = ValidationReport::new();
+
+        for fv in self.fv_list {
+            for file in &fv.files {
+                let terminated_at = file
+                    .sections
+                    .iter()
+                    .position(|section| TERMINATING_SECTION_TYPES.contains(&section.section_type.as_str()));
+                let Some(terminated_at) = terminated_at else {
+                    continue;
+                };
+                if file.sections[terminated_at..]
+                    .iter()
+                    .any(|section| IMAGE_SECTION_TYPES.contains(&section.section_type.as_str()))
+                {
+                    validation_report
+                        .add_violation(ValidationKind::Fv(FvValidationKind::SuspiciousSectionOrdering { fv, file }));
+                }
+            }
+        }
+
+        Ok(validation_report)
+    }
+
     /// Checks firmware volumes for presence of combined driver files and
     /// reports violations if any are found.
-    pub(super) fn validate_fv_for_combined_drivers(&self) -> ValidationResult<'_> {
+    pub(super) fn validate_fv_for_combined_drivers(&self) -> ValidationResult<'a> {
         let mut validation_report = ValidationReport::new();
 
         self.fv_list.iter().for_each(|fv| {
@@ -65,7 +203,7 @@ impl<'a> FvValidator<'a> {
 
     /// Checks firmware volumes for presence of prohibited Apriori files by
     /// their GUIDs and reports violations if found.
-    pub(super) fn validate_fv_for_apriori_file(&self) -> ValidationResult<'_> {
+    pub(super) fn validate_fv_for_apriori_file(&self) -> ValidationResult<'a> {
         let mut validation_report = ValidationReport::new();
 
         let pei_apriori_file_name_guid = format_guid(&Guid::from_fields(
@@ -100,8 +238,7 @@ impl<'a> FvValidator<'a> {
     /// Validates sections within firmware volumes for LZMA compression.
     /// For PE images, validates that the section alignment is correct.
     /// Reports violations if any are found.
-    pub(super) fn validate_fv_file_sections(&self) -> ValidationResult<'_> {
-        const FV_ARM64_RUNTIME_DRIVER_ALIGNMENT: usize = 0x10000;
+    pub(super) fn validate_fv_file_sections(&self) -> ValidationResult<'a> {
         let mut validation_report = ValidationReport::new();
 
         for fv in self.fv_list {
@@ -112,7 +249,7 @@ impl<'a> FvValidator<'a> {
                     continue;
                 }
                 for section in &file.sections {
-                    if section.compression_type.starts_with("LZMA ") {
+                    if self.allowed_compression.is_none() && section.compression_type.starts_with("LZMA ") {
                         validation_report.add_violation(ValidationKind::Fv(FvValidationKind::LzmaCompressedSections {
                             fv,
                             file,
@@ -123,18 +260,18 @@ impl<'a> FvValidator<'a> {
                     if section.section_type == "Pe32"
                         && let Some(pe_header_info) = &section.pe_info
                     {
-                        // ARM64 DXE_RUNTIME_DRIVER needs 64k alignment.
+                        // ARM64 DXE_RUNTIME_DRIVER needs 64k alignment, unless --runtime-alignment says otherwise.
                         if pe_header_info.machine == COFF_MACHINE_ARM64
                             && pe_header_info.subsystem == IMAGE_SUBSYSTEM_EFI_RUNTIME_DRIVER
                             && !(pe_header_info.section_alignment as usize)
-                                .is_multiple_of(FV_ARM64_RUNTIME_DRIVER_ALIGNMENT)
+                                .is_multiple_of(self.runtime_driver_alignment)
                         {
                             validation_report.add_violation(ValidationKind::Fv(
                                 FvValidationKind::InvalidSectionAlignment {
                                     fv,
                                     file,
                                     section,
-                                    required_alignment: FV_ARM64_RUNTIME_DRIVER_ALIGNMENT,
+                                    required_alignment: self.runtime_driver_alignment,
                                 },
                             ));
                         }
@@ -158,31 +295,312 @@ impl<'a> FvValidator<'a> {
 
         Ok(validation_report)
     }
+
+    /// Checks that every DXE driver carries a DXE_DEPEX section, since A Priori
+    /// files cannot be used to order dispatch. The DXE core is never
+    /// dispatched and so needs no depex.
+    pub(super) fn validate_drivers_have_depex(&self) -> ValidationResult<'a> {
+        let mut validation_report = ValidationReport::new();
+
+        self.fv_list.iter().for_each(|fv| {
+            fv.files.iter().for_each(|file| {
+                if file.file_type == "Driver" && !file.sections.iter().any(|section| section.section_type == "DxeDepex")
+                {
+                    validation_report
+                        .add_violation(ValidationKind::Fv(FvValidationKind::DriverMissingDepex { fv, file }));
+                }
+            });
+        });
+
+        Ok(validation_report)
+    }
+
+    /// Checks that every parsed PE32 image of a DXE module targets a 64-bit
+    /// machine supported by Patina. PEI modules are skipped, as they may
+    /// legitimately be built for a 32-bit PEI phase.
+    pub(super) fn validate_pe_machine_type(&self) -> ValidationResult<'a> {
+        const SUPPORTED_MACHINES: &[u16] = &[COFF_MACHINE_X86_64, COFF_MACHINE_ARM64];
+        const ELIGIBLE_MODULE_TYPES: &[&str] = &["Driver", "Application", "DxeCore"];
+        let mut validation_report = ValidationReport::new();
+
+        for fv in self.fv_list {
+            for file in &fv.files {
+                if !ELIGIBLE_MODULE_TYPES.contains(&file.file_type.as_str()) {
+                    continue;
+                }
+                for section in &file.sections {
+                    if let Some(pe_header_info) = &section.pe_info
+                        && !SUPPORTED_MACHINES.contains(&pe_header_info.machine)
+                    {
+                        validation_report.add_violation(ValidationKind::Fv(FvValidationKind::UnsupportedPeMachine {
+                            fv,
+                            file,
+                            section,
+                            machine: pe_header_info.machine,
+                        }));
+                    }
+                }
+            }
+        }
+
+        Ok(validation_report)
+    }
+
+    /// Checks that the firmware volumes carry no MM core or MM driver at all.
+    /// Combined MM modules are left to `validate_fv_for_traditional_smm`.
+    pub(super) fn validate_mm_absent(&self) -> ValidationResult<'a> {
+        let mut validation_report = ValidationReport::new();
+
+        for fv in self.fv_list {
+            for file in fv
+                .files
+                .iter()
+                .filter(|file| matches!(file.file_type.as_str(), "Mm" | "MmCore" | "MmStandalone" | "MmCoreStandalone"))
+            {
+                validation_report.add_violation(ValidationKind::Fv(FvValidationKind::MmPresent { fv, file }));
+            }
+        }
+
+        Ok(validation_report)
+    }
+
+    /// Checks that no DXE driver image has its relocations stripped. The DXE
+    /// core relocates every driver it loads, so an image linked with `/FIXED`
+    /// fails to dispatch.
+    pub(super) fn validate_pe_relocations(&self) -> ValidationResult<'a> {
+        let mut validation_report = ValidationReport::new();
+
+        for pe in self.pe_characteristics {
+            if pe.characteristics & IMAGE_FILE_RELOCS_STRIPPED == 0 {
+                continue;
+            }
+            let Some(fv) = self.fv_list.iter().find(|fv| fv.fv_base_address == pe.fv_base_address) else {
+                continue;
+            };
+            if let Some(file) = fv.files.get(pe.file_index)
+                && file.file_type == "Driver"
+                && let Some(section) = file.sections.get(pe.section_index)
+            {
+                validation_report.add_violation(ValidationKind::Fv(FvValidationKind::RelocationsStripped {
+                    fv,
+                    file,
+                    section,
+                }));
+            }
+        }
+
+        Ok(validation_report)
+    }
+
+    /// Checks that every firmware file has a non-zero length that is large
+    /// enough to hold its sections. Pad and raw files carry no sections, and
+    /// the sections of a compressed or GUID defined encapsulation are listed
+    /// decompressed and can legitimately exceed the file length, so such files
+    /// are only checked for a zero length.
+    pub(super) fn validate_file_length_sanity(&self) -> ValidationResult<'a> {
+        const SECTIONLESS_FILE_TYPES: &[&str] = &["Raw", "FfsPad"];
+        const ENCAPSULATION_SECTION_TYPES: &[&str] = &["Compression", "GuidDefined"];
+        let mut validation_report = ValidationReport::new();
+
+        self.fv_list.iter().for_each(|fv| {
+            fv.files.iter().for_each(|file| {
+                let sized_by_sections = !SECTIONLESS_FILE_TYPES.contains(&file.file_type.as_str())
+                    && !file
+                        .sections
+                        .iter()
+                        .any(|section| ENCAPSULATION_SECTION_TYPES.contains(&section.section_type.as_str()));
+                let section_length: usize = file.sections.iter().map(|section| section.length).sum();
+                if file.length == 0 || (sized_by_sections && section_length > file.length) {
+                    validation_report
+                        .add_violation(ValidationKind::Fv(FvValidationKind::InconsistentFileLength { fv, file }));
+                }
+            });
+        });
+
+        Ok(validation_report)
+    }
+
+    /// Checks that every firmware file name is a GUID in the canonical
+    /// 8-4-4-4-12 format. Other names only come from hand edited or truncated
+    /// captures.
+    pub(super) fn validate_guid_strings(&self) -> ValidationResult<'a> {
+        let mut validation_report = ValidationReport::new();
+        for fv in self.fv_list {
+            for file in fv.files.iter().filter(|file| !is_canonical_guid(&file.name)) {
+                validation_report.add_violation(ValidationKind::Fv(FvValidationKind::MalformedFileGuid { fv, file }));
+            }
+        }
+        Ok(validation_report)
+    }
+
+    /// Checks that no two firmware files share a GUID, within a firmware
+    /// volume or across volumes. GUIDs are compared case-insensitively since
+    /// their formatting can vary between producers. Pad files all share the
+    /// same GUID and are skipped.
+    pub(super) fn validate_duplicate_file_guids(&self) -> ValidationResult<'a> {
+        let mut validation_report = ValidationReport::new();
+
+        let mut occurrences: BTreeMap<String, Vec<(&FirmwareVolumeSerDe, &FirmwareFileSerDe)>> = BTreeMap::new();
+        self.fv_list.iter().for_each(|fv| {
+            fv.files.iter().filter(|file| file.file_type != "FfsPad").for_each(|file| {
+                occurrences.entry(file.name.to_lowercase()).or_default().push((fv, file));
+            });
+        });
+
+        for files in occurrences.values().filter(|files| files.len() > 1) {
+            for (index, &(fv, file)) in files.iter().enumerate() {
+                let other_fvs: BTreeSet<&str> = files
+                    .iter()
+                    .enumerate()
+                    .filter(|(other_index, _)| *other_index != index)
+                    .map(|(_, (other_fv, _))| other_fv.fv_name.as_str())
+                    .collect();
+                validation_report.add_violation(ValidationKind::Fv(FvValidationKind::DuplicateFileGuid {
+                    fv,
+                    file,
+                    other_fvs: other_fvs.into_iter().collect(),
+                }));
+            }
+        }
+
+        Ok(validation_report)
+    }
+
+    /// Checks that every compressed section of a DXE module uses one of the
+    /// `allowed` compression types. Names match the captured compression type
+    /// case-insensitively, with or without its "Compressed" suffix, so both
+    /// `brotli` and `Brotli Compressed` allow Brotli. The EDK II tool names in
+    /// `COMPRESSION_TOOL_NAMES`, e.g. `TianoCompress`, are accepted as well.
+    /// Uncompressed sections and CRC32 sections, an integrity wrapper rather
+    /// than a compression, are always allowed. Sections recorded with a raw
+    /// GUID are left to `validate_unknown_guided_sections`.
+    pub(super) fn validate_compression_policy(&self, allowed: &[String]) -> ValidationResult<'a> {
+        const ELIGIBLE_MODULE_TYPES: &[&str] = &["Driver", "Application", "DxeCore"];
+        let allowed: Vec<&str> = allowed
+            .iter()
+            .map(|name| {
+                COMPRESSION_TOOL_NAMES
+                    .iter()
+                    .find(|(tool_name, _)| tool_name.eq_ignore_ascii_case(name))
+                    .map_or(name.as_str(), |(_, compression_type)| compression_type)
+            })
+            .collect();
+        let is_allowed = |compression_type: &str| {
+            let short_name = compression_type
+                .strip_suffix(" Compressed")
+                .or_else(|| compression_type.strip_suffix(" compressed"))
+                .unwrap_or(compression_type);
+            compression_type == "uncompressed"
+                || compression_type == "CRC32 Compressed"
+                || is_canonical_guid(compression_type)
+                || allowed
+                    .iter()
+                    .any(|name| name.eq_ignore_ascii_case(compression_type) || name.eq_ignore_ascii_case(short_name))
+        };
+        let mut validation_report = ValidationReport::new();
+
+        for fv in self.fv_list {
+            for file in &fv.files {
+                if !ELIGIBLE_MODULE_TYPES.contains(&file.file_type.as_str()) {
+                    continue;
+                }
+                for section in &file.sections {
+                    if !is_allowed(&section.compression_type) {
+                        validation_report.add_violation(ValidationKind::Fv(FvValidationKind::DisallowedCompression {
+                            fv,
+                            file,
+                            section,
+                            compression_type: &section.compression_type,
+                        }));
+                    }
+                }
+            }
+        }
+
+        Ok(validation_report)
+    }
+
+    /// Checks for GUID defined sections whose section definition GUID is not
+    /// one of the named algorithms. The capture records the raw GUID as the
+    /// compression type of such sections.
+    pub(super) fn validate_unknown_guided_sections(&self) -> ValidationResult<'a> {
+        let mut validation_report = ValidationReport::new();
+
+        for fv in self.fv_list {
+            for file in &fv.files {
+                for section in &file.sections {
+                    if is_canonical_guid(&section.compression_type) {
+                        validation_report.add_violation(ValidationKind::Fv(FvValidationKind::UnknownGuidedSection {
+                            fv,
+                            file,
+                            section,
+                        }));
+                    }
+                }
+            }
+        }
+
+        Ok(validation_report)
+    }
 }
 
-impl Validator for FvValidator<'_> {
-    fn validate(&self) -> ValidationResult<'_> {
+impl<'a> Validator<'a> for FvValidator<'a> {
+    fn validate(&self) -> ValidationResult<'a> {
         let mut validation_report = ValidationReport::new();
         if self.fv_list.is_empty() {
-            return Err(ValidationAppError::EmptyFvList);
+            if !self.allow_empty {
+                return Err(ValidationAppError::EmptyFvList);
+            }
+            validation_report.add_note(ValidationAppError::EmptyFvList.to_string());
+            return Ok(validation_report);
         }
+        let items = self.fv_list.iter().map(|fv| fv.files.len()).sum();
 
-        validation_report.append_report(self.validate_fv_for_traditional_smm()?);
-        validation_report.append_report(self.validate_fv_for_combined_drivers()?);
-        validation_report.append_report(self.validate_fv_file_sections()?);
-        validation_report.append_report(self.validate_fv_for_apriori_file()?);
-        Ok(validation_report)
+        let mut passes = Passes::new(validation_report, items, &self.filter, self.fail_fast.as_ref());
+        passes.run("validate_guid_strings", &["MalformedFileGuid"], || self.validate_guid_strings())?;
+        passes.run("validate_fv_for_traditional_smm", &["UsesTraditionalSmm"], || {
+            self.validate_fv_for_traditional_smm()
+        })?;
+        passes.run("validate_mm_is_standalone", &["NonStandaloneMmPresent"], || self.validate_mm_is_standalone())?;
+        if self.forbid_mm {
+            passes.run("validate_mm_absent", &["MmPresent"], || self.validate_mm_absent())?;
+        }
+        passes.run("validate_fv_for_combined_drivers", &["CombinedDriversPresent"], || {
+            self.validate_fv_for_combined_drivers()
+        })?;
+        passes.run("validate_fv_file_sections", &["LzmaCompressedSections", "InvalidSectionAlignment"], || {
+            self.validate_fv_file_sections()
+        })?;
+        passes
+            .run("validate_fv_for_apriori_file", &["ProhibitedAprioriFile"], || self.validate_fv_for_apriori_file())?;
+        passes.run("validate_drivers_have_depex", &["DriverMissingDepex"], || self.validate_drivers_have_depex())?;
+        passes.run("validate_section_ordering", &["SuspiciousSectionOrdering"], || self.validate_section_ordering())?;
+        passes.run("validate_pe_machine_type", &["UnsupportedPeMachine"], || self.validate_pe_machine_type())?;
+        passes.run("validate_pe_relocations", &["RelocationsStripped"], || self.validate_pe_relocations())?;
+        passes
+            .run("validate_file_length_sanity", &["InconsistentFileLength"], || self.validate_file_length_sanity())?;
+        passes.run("validate_duplicate_file_guids", &["DuplicateFileGuid"], || self.validate_duplicate_file_guids())?;
+        passes.run("validate_unknown_guided_sections", &["UnknownGuidedSection"], || {
+            self.validate_unknown_guided_sections()
+        })?;
+        if let Some(allowed_compression) = &self.allowed_compression {
+            passes.run("validate_compression_policy", &["DisallowedCompression"], || {
+                self.validate_compression_policy(allowed_compression)
+            })?;
+        }
+        Ok(passes.finish())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{profile::Profile, validation_kind::Severity, validation_report::Baseline};
     use goblin::pe::{
-        header::COFF_MACHINE_X86_64,
+        header::{COFF_MACHINE_X86, COFF_MACHINE_X86_64},
         subsystem::{IMAGE_SUBSYSTEM_EFI_BOOT_SERVICE_DRIVER, IMAGE_SUBSYSTEM_EFI_RUNTIME_DRIVER},
     };
-    use patina::pi::serializable::serializable_fv::{FirmwareFileSerDe, FirmwareSectionSerDe, PeHeaderInfo};
+    use patina::pi::serializable::serializable_fv::{FirmwareSectionSerDe, PeHeaderInfo};
 
     #[test]
     fn test_validate_fv_for_traditional_smm() {
@@ -230,6 +648,85 @@ mod tests {
         assert_ne!(validation_report.violation_count(), 0);
     }
 
+    #[test]
+    fn test_validate_section_ordering() {
+        let section = |section_type: &str| FirmwareSectionSerDe {
+            section_type: section_type.to_string(),
+            length: 0x20,
+            compression_type: "uncompressed".to_string(),
+            pe_info: None,
+        };
+        let file = |name: &str, sections| FirmwareFileSerDe {
+            name: name.to_string(),
+            file_type: "Driver".to_string(),
+            length: 512,
+            attributes: 0,
+            sections,
+        };
+
+        let fv_list = vec![FirmwareVolumeSerDe {
+            fv_name: "FV1".to_string(),
+            fv_length: 1024,
+            fv_base_address: 0x1000,
+            fv_attributes: 0,
+            files: vec![
+                file(
+                    "aaaaaaaa-0000-0000-0000-000000000001",
+                    vec![section("DxeDepex"), section("Pe32"), section("UserInterface"), section("Version")],
+                ),
+                file(
+                    "aaaaaaaa-0000-0000-0000-000000000002",
+                    vec![section("Pe32"), section("UserInterface"), section("Raw")],
+                ),
+                file("aaaaaaaa-0000-0000-0000-000000000003", vec![section("Raw")]),
+                file(
+                    "aaaaaaaa-0000-0000-0000-000000000004",
+                    vec![section("DxeDepex"), section("UserInterface"), section("Version"), section("Pe32")],
+                ),
+                file("aaaaaaaa-0000-0000-0000-000000000005", vec![section("Version"), section("Te")]),
+            ],
+        }];
+
+        let result = FvValidator::new(&fv_list).validate_section_ordering();
+        assert_eq!(result.unwrap().violation_count(), 2);
+    }
+
+    #[test]
+    fn test_validate_mm_is_standalone() {
+        let file = |name: &str, file_type: &str| FirmwareFileSerDe {
+            name: name.to_string(),
+            file_type: file_type.to_string(),
+            length: 256,
+            attributes: 0,
+            sections: vec![],
+        };
+        let fv = |files| FirmwareVolumeSerDe {
+            fv_name: "FV1".to_string(),
+            fv_length: 1024,
+            fv_base_address: 0x1000,
+            fv_attributes: 0,
+            files,
+        };
+
+        // +ve test - Standalone MM only, or no MM at all
+        let fv_list = vec![fv(vec![
+            file("aaaaaaaa-0000-0000-0000-000000000001", "MmCoreStandalone"),
+            file("aaaaaaaa-0000-0000-0000-000000000002", "MmStandalone"),
+            file("aaaaaaaa-0000-0000-0000-000000000003", "Driver"),
+        ])];
+        let result = FvValidator::new(&fv_list).validate_mm_is_standalone();
+        assert_eq!(result.unwrap().violation_count(), 0);
+
+        // -ve test - a platform mid-migration reports each traditional MM module
+        let fv_list = vec![fv(vec![
+            file("aaaaaaaa-0000-0000-0000-000000000001", "MmCore"),
+            file("aaaaaaaa-0000-0000-0000-000000000002", "MmStandalone"),
+            file("aaaaaaaa-0000-0000-0000-000000000003", "Mm"),
+        ])];
+        let result = FvValidator::new(&fv_list).validate_mm_is_standalone();
+        assert_eq!(result.unwrap().violation_count(), 2);
+    }
+
     #[test]
     fn test_validate_fv_combined_drivers() {
         let fv_list = vec![FirmwareVolumeSerDe {
@@ -491,6 +988,117 @@ mod tests {
         assert_eq!(violation_count, 1);
     }
 
+    #[test]
+    fn test_runtime_driver_alignment_override() {
+        let fv_list = vec![FirmwareVolumeSerDe {
+            fv_name: "FV1".to_string(),
+            fv_length: 1024,
+            fv_base_address: 0x1000,
+            fv_attributes: 0,
+            files: vec![FirmwareFileSerDe {
+                name: "aaaaaaaa-0000-0000-0000-000000000001".to_string(),
+                file_type: "Driver".to_string(),
+                length: 512,
+                attributes: 0,
+                sections: vec![FirmwareSectionSerDe {
+                    section_type: "Pe32".to_string(),
+                    length: 256,
+                    compression_type: "uncompressed ".to_string(),
+                    pe_info: Some(PeHeaderInfo {
+                        section_alignment: UEFI_PAGE_SIZE as u32,
+                        machine: COFF_MACHINE_ARM64,
+                        subsystem: IMAGE_SUBSYSTEM_EFI_RUNTIME_DRIVER,
+                    }),
+                }],
+            }],
+        }];
+
+        // A page aligned ARM64 runtime driver is only accepted with a 4 KiB --runtime-alignment
+        let result = FvValidator::new(&fv_list).validate_fv_file_sections();
+        assert_eq!(result.unwrap().violation_count(), 1);
+        let result =
+            FvValidator::new(&fv_list).with_runtime_driver_alignment(UEFI_PAGE_SIZE).validate_fv_file_sections();
+        assert_eq!(result.unwrap().violation_count(), 0);
+    }
+
+    #[test]
+    fn test_validate_mm_absent() {
+        let file = |name: &str, file_type: &str| FirmwareFileSerDe {
+            name: name.to_string(),
+            file_type: file_type.to_string(),
+            length: 256,
+            attributes: 0,
+            sections: vec![],
+        };
+        let fv_list = vec![FirmwareVolumeSerDe {
+            fv_name: "FV1".to_string(),
+            fv_length: 1024,
+            fv_base_address: 0x1000,
+            fv_attributes: 0,
+            files: vec![
+                file("aaaaaaaa-0000-0000-0000-000000000001", "MmCoreStandalone"),
+                file("aaaaaaaa-0000-0000-0000-000000000002", "MmStandalone"),
+                file("aaaaaaaa-0000-0000-0000-000000000003", "Mm"),
+                file("aaaaaaaa-0000-0000-0000-000000000004", "Driver"),
+            ],
+        }];
+
+        // Only the client profile forbids MM, Standalone MM included
+        for (profile, expected) in [(Profile::Default, 0), (Profile::Client, 3), (Profile::Server, 0)] {
+            let validator = FvValidator::new(&fv_list).with_forbid_mm(profile.forbids_mm());
+            let result = validator.validate().unwrap();
+            let count = result.iter_violations().filter(|(_, kind)| kind.name() == "MmPresent").count();
+            assert_eq!(count, expected, "{:?}", profile);
+        }
+    }
+
+    #[test]
+    fn test_fail_fast() {
+        let file = |name: &str, file_type: &str| FirmwareFileSerDe {
+            name: name.to_string(),
+            file_type: file_type.to_string(),
+            length: 256,
+            attributes: 0,
+            sections: vec![],
+        };
+        let fv_list = vec![FirmwareVolumeSerDe {
+            fv_name: "FV1".to_string(),
+            fv_length: 1024,
+            fv_base_address: 0x1000,
+            fv_attributes: 0,
+            files: vec![
+                file("aaaaaaaa-0000-0000-0000-000000000001", "CombinedPeimDriver"),
+                file("aaaaaaaa-0000-0000-0000-000000000002", "Driver"),
+            ],
+        }];
+        let names = |report: &ValidationReport| report.groups().map(str::to_string).collect::<Vec<_>>();
+
+        // The passes after validate_fv_for_traditional_smm are skipped
+        let fail_fast = FailFast::new(Severity::Error, None);
+        let report = FvValidator::new(&fv_list).with_fail_fast(Some(fail_fast)).validate().unwrap();
+        assert_eq!(names(&report), ["UsesTraditionalSmm"]);
+
+        // Violations of filtered kinds do not stop the validation
+        let report = FvValidator::new(&fv_list)
+            .with_filter(KindFilter::new(&[], &["UsesTraditionalSmm".to_string()]).unwrap())
+            .with_fail_fast(Some(FailFast::new(Severity::Error, None)))
+            .validate()
+            .unwrap();
+        assert_eq!(names(&report), ["CombinedDriversPresent"]);
+
+        // Nor do the violations accepted by the baseline, so the validation
+        // stops at the DriverMissingDepex warning
+        let report = FvValidator::new(&fv_list).validate().unwrap();
+        let fingerprints = report
+            .iter_violations()
+            .filter(|(_, violation)| violation.severity() == Severity::Error)
+            .map(|(_, violation)| violation.fingerprint())
+            .collect::<Vec<_>>();
+        let fail_fast = FailFast::new(Severity::Warning, Some(Baseline::parse(&fingerprints.join("\n"))));
+        let report = FvValidator::new(&fv_list).with_fail_fast(Some(fail_fast)).validate().unwrap();
+        assert_eq!(names(&report), ["CombinedDriversPresent", "DriverMissingDepex", "UsesTraditionalSmm"]);
+    }
+
     #[test]
     fn test_ineligible_modules_types_do_not_fail() {
         const ALL_MODULE_TYPES: &[&str] = &[
@@ -539,5 +1147,379 @@ mod tests {
         let result = validator.validate();
         assert!(result.is_err());
         assert_eq!(result.unwrap_err(), ValidationAppError::EmptyFvList);
+
+        let validator = FvValidator::new(&fv_list).with_allow_empty(true);
+        let report = validator.validate().unwrap();
+        assert_eq!(report.violation_count(), 0);
+        assert_eq!(report.notes(), [ValidationAppError::EmptyFvList.to_string()]);
+    }
+
+    #[test]
+    fn test_validate_drivers_have_depex() {
+        let section = |section_type: &str| FirmwareSectionSerDe {
+            section_type: section_type.to_string(),
+            length: 64,
+            compression_type: "uncompressed".to_string(),
+            pe_info: None,
+        };
+        let file = |name: &str, file_type: &str, sections| FirmwareFileSerDe {
+            name: name.to_string(),
+            file_type: file_type.to_string(),
+            length: 512,
+            attributes: 0,
+            sections,
+        };
+
+        let fv_list = vec![FirmwareVolumeSerDe {
+            fv_name: "FV1".to_string(),
+            fv_length: 1024,
+            fv_base_address: 0x1000,
+            fv_attributes: 0,
+            files: vec![
+                file("WithDepex", "Driver", vec![section("DxeDepex"), section("Pe32")]),
+                file("WithoutDepex", "Driver", vec![section("Pe32")]),
+                file("Core", "DxeCore", vec![section("Pe32")]),
+                file("App", "Application", vec![section("Pe32")]),
+            ],
+        }];
+
+        let validator = FvValidator::new(&fv_list);
+        let result = validator.validate_drivers_have_depex();
+        assert!(result.is_ok());
+        let validation_report = result.unwrap();
+        assert_eq!(validation_report.violation_count(), 1);
+    }
+
+    #[test]
+    fn test_validate_pe_machine_type() {
+        let pe_section = |machine| FirmwareSectionSerDe {
+            section_type: "Pe32".to_string(),
+            length: 4096,
+            compression_type: "uncompressed".to_string(),
+            pe_info: Some(PeHeaderInfo {
+                section_alignment: 0x1000,
+                machine,
+                subsystem: IMAGE_SUBSYSTEM_EFI_BOOT_SERVICE_DRIVER,
+            }),
+        };
+
+        let fv_list = vec![FirmwareVolumeSerDe {
+            fv_name: "FV1".to_string(),
+            fv_length: 1024,
+            fv_base_address: 0x1000,
+            fv_attributes: 0,
+            files: vec![
+                FirmwareFileSerDe {
+                    name: "Mixed".to_string(),
+                    file_type: "Driver".to_string(),
+                    length: 512,
+                    attributes: 0,
+                    sections: vec![
+                        pe_section(COFF_MACHINE_X86_64),
+                        pe_section(COFF_MACHINE_ARM64),
+                        pe_section(COFF_MACHINE_X86),
+                    ],
+                },
+                FirmwareFileSerDe {
+                    name: "Peim".to_string(),
+                    file_type: "Peim".to_string(),
+                    length: 512,
+                    attributes: 0,
+                    sections: vec![pe_section(COFF_MACHINE_X86)],
+                },
+            ],
+        }];
+
+        let validator = FvValidator::new(&fv_list);
+        let result = validator.validate_pe_machine_type();
+        assert!(result.is_ok());
+        let validation_report = result.unwrap();
+        assert_eq!(validation_report.violation_count(), 1);
+    }
+
+    #[test]
+    fn test_validate_pe_relocations() {
+        let pe_section = FirmwareSectionSerDe {
+            section_type: "Pe32".to_string(),
+            length: 4096,
+            compression_type: "uncompressed".to_string(),
+            pe_info: Some(PeHeaderInfo {
+                section_alignment: 0x1000,
+                machine: COFF_MACHINE_X86_64,
+                subsystem: IMAGE_SUBSYSTEM_EFI_BOOT_SERVICE_DRIVER,
+            }),
+        };
+        let file = |name: &str, file_type: &str| FirmwareFileSerDe {
+            name: name.to_string(),
+            file_type: file_type.to_string(),
+            length: 512,
+            attributes: 0,
+            sections: vec![pe_section.clone()],
+        };
+        let fv_list = vec![FirmwareVolumeSerDe {
+            fv_name: "FV1".to_string(),
+            fv_length: 1024,
+            fv_base_address: 0x1000,
+            fv_attributes: 0,
+            files: vec![file("Fixed", "Driver"), file("Relocatable", "Driver"), file("Peim", "Peim")],
+        }];
+        let pe = |fv_base_address, file_index, characteristics| PeCharacteristicsSerDe {
+            fv_base_address,
+            file_index,
+            section_index: 0,
+            characteristics,
+        };
+
+        // Only the driver image is reported, PEIMs may run in place. Entries
+        // that do not resolve to a section are ignored.
+        const EXECUTABLE_IMAGE: u16 = 0x0002;
+        let pe_characteristics = [
+            pe(0x1000, 0, EXECUTABLE_IMAGE | IMAGE_FILE_RELOCS_STRIPPED),
+            pe(0x1000, 1, EXECUTABLE_IMAGE),
+            pe(0x1000, 2, EXECUTABLE_IMAGE | IMAGE_FILE_RELOCS_STRIPPED),
+            pe(0x1000, 3, IMAGE_FILE_RELOCS_STRIPPED),
+            pe(0x2000, 0, IMAGE_FILE_RELOCS_STRIPPED),
+        ];
+        let validator = FvValidator::new(&fv_list).with_pe_characteristics(&pe_characteristics);
+        let validation_report = validator.validate_pe_relocations().unwrap();
+        assert_eq!(validation_report.violation_count(), 1);
+        assert!(validation_report.to_json().to_string().contains("RelocationsStripped"));
+
+        // Captures without characteristics are not checked
+        assert_eq!(FvValidator::new(&fv_list).validate_pe_relocations().unwrap().violation_count(), 0);
+    }
+
+    #[test]
+    fn test_validate_file_length_sanity() {
+        let section = |section_type: &str, length| FirmwareSectionSerDe {
+            section_type: section_type.to_string(),
+            length,
+            compression_type: "uncompressed".to_string(),
+            pe_info: None,
+        };
+        let file = |name: &str, length, sections| FirmwareFileSerDe {
+            name: name.to_string(),
+            file_type: "Driver".to_string(),
+            length,
+            attributes: 0,
+            sections,
+        };
+
+        let fv_list = vec![FirmwareVolumeSerDe {
+            fv_name: "FV1".to_string(),
+            fv_length: 0x10000,
+            fv_base_address: 0x1000,
+            fv_attributes: 0,
+            files: vec![
+                file("Valid", 0x200, vec![section("DxeDepex", 0x20), section("Pe32", 0x1c0)]),
+                file("Empty", 0, vec![]),
+                file("Truncated", 0x100, vec![section("DxeDepex", 0x20), section("Pe32", 0x1c0)]),
+                file("Compressed", 0x100, vec![section("Compression", 0xe0), section("Pe32", 0x1c0)]),
+                FirmwareFileSerDe {
+                    file_type: "FfsPad".to_string(),
+                    ..file("Pad", 0x50, vec![section("Raw", 0xffffffff)])
+                },
+            ],
+        }];
+
+        let validator = FvValidator::new(&fv_list);
+        let result = validator.validate_file_length_sanity();
+        assert!(result.is_ok());
+        let validation_report = result.unwrap();
+        assert_eq!(validation_report.violation_count(), 2);
+    }
+
+    #[test]
+    fn test_validate_duplicate_file_guids() {
+        let file = |name: &str, file_type: &str| FirmwareFileSerDe {
+            name: name.to_string(),
+            file_type: file_type.to_string(),
+            length: 512,
+            attributes: 0,
+            sections: vec![],
+        };
+        let fv = |fv_name: &str, files| FirmwareVolumeSerDe {
+            fv_name: fv_name.to_string(),
+            fv_length: 0x10000,
+            fv_base_address: 0x1000,
+            fv_attributes: 0,
+            files,
+        };
+
+        let fv_list = vec![
+            fv(
+                "FV1",
+                vec![
+                    file("aaaaaaaa-0000-0000-0000-000000000001", "Driver"),
+                    file("aaaaaaaa-0000-0000-0000-000000000002", "Driver"),
+                    file("ffffffff-ffff-ffff-ffff-ffffffffffff", "FfsPad"),
+                    file("ffffffff-ffff-ffff-ffff-ffffffffffff", "FfsPad"),
+                ],
+            ),
+            fv(
+                "FV2",
+                vec![
+                    file("AAAAAAAA-0000-0000-0000-000000000001", "Driver"),
+                    file("aaaaaaaa-0000-0000-0000-000000000003", "Driver"),
+                ],
+            ),
+        ];
+
+        let validator = FvValidator::new(&fv_list);
+        let result = validator.validate_duplicate_file_guids();
+        assert!(result.is_ok());
+        let validation_report = result.unwrap();
+        assert_eq!(validation_report.violation_count(), 2);
+    }
+
+    #[test]
+    fn test_validate_guid_strings() {
+        let file = |name: &str| FirmwareFileSerDe {
+            name: name.to_string(),
+            file_type: "Driver".to_string(),
+            length: 512,
+            attributes: 0,
+            sections: vec![],
+        };
+        let fv_list = vec![FirmwareVolumeSerDe {
+            fv_name: "FV1".to_string(),
+            fv_length: 0x10000,
+            fv_base_address: 0x1000,
+            fv_attributes: 0,
+            files: vec![
+                file("aaaaaaaa-0000-0000-0000-000000000001"),
+                file("AAAAAAAA-0000-0000-0000-000000000002"),
+                file("aaaaaaaa-0000-0000-0000-00000000000"),
+                file("not-a-guid"),
+            ],
+        }];
+
+        let validator = FvValidator::new(&fv_list);
+        let result = validator.validate_guid_strings();
+        assert!(result.is_ok());
+        let validation_report = result.unwrap();
+        assert_eq!(validation_report.violation_count(), 2);
+    }
+
+    #[test]
+    fn test_validate_compression_policy() {
+        let section = |compression_type: &str| FirmwareSectionSerDe {
+            section_type: "GuidDefined".to_string(),
+            length: 64,
+            compression_type: compression_type.to_string(),
+            pe_info: None,
+        };
+
+        let fv_list = vec![FirmwareVolumeSerDe {
+            fv_name: "FV1".to_string(),
+            fv_length: 0x10000,
+            fv_base_address: 0x1000,
+            fv_attributes: 0,
+            files: vec![FirmwareFileSerDe {
+                name: "aaaaaaaa-0000-0000-0000-000000000001".to_string(),
+                file_type: "Application".to_string(),
+                length: 512,
+                attributes: 0,
+                sections: vec![
+                    section("uncompressed"),
+                    section("Brotli Compressed"),
+                    section("Tiano Compressed"),
+                    section("LZMA Compressed"),
+                    section("CRC32 Compressed"),
+                ],
+            }],
+        }];
+
+        let allowed = vec!["brotli".to_string(), "Tiano Compressed".to_string()];
+        let validator = FvValidator::new(&fv_list).with_allowed_compression(&allowed);
+        let validation_report = validator.validate().unwrap();
+        assert_eq!(validation_report.violation_count(), 1);
+        assert!(validation_report.to_json().to_string().contains("DisallowedCompression"));
+        assert!(!validation_report.to_json().to_string().contains("LzmaCompressedSections"));
+
+        // Without an allowlist only the LZMA rule applies
+        let validator = FvValidator::new(&fv_list);
+        let validation_report = validator.validate().unwrap();
+        assert_eq!(validation_report.violation_count(), 1);
+        assert!(validation_report.to_json().to_string().contains("LzmaCompressedSections"));
+
+        // EDK II tool names are accepted
+        let allowed = vec!["BrotliCompress".to_string(), "tianocompress".to_string(), "LzmaCompress".to_string()];
+        let validator = FvValidator::new(&fv_list);
+        assert_eq!(validator.validate_compression_policy(&allowed).unwrap().violation_count(), 0);
+
+        // Only DXE modules are checked, as with the LZMA rule
+        let mut fv_list = fv_list;
+        fv_list[0].files[0].file_type = "Peim".to_string();
+        let allowed = vec!["brotli".to_string()];
+        let validator = FvValidator::new(&fv_list);
+        assert_eq!(validator.validate_compression_policy(&allowed).unwrap().violation_count(), 0);
+    }
+
+    #[test]
+    fn test_validate_compression_policy_crc32_and_guided_sections() {
+        let section = |compression_type: &str| FirmwareSectionSerDe {
+            section_type: "GuidDefined".to_string(),
+            length: 64,
+            compression_type: compression_type.to_string(),
+            pe_info: None,
+        };
+        let fv_list = vec![FirmwareVolumeSerDe {
+            fv_name: "FV1".to_string(),
+            fv_length: 0x10000,
+            fv_base_address: 0x1000,
+            fv_attributes: 0,
+            files: vec![FirmwareFileSerDe {
+                name: "aaaaaaaa-0000-0000-0000-000000000001".to_string(),
+                file_type: "Application".to_string(),
+                length: 512,
+                attributes: 0,
+                sections: vec![section("CRC32 Compressed"), section("0f9d89e8-9259-4f76-a5af-0c89e34023df")],
+            }],
+        }];
+
+        let allowed = vec!["brotli".to_string()];
+        let validator = FvValidator::new(&fv_list).with_allowed_compression(&allowed);
+        assert_eq!(validator.validate_compression_policy(&allowed).unwrap().violation_count(), 0);
+
+        // The GUID defined section is only reported once, as an unknown section processor
+        let json = validator.validate().unwrap().to_json().to_string();
+        assert!(json.contains("UnknownGuidedSection"));
+        assert!(!json.contains("DisallowedCompression"));
+    }
+
+    #[test]
+    fn test_validate_unknown_guided_sections() {
+        let section = |compression_type: &str| FirmwareSectionSerDe {
+            section_type: "GuidDefined".to_string(),
+            length: 64,
+            compression_type: compression_type.to_string(),
+            pe_info: None,
+        };
+
+        let fv_list = vec![FirmwareVolumeSerDe {
+            fv_name: "FV1".to_string(),
+            fv_length: 0x10000,
+            fv_base_address: 0x1000,
+            fv_attributes: 0,
+            files: vec![FirmwareFileSerDe {
+                name: "File1".to_string(),
+                file_type: "Application".to_string(),
+                length: 512,
+                attributes: 0,
+                sections: vec![
+                    section("Brotli Compressed"),
+                    section("CRC32 Compressed"),
+                    section("0x3"),
+                    section("0f9d89e8-9259-4f76-a5af-0c89e34023df"),
+                ],
+            }],
+        }];
+
+        let validator = FvValidator::new(&fv_list);
+        let result = validator.validate_unknown_guided_sections();
+        assert!(result.is_ok());
+        let validation_report = result.unwrap();
+        assert_eq!(validation_report.violation_count(), 1);
     }
 }
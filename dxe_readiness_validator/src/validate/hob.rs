@@ -6,36 +6,294 @@
 //!
 //! SPDX-License-Identifier: Apache-2.0
 //!
+use dxe_readiness_capture::{GuidHobDataSerDe, UnknownHobSerDe};
 use patina::{
     OwnedGuid,
     base::UEFI_PAGE_SIZE,
+    efi_types::EFI_MAX_MEMORY_TYPE,
     pi::{
-        hob::{EFI_RESOURCE_IO, EFI_RESOURCE_IO_RESERVED, MEMORY_TYPE_INFO_HOB_GUID},
+        hob::{
+            EFI_RESOURCE_ATTRIBUTE_INITIALIZED, EFI_RESOURCE_ATTRIBUTE_PRESENT, EFI_RESOURCE_ATTRIBUTE_TESTED,
+            EFI_RESOURCE_IO, EFI_RESOURCE_IO_RESERVED, EFI_RESOURCE_MEMORY_MAPPED_IO, EFI_RESOURCE_MEMORY_RESERVED,
+            EFI_RESOURCE_SYSTEM_MEMORY, MEMORY_TYPE_INFO_HOB_GUID,
+        },
         serializable::{
             Interval,
-            serializable_hob::{HobSerDe, MemoryTypeInfoEntrySerDe, ResourceDescriptorSerDe},
+            serializable_hob::{HobSerDe, MemAllocDescriptorSerDe, MemoryTypeInfoEntrySerDe, ResourceDescriptorSerDe},
         },
     },
 };
 use r_efi::efi;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
 use crate::{
     ValidationAppError,
-    validation_kind::{HobValidationKind, ValidationKind},
+    guid_names::is_canonical_guid,
+    validation_kind::{HobValidationKind, KNOWN_V2_ATTRIBUTES_MASK, KindFilter, RangeDescriptor, ValidationKind},
+    validation_report::{FailFast, HobSummary, MemoryFootprint},
     validator::Validator,
 };
 
-use super::{ValidationReport, ValidationResult};
+use super::{Passes, ValidationReport, ValidationResult};
+
+/// Memory access protection attributes that IO resource descriptors must not
+/// carry.
+const IO_PROTECTION_ATTRIBUTES: u64 = efi::MEMORY_RO | efi::MEMORY_XP | efi::MEMORY_RP;
+
+/// Handoff HOB version expected by the DXE core.
+const SUPPORTED_HANDOFF_VERSION: u32 = 0x00010000;
+
+/// `EFI_HOB_HANDOFF_TABLE_VERSION` produced by older EDK II PEI cores. The DXE
+/// core still accepts it, so it is reported as a warning.
+const LEGACY_HANDOFF_VERSION: u32 = 0x0009;
+
+/// A resource attribute bit with the name it is reported under.
+type ResourceAttributeBit = (u32, &'static str);
+
+/// Resource attribute bits paired with the prerequisite bit that must also be
+/// set whenever they are.
+pub(crate) const RESOURCE_ATTRIBUTE_DEPENDENCIES: &[(ResourceAttributeBit, ResourceAttributeBit)] = &[
+    ((EFI_RESOURCE_ATTRIBUTE_INITIALIZED, "INITIALIZED"), (EFI_RESOURCE_ATTRIBUTE_PRESENT, "PRESENT")),
+    ((EFI_RESOURCE_ATTRIBUTE_TESTED, "TESTED"), (EFI_RESOURCE_ATTRIBUTE_INITIALIZED, "INITIALIZED")),
+];
+
+/// Largest number of pages (1 GiB) a single Memory Type Information bin is
+/// expected to request.
+const MAX_MEMORY_TYPE_INFO_BIN_PAGES: u32 = 0x40000;
+
+/// Whether a GUID Extension HOB listed in `GUID_HOB_RULES` must be present in,
+/// or absent from, the HOB list.
+#[derive(PartialEq)]
+enum GuidHobRule {
+    Required,
+    Prohibited,
+}
+
+/// GUID Extension HOBs the DXE core requires or rejects.
+const GUID_HOB_RULES: &[(&str, GuidHobRule)] = &[
+    // gEfiMemoryTypeInformationGuid
+    ("4c19049f-4137-4dd3-9c10-8b97a83ffdfa", GuidHobRule::Required),
+    // A GUID Extension HOB with a nil name cannot be located by any consumer
+    ("00000000-0000-0000-0000-000000000000", GuidHobRule::Prohibited),
+];
+
+/// Range of a Firmware Volume HOB as `(base_address, length)`. Only built for
+/// non-empty ranges that do not wrap past the end of the address space.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct FvHobRange(u64, u64);
+
+impl Interval for FvHobRange {
+    fn start(&self) -> u64 {
+        self.0
+    }
+
+    fn end(&self) -> u64 {
+        self.0 + self.1
+    }
+
+    fn merge(&self, other: &Self) -> Self {
+        let start = self.start().min(other.start());
+        FvHobRange(start, self.end().max(other.end()) - start)
+    }
+}
+
+/// Range of an IO resource descriptor HOB in IO port space as
+/// `(physical_start, resource_length)`. Only built for non-empty ranges that
+/// do not wrap past the end of the address space.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct IoPortRange(u64, u64);
+
+/// The 16-bit IO port space, ports 0x0 to 0xFFFF.
+const IO_PORT_SPACE: IoPortRange = IoPortRange(0, 0x10000);
+
+impl Interval for IoPortRange {
+    fn start(&self) -> u64 {
+        self.0
+    }
+
+    fn end(&self) -> u64 {
+        self.0 + self.1
+    }
+
+    fn merge(&self, other: &Self) -> Self {
+        let start = self.start().min(other.start());
+        IoPortRange(start, self.end().max(other.end()) - start)
+    }
+}
 
 /// Performs validation on a list of hobs to check for violations of Patina
 /// requirements.
 pub struct HobValidator<'a> {
     hob_list: &'a Vec<HobSerDe>,
+    filter: KindFilter,
+    // Set by --fail-fast, the passes stop at the first violation reported
+    fail_fast: Option<FailFast>,
+    // Set by --allow-empty, an empty HOB list is a note rather than an error
+    allow_empty: bool,
+    // Set by --verbose-overlaps, overlapping ranges are reported pair by pair
+    verbose_overlaps: bool,
+    // Set by --require-contiguous, system memory must cover [0, end) without gaps
+    require_contiguous: Option<u64>,
+    // Set by --flag-unknown-hobs, the headers of the unknown HOBs of the capture
+    unknown_hobs: Option<&'a [UnknownHobSerDe]>,
+    // Set by --require-v2, V1 resource descriptors without any V2 are a violation
+    require_v2: bool,
+    // Payloads of the GUID Extension HOBs, recorded next to the HOB list
+    guid_hob_data: &'a [GuidHobDataSerDe],
 }
 
 impl<'a> HobValidator<'a> {
     pub fn new(hob_list: &'a Vec<HobSerDe>) -> Self {
-        HobValidator { hob_list }
+        HobValidator {
+            hob_list,
+            filter: KindFilter::default(),
+            fail_fast: None,
+            allow_empty: false,
+            verbose_overlaps: false,
+            require_contiguous: None,
+            unknown_hobs: None,
+            require_v2: false,
+            guid_hob_data: &[],
+        }
+    }
+
+    /// Restricts the validation kinds that may report violations.
+    pub fn with_filter(mut self, filter: KindFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Skips the remaining passes once one records a violation `fail_fast`
+    /// stops at.
+    pub fn with_fail_fast(mut self, fail_fast: Option<FailFast>) -> Self {
+        self.fail_fast = fail_fast;
+        self
+    }
+
+    /// Makes an empty HOB list produce an empty report with a note instead of
+    /// an error.
+    pub fn with_allow_empty(mut self, allow_empty: bool) -> Self {
+        self.allow_empty = allow_empty;
+        self
+    }
+
+    /// Makes overlapping resource descriptors be reported as every overlapping
+    /// pair instead of one cluster of mutually overlapping descriptors.
+    pub fn with_verbose_overlaps(mut self, verbose_overlaps: bool) -> Self {
+        self.verbose_overlaps = verbose_overlaps;
+        self
+    }
+
+    /// Requires system memory resource descriptors to cover every address
+    /// below `end`. Gaps are legal in general, so this is off by default.
+    pub fn with_require_contiguous(mut self, end: Option<u64>) -> Self {
+        self.require_contiguous = end;
+        self
+    }
+
+    /// Reports the HOBs the capture could not classify, with the type codes
+    /// recorded in `unknown_hobs`. Unknown HOBs are ignored by default.
+    pub fn with_flag_unknown_hobs(mut self, unknown_hobs: Option<&'a [UnknownHobSerDe]>) -> Self {
+        self.unknown_hobs = unknown_hobs;
+        self
+    }
+
+    /// Requires a capture with V1 resource descriptors to also carry V2 ones.
+    /// Platforms that have not started migrating legitimately have none, so
+    /// this is off by default.
+    pub fn with_require_v2(mut self, require_v2: bool) -> Self {
+        self.require_v2 = require_v2;
+        self
+    }
+
+    /// Supplies the GUID Extension HOB payloads recorded in `guid_hob_data`.
+    /// Captures from older capture tools carry none, so the parsed HOB list
+    /// is used instead.
+    pub fn with_guid_hob_data(mut self, guid_hob_data: &'a [GuidHobDataSerDe]) -> Self {
+        self.guid_hob_data = guid_hob_data;
+        self
+    }
+
+    /// Counts the HOBs of each `HobSerDe` variant in the HOB list.
+    pub fn summarize(&self) -> HobSummary {
+        let mut summary = HobSummary::default();
+        for hob in self.hob_list {
+            match hob {
+                HobSerDe::Handoff { .. } => summary.handoff += 1,
+                HobSerDe::MemoryAllocation { .. } => summary.memory_allocation += 1,
+                HobSerDe::ResourceDescriptor(_) => summary.resource_descriptor += 1,
+                HobSerDe::ResourceDescriptorV2 { .. } => summary.resource_descriptor_v2 += 1,
+                HobSerDe::GuidExtension { .. } => summary.guid_extension += 1,
+                HobSerDe::MemoryTypeInformation { .. } => summary.memory_type_information += 1,
+                HobSerDe::FirmwareVolume { .. } => summary.firmware_volume += 1,
+                HobSerDe::Cpu { .. } => summary.cpu += 1,
+                HobSerDe::UnknownHob => summary.unknown += 1,
+            }
+        }
+        summary
+    }
+
+    /// Totals the system and reserved memory declared by well-formed V1 and
+    /// V2 resource descriptors and finds the largest range of system memory
+    /// left uncovered by memory allocation HOBs.
+    pub fn memory_footprint(&self) -> MemoryFootprint {
+        let resources_of_type = |resource_type: u32| -> Vec<&ResourceDescriptorSerDe> {
+            self.hob_list
+                .iter()
+                .filter_map(|hob| match hob {
+                    HobSerDe::ResourceDescriptor(resource) | HobSerDe::ResourceDescriptorV2 { v1: resource, .. }
+                        if resource.resource_type == resource_type && Self::is_well_formed_resource(resource) =>
+                    {
+                        Some(resource)
+                    }
+                    _ => None,
+                })
+                .collect()
+        };
+        let system_memory = Interval::merge_intervals(&resources_of_type(EFI_RESOURCE_SYSTEM_MEMORY));
+        let reserved_memory = Interval::merge_intervals(&resources_of_type(EFI_RESOURCE_MEMORY_RESERVED));
+
+        let alloc_descs: Vec<&MemAllocDescriptorSerDe> = self
+            .hob_list
+            .iter()
+            .filter_map(|hob| match hob {
+                HobSerDe::MemoryAllocation { alloc_descriptor }
+                    if Self::is_well_formed_allocation(alloc_descriptor) =>
+                {
+                    Some(alloc_descriptor)
+                }
+                _ => None,
+            })
+            .collect();
+        let allocations = Interval::merge_intervals(&alloc_descs);
+
+        let mut footprint = MemoryFootprint {
+            system_memory: system_memory.iter().map(Interval::length).sum(),
+            reserved_memory: reserved_memory.iter().map(Interval::length).sum(),
+            ..Default::default()
+        };
+        let mut record_free = |start: u64, end: u64| {
+            if end - start > footprint.largest_free_region_length {
+                footprint.largest_free_region_start = start;
+                footprint.largest_free_region_length = end - start;
+            }
+        };
+        for resource in &system_memory {
+            let mut free_start = resource.start();
+            for allocation in allocations
+                .iter()
+                .filter(|allocation| allocation.start() < resource.end() && resource.start() < allocation.end())
+            {
+                if allocation.start() > free_start {
+                    record_free(free_start, allocation.start());
+                }
+                free_start = free_start.max(allocation.end());
+            }
+            if free_start < resource.end() {
+                record_free(free_start, resource.end());
+            }
+        }
+        footprint
     }
 
     fn is_io(resource_type: u32) -> bool {
@@ -48,59 +306,399 @@ impl<'a> HobValidator<'a> {
         OwnedGuid::try_from_string(&resource.owner).is_ok_and(|owner| owner == MEMORY_TYPE_INFO_HOB_GUID)
     }
 
+    fn is_range_overflow(resource: &ResourceDescriptorSerDe) -> bool {
+        resource.physical_start.checked_add(resource.resource_length).is_none()
+    }
+
+    /// Returns true when the range described by a resource descriptor HOB is
+    /// non-empty and does not wrap past the end of the 64-bit address space.
+    fn is_well_formed_resource(resource: &ResourceDescriptorSerDe) -> bool {
+        resource.resource_length != 0 && !Self::is_range_overflow(resource)
+    }
+
+    /// Returns true when the range described by a memory allocation HOB is
+    /// non-empty and does not wrap past the end of the 64-bit address space.
+    fn is_well_formed_allocation(alloc_desc: &MemAllocDescriptorSerDe) -> bool {
+        alloc_desc.memory_length != 0 && alloc_desc.memory_base_address.checked_add(alloc_desc.memory_length).is_some()
+    }
+
+    /// Returns every pair of overlapping intervals in `resource_list`, in the
+    /// order a nested loop over the list would find them.
+    ///
+    /// Intervals are half-open, `[start(), end())`, so ranges that only touch,
+    /// where one ends exactly where the other starts, do not overlap.
+    ///
+    /// The intervals are swept by start address while keeping the ones that
+    /// are still open, so only intervals that can overlap are compared. This
+    /// takes O(n log n + k) for k overlapping pairs instead of O(n²).
     fn check_hob_overlap<'b, T>(resource_list: &[&'b T]) -> Vec<(&'b T, &'b T)>
     where
         T: Interval,
     {
-        let mut overlaps = Vec::new();
-        for i in 0..resource_list.len() {
-            for j in (i + 1)..resource_list.len() {
+        let mut by_start: Vec<usize> = (0..resource_list.len()).collect();
+        by_start.sort_by_key(|&i| (resource_list[i].start(), i));
+
+        let mut open: Vec<usize> = Vec::new();
+        let mut overlapping_indices = Vec::new();
+        for i in by_start {
+            let start = resource_list[i].start();
+            open.retain(|&j| resource_list[j].end() > start);
+            for &j in &open {
                 if resource_list[i].overlaps(resource_list[j]) {
-                    overlaps.push((resource_list[i], resource_list[j]));
+                    overlapping_indices.push((i.min(j), i.max(j)));
+                }
+            }
+            open.push(i);
+        }
+
+        overlapping_indices.sort_unstable();
+        overlapping_indices.into_iter().map(|(i, j)| (resource_list[i], resource_list[j])).collect()
+    }
+
+    /// Returns every pair of an interval in `list_a` overlapping an interval in
+    /// `list_b`, in the order a nested loop over `list_a` then `list_b` would
+    /// find them. Uses the same sweep as `check_hob_overlap`, but only compares
+    /// intervals of one list against the open intervals of the other.
+    fn check_hob_overlap_between<'b, T>(list_a: &[&'b T], list_b: &[&'b T]) -> Vec<(&'b T, &'b T)>
+    where
+        T: Interval,
+    {
+        let lists = [list_a, list_b];
+        let mut by_start: Vec<(usize, usize)> =
+            (0..list_a.len()).map(|i| (0, i)).chain((0..list_b.len()).map(|j| (1, j))).collect();
+        by_start.sort_by_key(|&(list, i)| (lists[list][i].start(), list, i));
+
+        let mut open: [Vec<usize>; 2] = [Vec::new(), Vec::new()];
+        let mut overlapping_indices = Vec::new();
+        for (list, i) in by_start {
+            let interval = lists[list][i];
+            let other = 1 - list;
+            open[other].retain(|&j| lists[other][j].end() > interval.start());
+            for &j in &open[other] {
+                if interval.overlaps(lists[other][j]) {
+                    overlapping_indices.push(if list == 0 { (i, j) } else { (j, i) });
                 }
             }
+            open[list].push(i);
+        }
+
+        overlapping_indices.sort_unstable();
+        overlapping_indices.into_iter().map(|(i, j)| (list_a[i], list_b[j])).collect()
+    }
+
+    /// Groups overlapping pairs into clusters, the connected components of the
+    /// graph whose edges are the pairs. Members of a cluster are sorted by
+    /// range and clusters are ordered by their first pair in `pairs`.
+    fn cluster_overlaps<'b, T>(pairs: &[(&'b T, &'b T)]) -> Vec<Vec<&'b T>>
+    where
+        T: Interval,
+    {
+        fn find(parent: &mut [usize], mut i: usize) -> usize {
+            while parent[i] != i {
+                parent[i] = parent[parent[i]];
+                i = parent[i];
+            }
+            i
+        }
+
+        let mut members: Vec<&'b T> = Vec::new();
+        let mut parent: Vec<usize> = Vec::new();
+        let mut index_of: HashMap<*const T, usize> = HashMap::new();
+        for (a, b) in pairs {
+            let [a, b] = [*a, *b].map(|member| {
+                *index_of.entry(member as *const T).or_insert_with(|| {
+                    members.push(member);
+                    parent.push(parent.len());
+                    parent.len() - 1
+                })
+            });
+            let (root_a, root_b) = (find(&mut parent, a), find(&mut parent, b));
+            parent[root_a.max(root_b)] = root_a.min(root_b);
+        }
+
+        let mut clusters: Vec<Vec<&'b T>> = Vec::new();
+        let mut cluster_of_root: HashMap<usize, usize> = HashMap::new();
+        for (i, member) in members.iter().enumerate() {
+            let root = find(&mut parent, i);
+            let cluster = *cluster_of_root.entry(root).or_insert_with(|| {
+                clusters.push(Vec::new());
+                clusters.len() - 1
+            });
+            clusters[cluster].push(*member);
+        }
+
+        for cluster in &mut clusters {
+            cluster.sort_by_key(|member| (member.start(), member.end()));
+        }
+        clusters
+    }
+
+    /// Checks for resource descriptor HOBs whose `physical_start` plus
+    /// `resource_length` wraps past `u64::MAX`. Such descriptors are skipped by
+    /// the interval based checks, so each one is reported here instead.
+    fn validate_resource_range_overflow(&self) -> ValidationResult<'a> {
+        let mut validation_report = ValidationReport::new();
+        for hob in self.hob_list {
+            if let HobSerDe::ResourceDescriptor(resource) | HobSerDe::ResourceDescriptorV2 { v1: resource, .. } = hob
+                && Self::is_range_overflow(resource)
+            {
+                validation_report
+                    .add_violation(ValidationKind::Hob(HobValidationKind::ResourceRangeOverflow { hob1: resource }));
+            }
         }
+        Ok(validation_report)
+    }
 
-        overlaps
+    /// Checks for resource descriptor and memory allocation HOBs describing an
+    /// empty range. Such HOBs are skipped by the interval based checks, so
+    /// each one is reported here instead.
+    fn validate_zero_length_ranges(&self) -> ValidationResult<'a> {
+        let mut validation_report = ValidationReport::new();
+        for hob in self.hob_list {
+            let range = match hob {
+                HobSerDe::ResourceDescriptor(resource) | HobSerDe::ResourceDescriptorV2 { v1: resource, .. }
+                    if resource.resource_length == 0 =>
+                {
+                    RangeDescriptor::Resource(resource)
+                }
+                HobSerDe::MemoryAllocation { alloc_descriptor } if alloc_descriptor.memory_length == 0 => {
+                    RangeDescriptor::Allocation(alloc_descriptor)
+                }
+                _ => continue,
+            };
+            validation_report.add_violation(ValidationKind::Hob(HobValidationKind::ZeroLengthRange { range }));
+        }
+        Ok(validation_report)
     }
 
-    /// Checks for overlapping address ranges in memory and I/O resource
-    /// descriptor HOBs. Reports each overlapping pair as a validation
-    /// violation.
-    fn validate_memory_overlap(&self) -> ValidationResult<'_> {
+    /// Checks for overlapping address ranges in memory resource descriptor
+    /// HOBs. Descriptors that overlap only one other are reported as an
+    /// overlapping pair, while three or more connected by overlaps are
+    /// reported once as a cluster, unless `verbose_overlaps` asks for every
+    /// pair. Exact duplicates are left to `validate_duplicate_resources`, and
+    /// IO descriptors to `validate_io_port_ranges`.
+    fn validate_memory_overlap(&self) -> ValidationResult<'a> {
         let mut validation_report = ValidationReport::new();
         let mut overlaps = Vec::new();
         let mut v1_memory_hobs: Vec<&ResourceDescriptorSerDe> = Vec::new();
         let mut v2_memory_hobs: Vec<&ResourceDescriptorSerDe> = Vec::new();
+
+        for hob in self.hob_list {
+            match hob {
+                HobSerDe::ResourceDescriptor(resource) | HobSerDe::ResourceDescriptorV2 { v1: resource, .. }
+                    if !Self::is_well_formed_resource(resource) || Self::is_io(resource.resource_type) => {}
+                HobSerDe::ResourceDescriptor(resource) => v1_memory_hobs.push(resource),
+                HobSerDe::ResourceDescriptorV2 { v1: resource, .. } => v2_memory_hobs.push(resource),
+                _ => (),
+            }
+        }
+
+        overlaps.extend(Self::check_hob_overlap(&v1_memory_hobs));
+        overlaps.extend(Self::check_hob_overlap(&v2_memory_hobs));
+
+        overlaps.retain(|(hob1, hob2)| hob1 != hob2);
+
+        if self.verbose_overlaps {
+            for (hob1, hob2) in overlaps {
+                validation_report
+                    .add_violation(ValidationKind::Hob(HobValidationKind::OverlappingMemoryRanges { hob1, hob2 }));
+            }
+            return Ok(validation_report);
+        }
+
+        for hobs in Self::cluster_overlaps(&overlaps) {
+            match hobs[..] {
+                [hob1, hob2] => validation_report
+                    .add_violation(ValidationKind::Hob(HobValidationKind::OverlappingMemoryRanges { hob1, hob2 })),
+                _ => validation_report
+                    .add_violation(ValidationKind::Hob(HobValidationKind::OverlappingMemoryCluster { hobs })),
+            }
+        }
+
+        Ok(validation_report)
+    }
+
+    /// Checks the IO and IO reserved resource descriptor HOBs, which describe
+    /// ranges of the 16-bit IO port space rather than of physical memory.
+    /// Descriptors reaching past port 0xFFFF are reported, as are pairs of
+    /// descriptors of the same version whose port ranges overlap. Exact
+    /// duplicates are left to `validate_duplicate_resources`.
+    fn validate_io_port_ranges(&self) -> ValidationResult<'a> {
+        let mut validation_report = ValidationReport::new();
         let mut v1_io_hobs: Vec<&ResourceDescriptorSerDe> = Vec::new();
         let mut v2_io_hobs: Vec<&ResourceDescriptorSerDe> = Vec::new();
 
+        for hob in self.hob_list {
+            let resource = match hob {
+                HobSerDe::ResourceDescriptor(resource) | HobSerDe::ResourceDescriptorV2 { v1: resource, .. }
+                    if Self::is_io(resource.resource_type) && Self::is_well_formed_resource(resource) =>
+                {
+                    resource
+                }
+                _ => continue,
+            };
+            match hob {
+                HobSerDe::ResourceDescriptorV2 { .. } => v2_io_hobs.push(resource),
+                _ => v1_io_hobs.push(resource),
+            }
+
+            if !IO_PORT_SPACE.contains(&IoPortRange(resource.physical_start, resource.resource_length)) {
+                validation_report
+                    .add_violation(ValidationKind::Hob(HobValidationKind::IoPortRangeOutOfBounds { hob1: resource }));
+            }
+        }
+
+        let mut overlaps = Self::check_hob_overlap(&v1_io_hobs);
+        overlaps.extend(Self::check_hob_overlap(&v2_io_hobs));
+        for (hob1, hob2) in overlaps.into_iter().filter(|(hob1, hob2)| hob1 != hob2) {
+            validation_report
+                .add_violation(ValidationKind::Hob(HobValidationKind::OverlappingIoPortRanges { hob1, hob2 }));
+        }
+
+        Ok(validation_report)
+    }
+
+    /// Checks for resource descriptor HOBs of the same version that describe an
+    /// identical range with the same type, attribute and owner. Reports each
+    /// duplicate paired with the first descriptor of its group.
+    fn validate_duplicate_resources(&self) -> ValidationResult<'a> {
+        let mut validation_report = ValidationReport::new();
+        let mut v1_hobs: Vec<&ResourceDescriptorSerDe> = Vec::new();
+        let mut v2_hobs: Vec<&ResourceDescriptorSerDe> = Vec::new();
+
         for hob in self.hob_list {
             match hob {
-                HobSerDe::ResourceDescriptor(resource) if !Self::is_io(resource.resource_type) => {
-                    v1_memory_hobs.push(resource)
+                HobSerDe::ResourceDescriptor(resource) if Self::is_well_formed_resource(resource) => {
+                    v1_hobs.push(resource)
                 }
-                HobSerDe::ResourceDescriptorV2 { v1: resource, .. } if !Self::is_io(resource.resource_type) => {
-                    v2_memory_hobs.push(resource)
+                HobSerDe::ResourceDescriptorV2 { v1: resource, .. } if Self::is_well_formed_resource(resource) => {
+                    v2_hobs.push(resource)
                 }
-                HobSerDe::ResourceDescriptor(resource) if Self::is_io(resource.resource_type) => {
-                    v1_io_hobs.push(resource)
+                _ => (),
+            }
+        }
+
+        for resource_list in [&v1_hobs, &v2_hobs] {
+            let mut groups: BTreeMap<(u64, u64, u32, u32, &str), Vec<&ResourceDescriptorSerDe>> = BTreeMap::new();
+            for resource in resource_list {
+                let key = (
+                    resource.physical_start,
+                    resource.resource_length,
+                    resource.resource_type,
+                    resource.resource_attribute,
+                    resource.owner.as_str(),
+                );
+                groups.entry(key).or_default().push(resource);
+            }
+
+            for group in groups.values() {
+                if let [first, duplicates @ ..] = group.as_slice() {
+                    for duplicate in duplicates {
+                        validation_report.add_violation(ValidationKind::Hob(
+                            HobValidationKind::DuplicateResourceDescriptor { hob1: first, hob2: duplicate },
+                        ));
+                    }
                 }
-                HobSerDe::ResourceDescriptorV2 { v1: resource, .. } if Self::is_io(resource.resource_type) => {
-                    v2_io_hobs.push(resource)
+            }
+        }
+
+        Ok(validation_report)
+    }
+
+    /// Checks for overlapping address ranges in memory allocation HOBs.
+    /// Reports each overlapping pair as a validation violation.
+    fn validate_memory_allocation_overlap(&self) -> ValidationResult<'a> {
+        let mut validation_report = ValidationReport::new();
+        let alloc_descs: Vec<&MemAllocDescriptorSerDe> = self
+            .hob_list
+            .iter()
+            .filter_map(|hob| match hob {
+                HobSerDe::MemoryAllocation { alloc_descriptor }
+                    if Self::is_well_formed_allocation(alloc_descriptor) =>
+                {
+                    Some(alloc_descriptor)
                 }
-                _ => (),
+                _ => None,
+            })
+            .collect();
+
+        for (alloc_desc1, alloc_desc2) in Self::check_hob_overlap(&alloc_descs) {
+            validation_report.add_violation(ValidationKind::Hob(HobValidationKind::OverlappingMemoryAllocations {
+                alloc_desc1,
+                alloc_desc2,
+            }));
+        }
+
+        Ok(validation_report)
+    }
+
+    /// Validates that a non-null name GUID is not shared by memory allocation
+    /// HOBs describing different ranges, which can point to a driver
+    /// allocating twice under one identity. Allocations repeating the same
+    /// range are not counted twice, and anonymous allocations named by the
+    /// all-zero GUID are exempt.
+    fn validate_duplicate_allocation_names(&self) -> ValidationResult<'a> {
+        let mut validation_report = ValidationReport::new();
+        let mut index_of: HashMap<String, usize> = HashMap::new();
+        let mut named_ranges: Vec<(&str, Vec<(u64, u64)>)> = Vec::new();
+
+        for hob in self.hob_list {
+            let HobSerDe::MemoryAllocation { alloc_descriptor } = hob else {
+                continue;
+            };
+            let name = alloc_descriptor.name.as_str();
+            if OwnedGuid::try_from_string(name).is_ok_and(|guid| guid == OwnedGuid::ZERO) {
+                continue;
+            }
+            let index = *index_of.entry(name.to_lowercase()).or_insert_with(|| {
+                named_ranges.push((name, Vec::new()));
+                named_ranges.len() - 1
+            });
+            let range = (alloc_descriptor.memory_base_address, alloc_descriptor.memory_length);
+            if !named_ranges[index].1.contains(&range) {
+                named_ranges[index].1.push(range);
             }
         }
 
-        overlaps.extend(Self::check_hob_overlap(&v1_memory_hobs));
-        overlaps.extend(Self::check_hob_overlap(&v2_memory_hobs));
-        overlaps.extend(Self::check_hob_overlap(&v1_io_hobs));
-        overlaps.extend(Self::check_hob_overlap(&v2_io_hobs));
+        for (name, ranges) in named_ranges {
+            if ranges.len() > 1 {
+                validation_report.add_violation(ValidationKind::Hob(HobValidationKind::DuplicateAllocationName {
+                    name,
+                    count: ranges.len(),
+                    ranges,
+                }));
+            }
+        }
 
-        for (hob1, hob2) in &overlaps {
-            validation_report
-                .add_violation(ValidationKind::Hob(HobValidationKind::OverlappingMemoryRanges { hob1, hob2 }));
+        Ok(validation_report)
+    }
+
+    /// Validates that no two Firmware Volume HOBs describe overlapping ranges,
+    /// which points to a flash layout bug. Empty and wrapping ranges are left
+    /// to the FV validator.
+    fn validate_fv_hob_overlap(&self) -> ValidationResult<'a> {
+        let mut validation_report = ValidationReport::new();
+        let fv_ranges: Vec<FvHobRange> = self
+            .hob_list
+            .iter()
+            .filter_map(|hob| match hob {
+                HobSerDe::FirmwareVolume { base_address, length }
+                    if *length != 0 && base_address.checked_add(*length).is_some() =>
+                {
+                    Some(FvHobRange(*base_address, *length))
+                }
+                _ => None,
+            })
+            .collect();
+        let fv_ranges: Vec<&FvHobRange> = fv_ranges.iter().collect();
+
+        for (fv1, fv2) in Self::check_hob_overlap(&fv_ranges) {
+            validation_report.add_violation(ValidationKind::Hob(HobValidationKind::OverlappingFirmwareVolumes {
+                base1: fv1.0,
+                len1: fv1.1,
+                base2: fv2.0,
+                len2: fv2.1,
+            }));
         }
 
         Ok(validation_report)
@@ -127,24 +725,25 @@ impl<'a> HobValidator<'a> {
     ///    overlaps with any V2s, those V2s must have the same attributes as it,
     ///    so it's safe to merge for the superset check
     /// - If v1 and v2 overlap, make sure info is consistent
-    fn validate_overlapping_v1v2_attributes(&self) -> ValidationResult<'_> {
-        let mut inconsistent_v1_v2 = Vec::new();
-        let mut validation_report = ValidationReport::new();
-        for hob1 in self.hob_list {
-            for hob2 in self.hob_list {
-                let HobSerDe::ResourceDescriptor(v1) = hob1 else { continue };
-                let HobSerDe::ResourceDescriptorV2 { v1: v2, .. } = hob2 else { continue };
-                if Self::is_memory_type_info(v1) || Self::is_memory_type_info(v2) {
-                    continue;
-                }
-                if v1.overlaps(v2)
-                    && (v1.resource_type != v2.resource_type || v1.resource_attribute != v2.resource_attribute)
-                {
-                    inconsistent_v1_v2.push((v1, v2));
-                }
+    fn validate_overlapping_v1v2_attributes(&self) -> ValidationResult<'a> {
+        let mut validation_report = ValidationReport::new();
+        let mut v1_resources: Vec<&ResourceDescriptorSerDe> = Vec::new();
+        let mut v2_resources: Vec<&ResourceDescriptorSerDe> = Vec::new();
+
+        for hob in self.hob_list {
+            match hob {
+                HobSerDe::ResourceDescriptor(resource) | HobSerDe::ResourceDescriptorV2 { v1: resource, .. }
+                    if Self::is_memory_type_info(resource) || !Self::is_well_formed_resource(resource) => {}
+                HobSerDe::ResourceDescriptor(resource) => v1_resources.push(resource),
+                HobSerDe::ResourceDescriptorV2 { v1: resource, .. } => v2_resources.push(resource),
+                _ => (),
             }
         }
 
+        let inconsistent_v1_v2 = Self::check_hob_overlap_between(&v1_resources, &v2_resources)
+            .into_iter()
+            .filter(|(v1, v2)| v1.resource_type != v2.resource_type || v1.resource_attribute != v2.resource_attribute);
+
         for (hob1, hob2) in inconsistent_v1_v2 {
             validation_report
                 .add_violation(ValidationKind::Hob(HobValidationKind::InconsistentMemoryAttributes { hob1, hob2 }));
@@ -153,12 +752,28 @@ impl<'a> HobValidator<'a> {
         Ok(validation_report)
     }
 
+    /// Checks that a capture describing its resources with V1 descriptors has
+    /// at least one V2 descriptor, i.e. that the platform has started the
+    /// migration to the V2 format. Captures without any resource descriptor
+    /// are left to the other checks.
+    fn validate_v2_presence(&self) -> ValidationResult<'a> {
+        let mut validation_report = ValidationReport::new();
+        let v1_count = self.hob_list.iter().filter(|hob| matches!(hob, HobSerDe::ResourceDescriptor(_))).count();
+        let has_v2 = self.hob_list.iter().any(|hob| matches!(hob, HobSerDe::ResourceDescriptorV2 { .. }));
+
+        if v1_count != 0 && !has_v2 {
+            validation_report
+                .add_violation(ValidationKind::Hob(HobValidationKind::NoV2DescriptorsPresent { v1_count }));
+        }
+        Ok(validation_report)
+    }
+
     /// Checks that all V1 resource descriptors are covered by V2 descriptors,
     /// reporting any V1 ranges not migrated to V2.
     ///
     /// Resource descriptors whose `owner` is `MEMORY_TYPE_INFO_HOB_GUID` are
     /// skipped as the HOB describes PEI memory bins overlaying system memory.
-    fn validate_v1v2_superset(&self) -> ValidationResult<'_> {
+    fn validate_v1v2_superset(&self) -> ValidationResult<'a> {
         let mut validation_report = ValidationReport::new();
         let mut v1_resources: Vec<&ResourceDescriptorSerDe> = Vec::new();
         let mut v2_resources: Vec<&ResourceDescriptorSerDe> = Vec::new();
@@ -167,17 +782,20 @@ impl<'a> HobValidator<'a> {
 
         for hob in self.hob_list {
             if let HobSerDe::ResourceDescriptor(v1) = hob {
-                if Self::is_memory_type_info(v1) {
+                if Self::is_memory_type_info(v1) || !Self::is_well_formed_resource(v1) {
                     continue;
                 }
                 v1_resources.push(v1);
-            } else if let HobSerDe::ResourceDescriptorV2 { v1: v2, .. } = hob {
+            } else if let HobSerDe::ResourceDescriptorV2 { v1: v2, .. } = hob
+                && Self::is_well_formed_resource(v2)
+            {
                 v2_resources.push(v2);
             }
         }
 
         // if no v1, that's okay
-        // if no v2, is that okay? it means they haven't migrated over to the new resource descriptor format
+        // if no v2, they haven't migrated over to the new resource descriptor format yet. Every v1 is
+        // reported below, and --require-v2 also reports it once through validate_v2_presence
 
         let merged_v2 = Interval::merge_intervals(&v2_resources);
 
@@ -206,7 +824,7 @@ impl<'a> HobValidator<'a> {
     /// Validates that no memory allocations describe page zero address range
     /// (below UEFI_PAGE_SIZE). Reports a violation for each allocation
     /// overlapping this restricted range.
-    fn validate_page0_memory_allocation(&self) -> ValidationResult<'_> {
+    fn validate_page0_memory_allocation(&self) -> ValidationResult<'a> {
         let mut validation_report = ValidationReport::new();
         const PAGE_ZERO_END: u64 = UEFI_PAGE_SIZE as u64 - 1;
         for hob in self.hob_list {
@@ -222,40 +840,167 @@ impl<'a> HobValidator<'a> {
         Ok(validation_report)
     }
 
-    /// Checks for presence of the MEMORY_UCE attribute in V2 resource
-    /// descriptors and reports violations if found.
-    fn validate_memory_uce_attribute(&self) -> ValidationResult<'_> {
+    /// Validates that the `memory_type` of every memory allocation is either a
+    /// defined `EFI_MEMORY_TYPE` value or falls within the OEM
+    /// (`0x70000000..=0x7FFFFFFF`) or OS (`0x80000000..=0xFFFFFFFF`) reserved
+    /// ranges.
+    fn validate_memory_allocation_type(&self) -> ValidationResult<'a> {
         let mut validation_report = ValidationReport::new();
+        const OEM_RESERVED_MEMORY_TYPES: core::ops::RangeInclusive<u32> = 0x70000000..=0x7FFFFFFF;
+        const OS_RESERVED_MEMORY_TYPES: core::ops::RangeInclusive<u32> = 0x80000000..=0xFFFFFFFF;
         for hob in self.hob_list {
-            if let HobSerDe::ResourceDescriptorV2 { v1, attributes } = hob
-                && attributes & efi::MEMORY_UCE != 0
+            if let HobSerDe::MemoryAllocation { alloc_descriptor } = hob
+                && (alloc_descriptor.memory_type as usize) >= EFI_MAX_MEMORY_TYPE
+                && !OEM_RESERVED_MEMORY_TYPES.contains(&alloc_descriptor.memory_type)
+                && !OS_RESERVED_MEMORY_TYPES.contains(&alloc_descriptor.memory_type)
             {
-                validation_report.add_violation(ValidationKind::Hob(HobValidationKind::V2ContainsUceAttribute {
-                    hob1: v1,
-                    attributes: *attributes,
+                validation_report.add_violation(ValidationKind::Hob(HobValidationKind::InvalidMemoryType {
+                    alloc_desc: alloc_descriptor,
                 }));
             }
         }
+
         Ok(validation_report)
     }
 
-    /// Validates that each V2 resource descriptor has exactly one valid
-    /// cacheability attribute set, reporting violations if none or multiple
-    /// cache bits are present.
-    fn validate_memory_cacheability_attribute(&self) -> ValidationResult<'_> {
+    /// Validates that every memory allocation is fully contained within the
+    /// system memory described by V1 and V2 resource descriptors. The
+    /// descriptors are merged first so an allocation straddling two adjacent
+    /// descriptors is not reported.
+    fn validate_allocation_within_resource(&self) -> ValidationResult<'a> {
         let mut validation_report = ValidationReport::new();
-        for hob in self.hob_list {
-            if let HobSerDe::ResourceDescriptorV2 { v1, attributes } = hob {
-                const CACHE_ATTRIBUTE_IGNORED_MASK: u64 = !efi::MEMORY_UCE;
-                let mask = efi::CACHE_ATTRIBUTE_MASK & CACHE_ATTRIBUTE_IGNORED_MASK;
-                // Ensure exactly one cache attribute is set:
-                // 1. Check if none of the cache bits are set
-                // 2. Check if more than one bit is set by checking if it is not a power of 2
-                if (v1.resource_type != EFI_RESOURCE_IO && v1.resource_type != EFI_RESOURCE_IO_RESERVED)
-                    && (attributes & mask == 0 || ((attributes & mask) & (attributes - 1)) != 0)
+        let system_memory: Vec<&ResourceDescriptorSerDe> = self
+            .hob_list
+            .iter()
+            .filter_map(|hob| match hob {
+                HobSerDe::ResourceDescriptor(resource) | HobSerDe::ResourceDescriptorV2 { v1: resource, .. }
+                    if resource.resource_type == EFI_RESOURCE_SYSTEM_MEMORY
+                        && Self::is_well_formed_resource(resource) =>
                 {
-                    validation_report.add_violation(ValidationKind::Hob(
-                        HobValidationKind::V2MissingValidCacheabilityAttribute { hob1: v1, attributes: *attributes },
+                    Some(resource)
+                }
+                _ => None,
+            })
+            .collect();
+
+        let merged_system_memory = Interval::merge_intervals(&system_memory);
+
+        for hob in self.hob_list {
+            if let HobSerDe::MemoryAllocation { alloc_descriptor } = hob
+                && Self::is_well_formed_allocation(alloc_descriptor)
+                && !merged_system_memory.iter().any(|resource| {
+                    resource.start() <= alloc_descriptor.start() && alloc_descriptor.end() <= resource.end()
+                })
+            {
+                validation_report.add_violation(ValidationKind::Hob(
+                    HobValidationKind::AllocationOutsideDeclaredMemory { alloc_desc: alloc_descriptor },
+                ));
+            }
+        }
+
+        Ok(validation_report)
+    }
+
+    /// Validates that the system memory described by V1 and V2 resource
+    /// descriptors covers every address below `end`. The descriptors are
+    /// merged and every hole between them, or between the last one and `end`,
+    /// is reported.
+    fn validate_memory_contiguity(&self, end: u64) -> ValidationResult<'a> {
+        let mut validation_report = ValidationReport::new();
+        let system_memory: Vec<&ResourceDescriptorSerDe> = self
+            .hob_list
+            .iter()
+            .filter_map(|hob| match hob {
+                HobSerDe::ResourceDescriptor(resource) | HobSerDe::ResourceDescriptorV2 { v1: resource, .. }
+                    if resource.resource_type == EFI_RESOURCE_SYSTEM_MEMORY
+                        && Self::is_well_formed_resource(resource) =>
+                {
+                    Some(resource)
+                }
+                _ => None,
+            })
+            .collect();
+
+        let mut covered_end = 0;
+        for resource in Interval::merge_intervals(&system_memory) {
+            if covered_end >= end {
+                break;
+            }
+            if resource.start() > covered_end {
+                validation_report.add_violation(ValidationKind::Hob(HobValidationKind::MemoryGap {
+                    gap_start: covered_end,
+                    gap_end: resource.start().min(end),
+                }));
+            }
+            covered_end = covered_end.max(resource.end());
+        }
+        if covered_end < end {
+            validation_report.add_violation(ValidationKind::Hob(HobValidationKind::MemoryGap {
+                gap_start: covered_end,
+                gap_end: end,
+            }));
+        }
+
+        Ok(validation_report)
+    }
+
+    /// Validates that the `resource_attribute` of every resource descriptor
+    /// satisfies `RESOURCE_ATTRIBUTE_DEPENDENCIES`, e.g. `INITIALIZED` is only
+    /// set together with `PRESENT`.
+    fn validate_resource_attribute_consistency(&self) -> ValidationResult<'a> {
+        let mut validation_report = ValidationReport::new();
+        for hob in self.hob_list {
+            if let HobSerDe::ResourceDescriptor(resource) | HobSerDe::ResourceDescriptorV2 { v1: resource, .. } = hob
+                && RESOURCE_ATTRIBUTE_DEPENDENCIES.iter().any(|((bit, _), (prerequisite, _))| {
+                    resource.resource_attribute & bit != 0 && resource.resource_attribute & prerequisite == 0
+                })
+            {
+                validation_report.add_violation(ValidationKind::Hob(
+                    HobValidationKind::InconsistentResourceAttributeBits {
+                        hob1: resource,
+                        resource_attribute: resource.resource_attribute,
+                    },
+                ));
+            }
+        }
+        Ok(validation_report)
+    }
+
+    /// Checks for presence of the MEMORY_UCE attribute in V2 resource
+    /// descriptors and reports violations if found.
+    fn validate_memory_uce_attribute(&self) -> ValidationResult<'a> {
+        let mut validation_report = ValidationReport::new();
+        for hob in self.hob_list {
+            if let HobSerDe::ResourceDescriptorV2 { v1, attributes } = hob
+                && attributes & efi::MEMORY_UCE != 0
+            {
+                validation_report.add_violation(ValidationKind::Hob(HobValidationKind::V2ContainsUceAttribute {
+                    hob1: v1,
+                    attributes: *attributes,
+                }));
+            }
+        }
+        Ok(validation_report)
+    }
+
+    /// Validates that each V2 resource descriptor has exactly one valid
+    /// cacheability attribute set, reporting violations if none or multiple
+    /// cache bits are present.
+    fn validate_memory_cacheability_attribute(&self) -> ValidationResult<'a> {
+        let mut validation_report = ValidationReport::new();
+        for hob in self.hob_list {
+            if let HobSerDe::ResourceDescriptorV2 { v1, attributes } = hob {
+                const CACHE_ATTRIBUTE_IGNORED_MASK: u64 = !efi::MEMORY_UCE;
+                let mask = efi::CACHE_ATTRIBUTE_MASK & CACHE_ATTRIBUTE_IGNORED_MASK;
+                // Ensure exactly one cache attribute is set:
+                // 1. Check if none of the cache bits are set
+                // 2. Check if more than one bit is set by checking if it is not a power of 2
+                let cache = attributes & mask;
+                if (v1.resource_type != EFI_RESOURCE_IO && v1.resource_type != EFI_RESOURCE_IO_RESERVED)
+                    && (cache == 0 || (cache & (cache - 1)) != 0)
+                {
+                    validation_report.add_violation(ValidationKind::Hob(
+                        HobValidationKind::V2MissingValidCacheabilityAttribute { hob1: v1, attributes: *attributes },
                     ));
                 }
             }
@@ -264,13 +1009,14 @@ impl<'a> HobValidator<'a> {
     }
 
     /// Validates that each V2 resource descriptor with an IO resource type has
-    /// no attributes set.
-    fn validate_memory_cacheability_attribute_io_resource_hob(&self) -> ValidationResult<'_> {
+    /// no attributes set. Memory protection attributes are reported separately
+    /// by `validate_io_resource_protection_attribute`.
+    fn validate_memory_cacheability_attribute_io_resource_hob(&self) -> ValidationResult<'a> {
         let mut validation_report = ValidationReport::new();
         for hob in self.hob_list {
             if let HobSerDe::ResourceDescriptorV2 { v1, attributes } = hob
                 && (v1.resource_type == EFI_RESOURCE_IO || v1.resource_type == EFI_RESOURCE_IO_RESERVED)
-                && *attributes != 0
+                && *attributes & !IO_PROTECTION_ATTRIBUTES != 0
             {
                 validation_report.add_violation(ValidationKind::Hob(
                     HobValidationKind::V2InvalidIoCacheabilityAttributes { hob1: v1, attributes: *attributes },
@@ -280,8 +1026,89 @@ impl<'a> HobValidator<'a> {
         Ok(validation_report)
     }
 
+    /// Validates that each V2 resource descriptor with an IO resource type has
+    /// none of the `MEMORY_RO`, `MEMORY_XP` or `MEMORY_RP` protection
+    /// attributes set.
+    fn validate_io_resource_protection_attribute(&self) -> ValidationResult<'a> {
+        let mut validation_report = ValidationReport::new();
+        for hob in self.hob_list {
+            if let HobSerDe::ResourceDescriptorV2 { v1, attributes } = hob
+                && Self::is_io(v1.resource_type)
+                && *attributes & IO_PROTECTION_ATTRIBUTES != 0
+            {
+                validation_report.add_violation(ValidationKind::Hob(
+                    HobValidationKind::IoResourceHasProtectionAttribute { hob1: v1, attributes: *attributes },
+                ));
+            }
+        }
+        Ok(validation_report)
+    }
+
+    /// Validates that the attributes of every V2 resource descriptor only use
+    /// bits defined by the `EFI_MEMORY_*` attributes. Reserved bits usually
+    /// mean a producer stored flags meant for another field.
+    fn validate_v2_reserved_attribute_bits(&self) -> ValidationResult<'a> {
+        let mut validation_report = ValidationReport::new();
+        for hob in self.hob_list {
+            if let HobSerDe::ResourceDescriptorV2 { v1, attributes } = hob
+                && *attributes & !KNOWN_V2_ATTRIBUTES_MASK != 0
+            {
+                validation_report.add_violation(ValidationKind::Hob(HobValidationKind::V2ReservedAttributeBitsSet {
+                    hob1: v1,
+                    attributes: *attributes,
+                }));
+            }
+        }
+        Ok(validation_report)
+    }
+
+    /// Validates that every GUID-typed HOB field holds a GUID in the canonical
+    /// 8-4-4-4-12 format. Other values only come from hand edited or
+    /// truncated captures.
+    fn validate_guid_strings(&self) -> ValidationResult<'a> {
+        let mut validation_report = ValidationReport::new();
+        for hob in self.hob_list {
+            let (field, guid) = match hob {
+                HobSerDe::ResourceDescriptor(resource) | HobSerDe::ResourceDescriptorV2 { v1: resource, .. } => {
+                    ("Resource Descriptor owner", &resource.owner)
+                }
+                HobSerDe::MemoryAllocation { alloc_descriptor } => ("Memory Allocation name", &alloc_descriptor.name),
+                HobSerDe::GuidExtension { name } => ("GUID Extension name", name),
+                _ => continue,
+            };
+            if !is_canonical_guid(guid) {
+                validation_report.add_violation(ValidationKind::Hob(HobValidationKind::MalformedGuid {
+                    field,
+                    guid: guid.as_str(),
+                }));
+            }
+        }
+        Ok(validation_report)
+    }
+
+    /// Reports the unknown HOBs once, with the distinct type codes of
+    /// `unknown_hobs`. They are counted from `unknown_hobs`, as a capture
+    /// built with `filter_hobs` leaves them out of the HOB list, and from the
+    /// `UnknownHob` entries of captures from older capture tools, which carry
+    /// no type codes.
+    fn validate_unknown_hobs(&self, unknown_hobs: &[UnknownHobSerDe]) -> ValidationResult<'a> {
+        let mut validation_report = ValidationReport::new();
+        let count = match unknown_hobs.len() {
+            0 => self.hob_list.iter().filter(|hob| matches!(hob, HobSerDe::UnknownHob)).count(),
+            count => count,
+        };
+        if count > 0 {
+            let hob_types: BTreeSet<u16> = unknown_hobs.iter().map(|hob| hob.hob_type).collect();
+            validation_report.add_violation(ValidationKind::Hob(HobValidationKind::UnknownHobPresent {
+                count,
+                hob_types: hob_types.into_iter().collect(),
+            }));
+        }
+        Ok(validation_report)
+    }
+
     /// Returns all Resource Descriptor HOBs whose owner is `MEMORY_TYPE_INFO_HOB_GUID`.
-    fn memory_type_info_resource_hobs(&self) -> Vec<&ResourceDescriptorSerDe> {
+    fn memory_type_info_resource_hobs(&self) -> Vec<&'a ResourceDescriptorSerDe> {
         self.hob_list
             .iter()
             .filter_map(|hob| match hob {
@@ -296,17 +1123,61 @@ impl<'a> HobValidator<'a> {
     }
 
     /// Returns the parsed bin entries from the Memory Type Information GUID HOB, if present.
-    fn memory_type_info_entries(&self) -> Option<&[MemoryTypeInfoEntrySerDe]> {
+    fn memory_type_info_entries(&self) -> Option<&'a [MemoryTypeInfoEntrySerDe]> {
         self.hob_list.iter().find_map(|hob| match hob {
             HobSerDe::MemoryTypeInformation { entries } => Some(entries.as_slice()),
             _ => None,
         })
     }
 
+    /// Parses the raw payload of the Memory Type Information GUID HOB, if
+    /// recorded, up to the entry whose `memory_type` is `EfiMaxMemoryType`.
+    /// Unlike `MemoryTypeInformation`, which ends at the first entry with an
+    /// out of range type, entries past an out of range type are kept, as the
+    /// DXE core skips such entries rather than stopping at them.
+    fn raw_memory_type_info_entries(&self) -> Option<Vec<MemoryTypeInfoEntrySerDe>> {
+        let payload = self.guid_hob_data.iter().find(|guid_hob| {
+            OwnedGuid::try_from_string(&guid_hob.name).is_ok_and(|name| name == MEMORY_TYPE_INFO_HOB_GUID)
+        })?;
+        let entries = payload
+            .data
+            .chunks_exact(size_of::<MemoryTypeInfoEntrySerDe>())
+            .map(|chunk| MemoryTypeInfoEntrySerDe {
+                memory_type: u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]),
+                number_of_pages: u32::from_le_bytes([chunk[4], chunk[5], chunk[6], chunk[7]]),
+            })
+            .take_while(|entry| entry.memory_type as usize != EFI_MAX_MEMORY_TYPE)
+            .collect();
+        Some(entries)
+    }
+
+    /// Validates each entry of the Memory Type Information GUID HOB, reporting
+    /// entries whose `memory_type` is not below `EFI_MAX_MEMORY_TYPE` or whose
+    /// `number_of_pages` exceeds `MAX_MEMORY_TYPE_INFO_BIN_PAGES`. The entries
+    /// come from the raw payload when the capture records it.
+    fn validate_memory_type_info(&self) -> ValidationResult<'a> {
+        let mut validation_report = ValidationReport::new();
+        let Some(entries) =
+            self.raw_memory_type_info_entries().or_else(|| self.memory_type_info_entries().map(<[_]>::to_vec))
+        else {
+            return Ok(validation_report);
+        };
+
+        for entry in entries {
+            if entry.memory_type as usize >= EFI_MAX_MEMORY_TYPE
+                || entry.number_of_pages > MAX_MEMORY_TYPE_INFO_BIN_PAGES
+            {
+                validation_report
+                    .add_violation(ValidationKind::Hob(HobValidationKind::InvalidMemoryTypeInfoEntry { entry }));
+            }
+        }
+        Ok(validation_report)
+    }
+
     /// Validates that at most one Resource Descriptor HOB owned by `MEMORY_TYPE_INFO_HOB_GUID`
     /// exists. The DXE core rejects all such HOBs when multiple are present to avoid an
     /// ambiguous bin-region selection. One violation is reported per discovered HOB.
-    fn validate_memory_type_info_single_resource_hob(&self) -> ValidationResult<'_> {
+    fn validate_memory_type_info_single_resource_hob(&self) -> ValidationResult<'a> {
         let mut validation_report = ValidationReport::new();
         let hobs = self.memory_type_info_resource_hobs();
         if hobs.len() > 1 {
@@ -323,7 +1194,7 @@ impl<'a> HobValidator<'a> {
     ///
     /// The check only runs when exactly one Memory Type Info Resource Descriptor HOB is present
     /// and a Memory Type Information GUID HOB has been captured.
-    fn validate_memory_type_info_resource_length(&self) -> ValidationResult<'_> {
+    fn validate_memory_type_info_resource_length(&self) -> ValidationResult<'a> {
         let mut validation_report = ValidationReport::new();
         let hobs = self.memory_type_info_resource_hobs();
         let [resource] = hobs[..] else {
@@ -347,34 +1218,308 @@ impl<'a> HobValidator<'a> {
         }
         Ok(validation_report)
     }
+
+    /// Validates that the `physical_start` and `resource_length` of every
+    /// non-IO resource descriptor are multiples of `UEFI_PAGE_SIZE`. IO
+    /// descriptors describe port ranges and are not page-oriented.
+    fn validate_resource_page_alignment(&self) -> ValidationResult<'a> {
+        let mut validation_report = ValidationReport::new();
+        const PAGE_SIZE: u64 = UEFI_PAGE_SIZE as u64;
+        for hob in self.hob_list {
+            if let HobSerDe::ResourceDescriptor(resource) | HobSerDe::ResourceDescriptorV2 { v1: resource, .. } = hob
+                && !Self::is_io(resource.resource_type)
+                && (resource.physical_start % PAGE_SIZE != 0 || resource.resource_length % PAGE_SIZE != 0)
+            {
+                validation_report.add_violation(ValidationKind::Hob(HobValidationKind::UnalignedResourceDescriptor {
+                    hob1: resource,
+                }));
+            }
+        }
+        Ok(validation_report)
+    }
+
+    /// Validates that no memory allocation overlaps an MMIO or reserved
+    /// resource descriptor. Each overlapping allocation and descriptor pair is
+    /// reported along with the allocation memory type.
+    fn validate_allocation_vs_mmio(&self) -> ValidationResult<'a> {
+        let mut validation_report = ValidationReport::new();
+        let mmio_resources: Vec<&ResourceDescriptorSerDe> = self
+            .hob_list
+            .iter()
+            .filter_map(|hob| match hob {
+                HobSerDe::ResourceDescriptor(resource) | HobSerDe::ResourceDescriptorV2 { v1: resource, .. }
+                    if (resource.resource_type == EFI_RESOURCE_MEMORY_MAPPED_IO
+                        || resource.resource_type == EFI_RESOURCE_MEMORY_RESERVED)
+                        && Self::is_well_formed_resource(resource) =>
+                {
+                    Some(resource)
+                }
+                _ => None,
+            })
+            .collect();
+
+        for hob in self.hob_list {
+            let HobSerDe::MemoryAllocation { alloc_descriptor } = hob else { continue };
+            if !Self::is_well_formed_allocation(alloc_descriptor) {
+                continue;
+            }
+            for resource in &mmio_resources {
+                if alloc_descriptor.start() < resource.end() && resource.start() < alloc_descriptor.end() {
+                    validation_report.add_violation(ValidationKind::Hob(HobValidationKind::AllocationInMmioRange {
+                        alloc_desc: alloc_descriptor,
+                        hob1: resource,
+                    }));
+                }
+            }
+        }
+
+        Ok(validation_report)
+    }
+
+    /// Validates that no memory resource descriptor extends past the address
+    /// ceiling of `2^size_of_memory_space` advertised by the first CPU HOB.
+    /// The check is skipped when no CPU HOB has been captured.
+    fn validate_resources_within_cpu_space(&self) -> ValidationResult<'a> {
+        let mut validation_report = ValidationReport::new();
+        let Some(size_of_memory_space) = self.hob_list.iter().find_map(|hob| match hob {
+            HobSerDe::Cpu { size_of_memory_space, .. } => Some(*size_of_memory_space),
+            _ => None,
+        }) else {
+            return Ok(validation_report);
+        };
+
+        // A 64-bit (or wider) memory space covers every representable address.
+        let Some(address_ceiling) = 1u64.checked_shl(size_of_memory_space as u32) else {
+            return Ok(validation_report);
+        };
+
+        for hob in self.hob_list {
+            if let HobSerDe::ResourceDescriptor(resource) | HobSerDe::ResourceDescriptorV2 { v1: resource, .. } = hob
+                && !Self::is_io(resource.resource_type)
+                && Self::is_well_formed_resource(resource)
+                && resource.end() > address_ceiling
+            {
+                validation_report.add_violation(ValidationKind::Hob(
+                    HobValidationKind::ResourceBeyondCpuAddressSpace { hob1: resource, size_of_memory_space },
+                ));
+            }
+        }
+        Ok(validation_report)
+    }
+
+    /// Validates that exactly one Handoff (PHIT) HOB is present in the HOB
+    /// list. A missing or repeated Handoff HOB is reported as a single
+    /// violation carrying the number of Handoff HOBs found.
+    fn validate_handoff_presence(&self) -> ValidationResult<'a> {
+        let mut validation_report = ValidationReport::new();
+        let count = self.hob_list.iter().filter(|hob| matches!(hob, HobSerDe::Handoff { .. })).count();
+        if count != 1 {
+            validation_report
+                .add_violation(ValidationKind::Hob(HobValidationKind::MissingOrDuplicateHandoff { count }));
+        }
+        Ok(validation_report)
+    }
+
+    /// Validates the GUID Extension HOBs against `GUID_HOB_RULES`, reporting
+    /// each prohibited GUID HOB found and each required GUID HOB missing. The
+    /// parsed Memory Type Information HOB counts as a GUID HOB named
+    /// `MEMORY_TYPE_INFO_HOB_GUID`.
+    fn validate_guid_hobs(&self) -> ValidationResult<'a> {
+        let mut validation_report = ValidationReport::new();
+        let guid_eq = |name: &str, rule_guid: &str| {
+            OwnedGuid::try_from_string(name).is_ok_and(|name| Ok(name) == OwnedGuid::try_from_string(rule_guid))
+        };
+
+        for (rule_guid, rule) in GUID_HOB_RULES {
+            match rule {
+                GuidHobRule::Prohibited => {
+                    for hob in self.hob_list {
+                        if let HobSerDe::GuidExtension { name } = hob
+                            && guid_eq(name, rule_guid)
+                        {
+                            validation_report
+                                .add_violation(ValidationKind::Hob(HobValidationKind::ProhibitedGuidHob { name }));
+                        }
+                    }
+                }
+                GuidHobRule::Required => {
+                    let present = self.hob_list.iter().any(|hob| match hob {
+                        HobSerDe::GuidExtension { name } => guid_eq(name, rule_guid),
+                        HobSerDe::MemoryTypeInformation { .. } => {
+                            OwnedGuid::try_from_string(rule_guid).is_ok_and(|guid| guid == MEMORY_TYPE_INFO_HOB_GUID)
+                        }
+                        _ => false,
+                    });
+                    if !present {
+                        validation_report.add_violation(ValidationKind::Hob(
+                            HobValidationKind::MissingRequiredGuidHob { name: rule_guid },
+                        ));
+                    }
+                }
+            }
+        }
+
+        Ok(validation_report)
+    }
+
+    /// Validates that the version of each Handoff HOB is
+    /// `SUPPORTED_HANDOFF_VERSION`. The `LEGACY_HANDOFF_VERSION` of older PEI
+    /// cores is reported separately, as a warning.
+    fn validate_handoff_version(&self) -> ValidationResult<'a> {
+        let mut validation_report = ValidationReport::new();
+        for hob in self.hob_list {
+            match *hob {
+                HobSerDe::Handoff { version: SUPPORTED_HANDOFF_VERSION, .. } => {}
+                HobSerDe::Handoff { version: LEGACY_HANDOFF_VERSION, .. } => {
+                    validation_report.add_violation(ValidationKind::Hob(HobValidationKind::LegacyHandoffVersion {
+                        version: LEGACY_HANDOFF_VERSION,
+                    }))
+                }
+                HobSerDe::Handoff { version, .. } => validation_report
+                    .add_violation(ValidationKind::Hob(HobValidationKind::UnsupportedHandoffVersion { version })),
+                _ => {}
+            }
+        }
+        Ok(validation_report)
+    }
+
+    /// Validates that the free memory range described by each Handoff HOB lies
+    /// within its memory range and is not inverted.
+    fn validate_handoff_memory_bounds(&self) -> ValidationResult<'a> {
+        let mut validation_report = ValidationReport::new();
+        for hob in self.hob_list {
+            if let HobSerDe::Handoff { memory_top, memory_bottom, free_memory_top, free_memory_bottom, .. } = *hob
+                && (free_memory_bottom < memory_bottom
+                    || free_memory_top > memory_top
+                    || free_memory_bottom > free_memory_top)
+            {
+                validation_report.add_violation(ValidationKind::Hob(HobValidationKind::InconsistentHandoffBounds {
+                    memory_bottom,
+                    memory_top,
+                    free_memory_bottom,
+                    free_memory_top,
+                }));
+            }
+        }
+        Ok(validation_report)
+    }
 }
 
-impl Validator for HobValidator<'_> {
-    fn validate(&self) -> ValidationResult<'_> {
+impl<'a> Validator<'a> for HobValidator<'a> {
+    fn validate(&self) -> ValidationResult<'a> {
         let mut validation_report = ValidationReport::new();
         if self.hob_list.is_empty() {
-            return Err(ValidationAppError::EmptyHobList);
+            if !self.allow_empty {
+                return Err(ValidationAppError::EmptyHobList);
+            }
+            validation_report.add_note(ValidationAppError::EmptyHobList.to_string());
+            return Ok(validation_report);
         }
 
-        validation_report.append_report(self.validate_memory_overlap()?);
-        validation_report.append_report(self.validate_overlapping_v1v2_attributes()?);
-        validation_report.append_report(self.validate_v1v2_superset()?);
-        validation_report.append_report(self.validate_page0_memory_allocation()?);
-        validation_report.append_report(self.validate_memory_uce_attribute()?);
-        validation_report.append_report(self.validate_memory_cacheability_attribute()?);
-        validation_report.append_report(self.validate_memory_cacheability_attribute_io_resource_hob()?);
-        validation_report.append_report(self.validate_memory_type_info_single_resource_hob()?);
-        validation_report.append_report(self.validate_memory_type_info_resource_length()?);
-        Ok(validation_report)
+        let items = self.hob_list.len();
+        validation_report.set_hob_summary(self.summarize());
+        validation_report.set_memory_footprint(self.memory_footprint());
+
+        let mut passes = Passes::new(validation_report, items, &self.filter, self.fail_fast.as_ref());
+        passes.run("validate_guid_strings", &["MalformedGuid"], || self.validate_guid_strings())?;
+        passes.run("validate_handoff_presence", &["MissingOrDuplicateHandoff"], || self.validate_handoff_presence())?;
+        passes.run("validate_handoff_version", &["UnsupportedHandoffVersion", "LegacyHandoffVersion"], || {
+            self.validate_handoff_version()
+        })?;
+        passes.run("validate_handoff_memory_bounds", &["InconsistentHandoffBounds"], || {
+            self.validate_handoff_memory_bounds()
+        })?;
+        passes.run("validate_resource_range_overflow", &["ResourceRangeOverflow"], || {
+            self.validate_resource_range_overflow()
+        })?;
+        passes.run("validate_zero_length_ranges", &["ZeroLengthRange"], || self.validate_zero_length_ranges())?;
+        passes.run("validate_memory_overlap", &["OverlappingMemoryRanges", "OverlappingMemoryCluster"], || {
+            self.validate_memory_overlap()
+        })?;
+        passes.run("validate_io_port_ranges", &["OverlappingIoPortRanges", "IoPortRangeOutOfBounds"], || {
+            self.validate_io_port_ranges()
+        })?;
+        passes.run("validate_duplicate_resources", &["DuplicateResourceDescriptor"], || {
+            self.validate_duplicate_resources()
+        })?;
+        passes.run("validate_memory_allocation_overlap", &["OverlappingMemoryAllocations"], || {
+            self.validate_memory_allocation_overlap()
+        })?;
+        passes.run("validate_fv_hob_overlap", &["OverlappingFirmwareVolumes"], || self.validate_fv_hob_overlap())?;
+        passes.run("validate_duplicate_allocation_names", &["DuplicateAllocationName"], || {
+            self.validate_duplicate_allocation_names()
+        })?;
+        passes.run("validate_overlapping_v1v2_attributes", &["InconsistentMemoryAttributes"], || {
+            self.validate_overlapping_v1v2_attributes()
+        })?;
+        passes.run("validate_v1v2_superset", &["V1MemoryRangeNotContainedInV2"], || self.validate_v1v2_superset())?;
+        passes.run("validate_page0_memory_allocation", &["PageZeroMemoryDescribed"], || {
+            self.validate_page0_memory_allocation()
+        })?;
+        passes.run("validate_memory_allocation_type", &["InvalidMemoryType"], || {
+            self.validate_memory_allocation_type()
+        })?;
+        passes.run("validate_allocation_within_resource", &["AllocationOutsideDeclaredMemory"], || {
+            self.validate_allocation_within_resource()
+        })?;
+        passes.run("validate_allocation_vs_mmio", &["AllocationInMmioRange"], || self.validate_allocation_vs_mmio())?;
+        passes.run("validate_resources_within_cpu_space", &["ResourceBeyondCpuAddressSpace"], || {
+            self.validate_resources_within_cpu_space()
+        })?;
+        passes.run("validate_resource_page_alignment", &["UnalignedResourceDescriptor"], || {
+            self.validate_resource_page_alignment()
+        })?;
+        passes.run("validate_resource_attribute_consistency", &["InconsistentResourceAttributeBits"], || {
+            self.validate_resource_attribute_consistency()
+        })?;
+        passes.run("validate_memory_uce_attribute", &["V2ContainsUceAttribute"], || {
+            self.validate_memory_uce_attribute()
+        })?;
+        passes.run("validate_memory_cacheability_attribute", &["V2MissingValidCacheabilityAttribute"], || {
+            self.validate_memory_cacheability_attribute()
+        })?;
+        passes.run(
+            "validate_memory_cacheability_attribute_io_resource_hob",
+            &["V2InvalidIoCacheabilityAttributes"],
+            || self.validate_memory_cacheability_attribute_io_resource_hob(),
+        )?;
+        passes.run("validate_io_resource_protection_attribute", &["IoResourceHasProtectionAttribute"], || {
+            self.validate_io_resource_protection_attribute()
+        })?;
+        passes.run("validate_v2_reserved_attribute_bits", &["V2ReservedAttributeBitsSet"], || {
+            self.validate_v2_reserved_attribute_bits()
+        })?;
+        passes.run("validate_guid_hobs", &["MissingRequiredGuidHob", "ProhibitedGuidHob"], || {
+            self.validate_guid_hobs()
+        })?;
+        passes
+            .run("validate_memory_type_info", &["InvalidMemoryTypeInfoEntry"], || self.validate_memory_type_info())?;
+        passes.run("validate_memory_type_info_single_resource_hob", &["MemoryTypeInfoMultipleResourceHobs"], || {
+            self.validate_memory_type_info_single_resource_hob()
+        })?;
+        passes.run("validate_memory_type_info_resource_length", &["MemoryTypeInfoResourceLengthTooSmall"], || {
+            self.validate_memory_type_info_resource_length()
+        })?;
+        if let Some(end) = self.require_contiguous {
+            passes.run("validate_memory_contiguity", &["MemoryGap"], || self.validate_memory_contiguity(end))?;
+        }
+        if self.require_v2 {
+            passes.run("validate_v2_presence", &["NoV2DescriptorsPresent"], || self.validate_v2_presence())?;
+        }
+        if let Some(unknown_hobs) = self.unknown_hobs {
+            passes.run("validate_unknown_hobs", &["UnknownHobPresent"], || self.validate_unknown_hobs(unknown_hobs))?;
+        }
+        Ok(passes.finish())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::validation_kind::Severity;
     use patina::pi::{
         hob::{EFI_RESOURCE_IO, EFI_RESOURCE_IO_RESERVED, EfiPhysicalAddress},
-        serializable::serializable_hob::{MemAllocDescriptorSerDe, ResourceDescriptorSerDe},
+        serializable::serializable_hob::ResourceDescriptorSerDe,
     };
 
     fn create_v1_hob(
@@ -433,6 +1578,30 @@ mod tests {
         assert_eq!(validation_report.violation_count(), 0);
     }
 
+    #[test]
+    fn test_validate_io_port_ranges() {
+        // IO ports and memory are separate address spaces, so an IO range over
+        // the same numbers as a memory range does not overlap it
+        let memory = create_v1_hob(0x0, 0x1000, EFI_RESOURCE_SYSTEM_MEMORY, 0, "owner1");
+        let legacy = create_v1_hob(0x60, 0x10, EFI_RESOURCE_IO, 0, "owner1");
+        let pci_config = create_v1_hob(0xCF8, 0x8, EFI_RESOURCE_IO_RESERVED, 0, "owner1");
+        let top = create_v1_hob(0xF000, 0x1000, EFI_RESOURCE_IO, 0, "owner1");
+        let hob_list = vec![memory, legacy, pci_config, top];
+        let validator = HobValidator::new(&hob_list);
+        assert_eq!(validator.validate_memory_overlap().unwrap().violation_count(), 0);
+        assert_eq!(validator.validate_io_port_ranges().unwrap().violation_count(), 0);
+
+        let aperture = create_v1_hob(0x0, 0x1000, EFI_RESOURCE_IO, 0, "owner1");
+        let beyond = create_v2_hob(0xFFFF, 0x2, EFI_RESOURCE_IO, 0, "owner1", 0);
+        let hob_list = vec![aperture, hob_list[2].clone(), beyond];
+        let validator = HobValidator::new(&hob_list);
+        let validation_report = validator.validate_io_port_ranges().unwrap();
+        assert_eq!(validation_report.violation_count(), 2);
+        let names: Vec<&str> = validation_report.iter_violations().map(|(name, _)| name).collect();
+        assert_eq!(names, vec!["IoPortRangeOutOfBounds", "OverlappingIoPortRanges"]);
+        assert_eq!(validator.validate_memory_overlap().unwrap().violation_count(), 0);
+    }
+
     #[test]
     fn test_validate_v1v2_superset_ok() {
         // V1 hob fully covered by single V2
@@ -463,6 +1632,67 @@ mod tests {
         assert_eq!(validation_report.violation_count(), 0);
     }
 
+    #[test]
+    fn test_interval_boundaries() {
+        let resource = |physical_start, resource_length| ResourceDescriptorSerDe {
+            owner: "owner1".to_string(),
+            resource_type: 0,
+            resource_attribute: 0,
+            physical_start,
+            resource_length,
+        };
+        let low = resource(0x1000, 0x1000);
+        let high = resource(0x2000, 0x1000);
+        let last_byte = resource(0x1fff, 1);
+
+        // end() is exclusive: abutting ranges touch without overlapping
+        assert_eq!(low.end(), high.start());
+        assert!(!low.overlaps(&high) && !high.overlaps(&low));
+        assert!(low.adjacent(&high));
+        assert!(HobValidator::check_hob_overlap(&[&low, &high]).is_empty());
+
+        // A single byte range is contained in and overlaps only its own range
+        assert!(low.contains(&last_byte));
+        assert!(!high.contains(&last_byte));
+        assert!(last_byte.overlaps(&low));
+        assert!(!last_byte.overlaps(&high));
+        assert_eq!(HobValidator::check_hob_overlap(&[&low, &high, &last_byte]), vec![(&low, &last_byte)]);
+
+        // Abutting ranges merge into one that contains both
+        let merged = Interval::merge_intervals(&[&high, &low]);
+        assert_eq!(merged.len(), 1);
+        assert!(merged[0].contains(&low) && merged[0].contains(&high));
+        assert_eq!((merged[0].start(), merged[0].end()), (0x1000, 0x3000));
+    }
+
+    #[test]
+    fn test_check_v1v2_abutting_superset_ok() {
+        // Interval ends are exclusive, so [100, 200) and [200, 300) touch with
+        // no gap and are merged before the containment check. The second V1
+        // spans the boundary and is only covered by the merged range.
+        let v1_hob1 = create_v1_hob(200, 100, 3, 0, "owner1");
+        let v1_hob2 = create_v1_hob(150, 100, 3, 0, "owner1");
+        let v2_hob1 = create_v2_hob(100, 100, 3, 0, "owner1", 123);
+        let v2_hob2 = create_v2_hob(200, 100, 3, 0, "owner1", 123);
+        let hob_list = vec![v1_hob1, v1_hob2, v2_hob1, v2_hob2];
+
+        let validator = HobValidator::new(&hob_list);
+        let result = validator.validate_v1v2_superset();
+        assert!(result.is_ok());
+        let validation_report = result.unwrap();
+        assert_eq!(validation_report.violation_count(), 0);
+
+        // A single byte gap between the V2 ranges is still reported
+        let v1_hob = create_v1_hob(150, 100, 3, 0, "owner1");
+        let v2_hob1 = create_v2_hob(100, 99, 3, 0, "owner1", 123);
+        let v2_hob2 = create_v2_hob(200, 100, 3, 0, "owner1", 123);
+        let hob_list = vec![v1_hob, v2_hob1, v2_hob2];
+
+        let validator = HobValidator::new(&hob_list);
+        let validation_report = validator.validate_v1v2_superset().unwrap();
+        assert_eq!(validation_report.violation_count(), 1);
+    }
+
     #[test]
     fn test_validate_v1v2_superset_fail() {
         // V1 not fully covered (gap)
@@ -608,6 +1838,25 @@ mod tests {
         assert_ne!(validation_report.violation_count(), 0);
     }
 
+    #[test]
+    fn test_memory_v2_single_cacheability_attribute_with_other_bits() {
+        // A single cacheability attribute is valid no matter which bits outside
+        // the cacheability mask are set, including ones below it (UCE) that
+        // would change the result of subtracting one from the unmasked value.
+        for attributes in [
+            efi::MEMORY_WB | efi::MEMORY_RP | efi::MEMORY_XP | efi::MEMORY_RO,
+            efi::MEMORY_WP | efi::MEMORY_RP | efi::MEMORY_XP | efi::MEMORY_RO | efi::MEMORY_UCE,
+            efi::MEMORY_UC | efi::MEMORY_SP | efi::MEMORY_RUNTIME,
+        ] {
+            let hob_list = vec![create_v2_hob(100, 100, 3, 0, "owner1", attributes)];
+            let validator = HobValidator::new(&hob_list);
+            let result = validator.validate_memory_cacheability_attribute();
+            assert!(result.is_ok());
+            let validation_report = result.unwrap();
+            assert_eq!(validation_report.violation_count(), 0, "attributes {:#x}", attributes);
+        }
+    }
+
     #[test]
     fn test_memory_v2_access_protection_attributes() {
         // +ve test - valid cacheability attribute specified with a single access protection attribute
@@ -650,18 +1899,138 @@ mod tests {
         assert_ne!(validation_report.violation_count(), 0);
     }
 
-    /// String form of `MEMORY_TYPE_INFO_HOB_GUID` used for tests.
-    fn mem_info_owner() -> String {
-        MEMORY_TYPE_INFO_HOB_GUID.as_guid().to_string()
-    }
+    #[test]
+    fn test_v2_reserved_attribute_bits() {
+        // +ve test - every defined attribute bit is accepted
+        let v2_hob =
+            create_v2_hob(0x100000, 0x100000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, "owner1", KNOWN_V2_ATTRIBUTES_MASK);
+        let hob_list = vec![v2_hob];
+        let result = HobValidator::new(&hob_list).validate_v2_reserved_attribute_bits();
+        assert_eq!(result.unwrap().violation_count(), 0);
 
-    /// String form of `OwnedGuid::ZERO` used for tests.
-    fn zero_owner() -> String {
-        OwnedGuid::ZERO.to_string()
+        // -ve test - a reserved bit, alone or next to defined ones, is reported
+        for attributes in [0x20, efi::MEMORY_WB | 0x100, 0x0000_0100_0000_0000] {
+            let v2_hob = create_v2_hob(0x100000, 0x100000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, "owner1", attributes);
+            let hob_list = vec![v2_hob];
+            let result = HobValidator::new(&hob_list).validate_v2_reserved_attribute_bits();
+            assert_eq!(result.unwrap().violation_count(), 1, "0x{:X}", attributes);
+        }
     }
 
-    /// Test that a V1 resource descriptor HOB owned by `MEMORY_TYPE_INFO_HOB_GUID`
-    /// that overlaps a V2 system memory range with different `resource_attribute`
+    #[test]
+    fn test_guid_strings() {
+        // +ve test - canonical GUIDs in either case are accepted
+        let hob_list = vec![
+            create_v1_hob(0x100000, 0x100000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, &zero_owner()),
+            create_memory_hob("4ED4BF27-4092-42E9-807D-527B1D00C9BD".to_string(), 0x100000, 0x1000, 4),
+            HobSerDe::GuidExtension { name: "4c19049f-4137-4dd3-9c10-8b97a83ffdfa".to_string() },
+        ];
+        let result = HobValidator::new(&hob_list).validate_guid_strings();
+        assert_eq!(result.unwrap().violation_count(), 0);
+
+        // -ve test - garbage, dashless and truncated GUIDs are reported for every GUID-typed field
+        let hob_list = vec![
+            create_v1_hob(0x100000, 0x100000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, "owner1"),
+            create_v2_hob(0x200000, 0x100000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, "owner1", efi::MEMORY_WB),
+            create_memory_hob("4ed4bf274092 42e9807d527b1d00c9bd".to_string(), 0x100000, 0x1000, 4),
+            HobSerDe::GuidExtension { name: "4c19049f-4137-4dd3-9c10-8b97a83f".to_string() },
+        ];
+        let result = HobValidator::new(&hob_list).validate_guid_strings();
+        let validation_report = result.unwrap();
+        assert_eq!(validation_report.violation_count(), 4);
+    }
+
+    #[test]
+    fn test_unknown_hobs() {
+        let unknown_hob_count = |validator: HobValidator| {
+            let validation_report = validator.validate().unwrap();
+            validation_report.iter_violations().filter(|(kind, _)| *kind == "UnknownHobPresent").count()
+        };
+        let hob_list = vec![
+            create_handoff_hob(SUPPORTED_HANDOFF_VERSION, HANDOFF_MEMORY, HANDOFF_FREE_MEMORY),
+            HobSerDe::UnknownHob,
+            HobSerDe::UnknownHob,
+            HobSerDe::UnknownHob,
+        ];
+        let unknown_hobs = [0x9, 0x5, 0x9].map(|hob_type| UnknownHobSerDe { hob_type, length: 0x20 });
+
+        // Unknown HOBs are ignored unless requested
+        assert_eq!(unknown_hob_count(HobValidator::new(&hob_list)), 0);
+        assert_eq!(unknown_hob_count(HobValidator::new(&hob_list).with_flag_unknown_hobs(Some(&unknown_hobs))), 1);
+
+        // -ve test - all unknown HOBs are reported once, with their distinct type codes
+        let result = HobValidator::new(&hob_list).validate_unknown_hobs(&unknown_hobs);
+        let validation_report = result.unwrap();
+        assert_eq!(validation_report.violation_count(), 1);
+        let (_, violation) = validation_report.iter_violations().next().unwrap();
+        assert!(matches!(
+            violation,
+            ValidationKind::Hob(HobValidationKind::UnknownHobPresent { count: 3, hob_types }) if *hob_types == [0x5, 0x9]
+        ));
+
+        // -ve test - a filtered capture records the unknown HOBs only in unknown_hobs
+        let hob_list = vec![create_handoff_hob(SUPPORTED_HANDOFF_VERSION, HANDOFF_MEMORY, HANDOFF_FREE_MEMORY)];
+        let result = HobValidator::new(&hob_list).validate_unknown_hobs(&unknown_hobs);
+        let validation_report = result.unwrap();
+        let (_, violation) = validation_report.iter_violations().next().unwrap();
+        assert!(matches!(
+            violation,
+            ValidationKind::Hob(HobValidationKind::UnknownHobPresent { count: 3, hob_types }) if *hob_types == [0x5, 0x9]
+        ));
+
+        // +ve test - no unknown HOBs
+        let result = HobValidator::new(&hob_list).validate_unknown_hobs(&[]);
+        assert_eq!(result.unwrap().violation_count(), 0);
+    }
+
+    #[test]
+    fn test_io_resource_protection_attributes() {
+        // +ve test - an io resource descriptor without attributes
+        let v2_hob = create_v2_hob(100, 100, EFI_RESOURCE_IO, 0, "owner1", 0);
+        let hob_list = vec![v2_hob];
+        let validator = HobValidator::new(&hob_list);
+        let result = validator.validate_io_resource_protection_attribute();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 0);
+
+        // -ve test - protection attributes on an io resource descriptor are reported as
+        // protection violations only, not as cacheability violations
+        for attributes in [efi::MEMORY_RO, efi::MEMORY_XP, efi::MEMORY_RP] {
+            let v2_hob = create_v2_hob(100, 100, EFI_RESOURCE_IO_RESERVED, 0, "owner1", attributes);
+            let hob_list = vec![v2_hob];
+            let validator = HobValidator::new(&hob_list);
+            let result = validator.validate_io_resource_protection_attribute();
+            assert!(result.is_ok());
+            assert_eq!(result.unwrap().violation_count(), 1);
+            let result = validator.validate_memory_cacheability_attribute_io_resource_hob();
+            assert!(result.is_ok());
+            assert_eq!(result.unwrap().violation_count(), 0);
+        }
+
+        // -ve test - cacheability and protection attributes are both reported
+        let v2_hob = create_v2_hob(100, 100, EFI_RESOURCE_IO, 0, "owner1", efi::MEMORY_UC | efi::MEMORY_XP);
+        let hob_list = vec![v2_hob];
+        let validator = HobValidator::new(&hob_list);
+        let result = validator.validate_io_resource_protection_attribute();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 1);
+        let result = validator.validate_memory_cacheability_attribute_io_resource_hob();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 1);
+    }
+
+    /// String form of `MEMORY_TYPE_INFO_HOB_GUID` used for tests.
+    fn mem_info_owner() -> String {
+        MEMORY_TYPE_INFO_HOB_GUID.as_guid().to_string()
+    }
+
+    /// String form of `OwnedGuid::ZERO` used for tests.
+    fn zero_owner() -> String {
+        OwnedGuid::ZERO.to_string()
+    }
+
+    /// Test that a V1 resource descriptor HOB owned by `MEMORY_TYPE_INFO_HOB_GUID`
+    /// that overlaps a V2 system memory range with different `resource_attribute`
     /// does not get flagged.
     #[test]
     fn test_memory_type_info_v1_overlap_with_v2_is_not_flagged() {
@@ -920,4 +2289,968 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(result.unwrap().violation_count(), 0);
     }
+
+    // Memory and free memory ranges, as (bottom, top), of the Handoff HOB of a QEMU Q35 boot
+    const HANDOFF_MEMORY: (u64, u64) = (0x7afbe000, 0x7f000000);
+    const HANDOFF_FREE_MEMORY: (u64, u64) = (0x7afe1d48, 0x7e7ff000);
+
+    fn create_handoff_hob(version: u32, memory: (u64, u64), free_memory: (u64, u64)) -> HobSerDe {
+        HobSerDe::Handoff {
+            version,
+            memory_top: memory.1,
+            memory_bottom: memory.0,
+            free_memory_top: free_memory.1,
+            free_memory_bottom: free_memory.0,
+            end_of_hob_list: free_memory.0,
+        }
+    }
+
+    #[test]
+    fn test_single_handoff_hob_is_ok() {
+        // A single Handoff HOB is the expected configuration and must not be flagged.
+        let hob_list = vec![
+            create_handoff_hob(SUPPORTED_HANDOFF_VERSION, HANDOFF_MEMORY, HANDOFF_FREE_MEMORY),
+            create_v1_hob(0x100000, 0x1000, 0, 0x7, "owner1"),
+        ];
+
+        let validator = HobValidator::new(&hob_list);
+        let result = validator.validate_handoff_presence();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 0);
+    }
+
+    #[test]
+    fn test_missing_handoff_hob_is_flagged() {
+        // A HOB list without a Handoff HOB must be flagged.
+        let hob_list = vec![create_v1_hob(0x100000, 0x1000, 0, 0x7, "owner1")];
+
+        let validator = HobValidator::new(&hob_list);
+        let result = validator.validate_handoff_presence();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 1);
+    }
+
+    #[test]
+    fn test_duplicate_handoff_hobs_are_flagged() {
+        // Multiple Handoff HOBs are reported as a single violation.
+        let hob_list = vec![
+            create_handoff_hob(SUPPORTED_HANDOFF_VERSION, HANDOFF_MEMORY, HANDOFF_FREE_MEMORY),
+            create_handoff_hob(SUPPORTED_HANDOFF_VERSION, HANDOFF_MEMORY, HANDOFF_FREE_MEMORY),
+        ];
+
+        let validator = HobValidator::new(&hob_list);
+        let result = validator.validate_handoff_presence();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 1);
+    }
+
+    #[test]
+    fn test_handoff_memory_bounds_ok() {
+        // A free memory range nested inside the Handoff memory range must not be flagged.
+        let hob_list = vec![create_handoff_hob(SUPPORTED_HANDOFF_VERSION, HANDOFF_MEMORY, HANDOFF_FREE_MEMORY)];
+
+        let validator = HobValidator::new(&hob_list);
+        let result = validator.validate_handoff_memory_bounds();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 0);
+    }
+
+    #[test]
+    fn test_handoff_memory_bounds_inconsistent_is_flagged() {
+        // Each broken Handoff memory bound invariant must be flagged.
+        // free_memory_bottom below memory_bottom
+        let hob_list = vec![create_handoff_hob(SUPPORTED_HANDOFF_VERSION, (0x2000, 0x9000), (0x1000, 0x8000))];
+        let validator = HobValidator::new(&hob_list);
+        assert_eq!(validator.validate_handoff_memory_bounds().unwrap().violation_count(), 1);
+
+        // free_memory_top above memory_top
+        let hob_list = vec![create_handoff_hob(SUPPORTED_HANDOFF_VERSION, (0x1000, 0x8000), (0x2000, 0x9000))];
+        let validator = HobValidator::new(&hob_list);
+        assert_eq!(validator.validate_handoff_memory_bounds().unwrap().violation_count(), 1);
+
+        // free_memory_bottom above free_memory_top
+        let hob_list = vec![create_handoff_hob(SUPPORTED_HANDOFF_VERSION, (0x1000, 0x9000), (0x8000, 0x2000))];
+        let validator = HobValidator::new(&hob_list);
+        assert_eq!(validator.validate_handoff_memory_bounds().unwrap().violation_count(), 1);
+    }
+
+    #[test]
+    fn test_memory_allocation_adjacent_is_ok() {
+        // Adjacent (touching) memory allocations must not be flagged as overlapping.
+        let mem_hob1 = create_memory_hob("alloc1".to_string(), 0x100000, 0x1000, 4);
+        let mem_hob2 = create_memory_hob("alloc2".to_string(), 0x101000, 0x1000, 4);
+        let hob_list = vec![mem_hob1, mem_hob2];
+
+        let validator = HobValidator::new(&hob_list);
+        let result = validator.validate_memory_allocation_overlap();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 0);
+    }
+
+    #[test]
+    fn test_memory_allocation_overlap_is_flagged() {
+        // Every overlapping pair of memory allocations must be flagged.
+        let mem_hob1 = create_memory_hob("alloc1".to_string(), 0x100000, 0x3000, 4);
+        let mem_hob2 = create_memory_hob("alloc2".to_string(), 0x101000, 0x1000, 4);
+        let mem_hob3 = create_memory_hob("alloc3".to_string(), 0x102000, 0x2000, 4);
+        let hob_list = vec![mem_hob1, mem_hob2, mem_hob3];
+
+        let validator = HobValidator::new(&hob_list);
+        let result = validator.validate_memory_allocation_overlap();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 2);
+    }
+
+    #[test]
+    fn test_allocation_within_resource_is_ok() {
+        // An allocation fully inside a system memory descriptor must not be flagged.
+        let v2 = create_v2_hob(0x100000, 0x100000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, "owner1", efi::MEMORY_WB);
+        let mem_hob = create_memory_hob("alloc".to_string(), 0x110000, 0x1000, 4);
+        let hob_list = vec![v2, mem_hob];
+
+        let validator = HobValidator::new(&hob_list);
+        let result = validator.validate_allocation_within_resource();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 0);
+    }
+
+    #[test]
+    fn test_allocation_straddling_adjacent_resources_is_ok() {
+        // An allocation straddling two adjacent system memory descriptors is
+        // covered by their merged range and must not be flagged.
+        let v1a = create_v1_hob(0x100000, 0x10000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, "owner1");
+        let v1b = create_v1_hob(0x110000, 0x10000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, "owner1");
+        let mem_hob = create_memory_hob("alloc".to_string(), 0x10F000, 0x2000, 4);
+        let hob_list = vec![v1a, v1b, mem_hob];
+
+        let validator = HobValidator::new(&hob_list);
+        let result = validator.validate_allocation_within_resource();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 0);
+    }
+
+    #[test]
+    fn test_allocation_outside_resource_is_flagged() {
+        // Allocations partially or entirely outside system memory, or inside
+        // non-system-memory descriptors, must be flagged.
+        let v1 = create_v1_hob(0x100000, 0x10000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, "owner1");
+        let mmio = create_v1_hob(0x200000, 0x10000, EFI_RESOURCE_MEMORY_MAPPED_IO, 0x7, "owner1");
+        let partial = create_memory_hob("partial".to_string(), 0x10F000, 0x2000, 4);
+        let outside = create_memory_hob("outside".to_string(), 0x300000, 0x1000, 4);
+        let in_mmio = create_memory_hob("in_mmio".to_string(), 0x200000, 0x1000, 4);
+        let hob_list = vec![v1, mmio, partial, outside, in_mmio];
+
+        let validator = HobValidator::new(&hob_list);
+        let result = validator.validate_allocation_within_resource();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 3);
+    }
+
+    #[test]
+    fn test_validate_duplicate_allocation_names() {
+        // A name GUID shared by allocations of different ranges must be flagged
+        // once, ignoring GUID case and repeated ranges, while the all-zero GUID
+        // and repeats of a single range must not.
+        let guid = "4ed4bf27-4092-42e9-807d-527b1d00c9bd";
+        let hob_list = vec![
+            create_memory_hob(guid.to_string(), 0x100000, 0x1000, 4),
+            create_memory_hob(guid.to_uppercase(), 0x200000, 0x2000, 4),
+            create_memory_hob(guid.to_string(), 0x100000, 0x1000, 4),
+            create_memory_hob(zero_owner(), 0x300000, 0x1000, 4),
+            create_memory_hob(zero_owner(), 0x400000, 0x1000, 4),
+            create_memory_hob("f8e21975-0899-4f58-a4be-5525a9c6d77a".to_string(), 0x500000, 0x1000, 4),
+            create_memory_hob("f8e21975-0899-4f58-a4be-5525a9c6d77a".to_string(), 0x500000, 0x1000, 4),
+        ];
+
+        let report = HobValidator::new(&hob_list).validate_duplicate_allocation_names().unwrap();
+        let violations: Vec<_> = report.iter_violations().map(|(_, violation)| violation).collect();
+        assert_eq!(violations.len(), 1);
+        match violations[0] {
+            ValidationKind::Hob(HobValidationKind::DuplicateAllocationName { name, count, ranges }) => {
+                assert_eq!(*name, guid);
+                assert_eq!(*count, 2);
+                assert_eq!(ranges, &vec![(0x100000, 0x1000), (0x200000, 0x2000)]);
+            }
+            other => panic!("unexpected violation {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_validate_fv_hob_overlap() {
+        // Overlapping Firmware Volume HOBs must be flagged once per pair, while
+        // adjacent and empty ones must not.
+        let fv = |base_address, length| HobSerDe::FirmwareVolume { base_address, length };
+        let hob_list = vec![fv(0x100000, 0x10000), fv(0x110000, 0x10000), fv(0x108000, 0x1000), fv(0x108000, 0)];
+
+        let report = HobValidator::new(&hob_list).validate_fv_hob_overlap().unwrap();
+        let overlaps: Vec<(u64, u64, u64, u64)> = report
+            .iter_violations()
+            .filter_map(|(_, violation)| match violation {
+                ValidationKind::Hob(HobValidationKind::OverlappingFirmwareVolumes { base1, len1, base2, len2 }) => {
+                    Some((*base1, *len1, *base2, *len2))
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(overlaps, vec![(0x100000, 0x10000, 0x108000, 0x1000)]);
+
+        let hob_list = vec![fv(0x100000, 0x10000), fv(0x110000, 0x10000)];
+        assert_eq!(HobValidator::new(&hob_list).validate_fv_hob_overlap().unwrap().violation_count(), 0);
+    }
+
+    #[test]
+    fn test_memory_contiguity() {
+        // Holes in system memory below the required end must be flagged, while
+        // adjacent descriptors, non-system-memory descriptors and memory above
+        // the end must not close or add gaps.
+        let low = create_v1_hob(0x0, 0xA0000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, "owner1");
+        let mmio = create_v1_hob(0xA0000, 0x60000, EFI_RESOURCE_MEMORY_MAPPED_IO, 0x7, "owner1");
+        let mid = create_v1_hob(0x100000, 0x100000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, "owner1");
+        let adjacent = create_v1_hob(0x200000, 0x100000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, "owner1");
+        let high = create_v2_hob(0x400000, 0x100000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, "owner1", efi::MEMORY_WB);
+        let hob_list = vec![high, adjacent, mmio, mid, low];
+
+        let validator = HobValidator::new(&hob_list);
+        let report = validator.validate_memory_contiguity(0x600000).unwrap();
+        let gaps: Vec<(u64, u64)> = report
+            .iter_violations()
+            .filter_map(|(_, violation)| match violation {
+                ValidationKind::Hob(HobValidationKind::MemoryGap { gap_start, gap_end }) => {
+                    Some((*gap_start, *gap_end))
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(gaps, vec![(0xA0000, 0x100000), (0x300000, 0x400000), (0x500000, 0x600000)]);
+
+        assert_eq!(validator.validate_memory_contiguity(0x300000).unwrap().violation_count(), 1);
+        assert_eq!(validator.validate_memory_contiguity(0xA0000).unwrap().violation_count(), 0);
+
+        // Off unless requested
+        let gap_count = |validator: HobValidator| {
+            let report = validator.validate().unwrap();
+            report.iter_violations().filter(|(kind, _)| *kind == "MemoryGap").count()
+        };
+        assert_eq!(gap_count(HobValidator::new(&hob_list)), 0);
+        assert_eq!(gap_count(HobValidator::new(&hob_list).with_require_contiguous(Some(0x600000))), 3);
+    }
+
+    #[test]
+    fn test_v2_presence() {
+        let v1 = create_v1_hob(0x100000, 0x100000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, "owner1");
+        let v2 = create_v2_hob(0x100000, 0x100000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, "owner1", efi::MEMORY_WB);
+
+        let hob_list = vec![v1.clone()];
+        let validator = HobValidator::new(&hob_list);
+        let report = validator.validate_v2_presence().unwrap();
+        assert_eq!(report.violation_count(), 1);
+        assert!(matches!(
+            report.iter_violations().next(),
+            Some((_, ValidationKind::Hob(HobValidationKind::NoV2DescriptorsPresent { v1_count: 1 })))
+        ));
+
+        let hob_list = vec![v1, v2];
+        assert_eq!(HobValidator::new(&hob_list).validate_v2_presence().unwrap().violation_count(), 0);
+        let hob_list = vec![];
+        assert_eq!(HobValidator::new(&hob_list).validate_v2_presence().unwrap().violation_count(), 0);
+
+        // Off unless requested
+        let hob_list = vec![create_v1_hob(0x100000, 0x100000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, "owner1")];
+        let v2_count = |validator: HobValidator| {
+            let report = validator.validate().unwrap();
+            report.iter_violations().filter(|(kind, _)| *kind == "NoV2DescriptorsPresent").count()
+        };
+        assert_eq!(v2_count(HobValidator::new(&hob_list)), 0);
+        assert_eq!(v2_count(HobValidator::new(&hob_list).with_require_v2(true)), 1);
+    }
+
+    #[test]
+    fn test_resource_range_overflow_ok() {
+        // Resource descriptors that stay within the 64-bit address space,
+        // including one ending exactly at `u64::MAX`, must not be flagged.
+        let v1 = create_v1_hob(0x100000, 0x100000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, "owner1");
+        let v2 = create_v2_hob(0xFFFF_FFFF_FFFF_F000, 0xFFF, EFI_RESOURCE_MEMORY_MAPPED_IO, 0x7, "owner1", 0);
+        let hob_list = vec![v1, v2];
+
+        let validator = HobValidator::new(&hob_list);
+        let result = validator.validate_resource_range_overflow();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 0);
+    }
+
+    #[test]
+    fn test_resource_range_overflow_is_flagged() {
+        // Descriptors whose range wraps past `u64::MAX` must be flagged, and the
+        // remaining interval based checks must skip them rather than panic.
+        let v1 = create_v1_hob(0x2000, 0xFFFF_FFFF_FFFF_F000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, &zero_owner());
+        let v2 = create_v2_hob(
+            0x2000,
+            0xFFFF_FFFF_FFFF_F000,
+            EFI_RESOURCE_SYSTEM_MEMORY,
+            0x7,
+            &zero_owner(),
+            efi::MEMORY_WB,
+        );
+        let hob_list = vec![
+            v1,
+            v2,
+            create_handoff_hob(SUPPORTED_HANDOFF_VERSION, HANDOFF_MEMORY, HANDOFF_FREE_MEMORY),
+            mem_type_info_hob(Vec::new()),
+        ];
+
+        let validator = HobValidator::new(&hob_list);
+        let result = validator.validate_resource_range_overflow();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 2);
+
+        let result = validator.validate();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 2);
+    }
+
+    #[test]
+    fn test_zero_length_ranges_ok() {
+        // HOBs describing non-empty ranges must not be flagged.
+        let v1 = create_v1_hob(0x100000, 0x100000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, "owner1");
+        let v2 = create_v2_hob(0x100000, 0x100000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, "owner1", efi::MEMORY_WB);
+        let mem_hob = create_memory_hob("alloc".to_string(), 0x110000, 0x1000, 4);
+        let hob_list = vec![v1, v2, mem_hob];
+
+        let validator = HobValidator::new(&hob_list);
+        let result = validator.validate_zero_length_ranges();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 0);
+    }
+
+    #[test]
+    fn test_zero_length_ranges_are_flagged() {
+        // Zero-length resource descriptors and allocations must be flagged once
+        // each, without secondary overlap or superset violations.
+        let v1 = create_v1_hob(0x100000, 0x100000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, &zero_owner());
+        let v2 = create_v2_hob(0x100000, 0x100000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, &zero_owner(), efi::MEMORY_WB);
+        let empty_v1 = create_v1_hob(0x300000, 0, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, &zero_owner());
+        let empty_v2 = create_v2_hob(0x180000, 0, EFI_RESOURCE_MEMORY_MAPPED_IO, 0x7, &zero_owner(), efi::MEMORY_UC);
+        let empty_alloc = create_memory_hob(zero_owner(), 0x110000, 0, 4);
+        let hob_list = vec![
+            create_handoff_hob(SUPPORTED_HANDOFF_VERSION, HANDOFF_MEMORY, HANDOFF_FREE_MEMORY),
+            mem_type_info_hob(Vec::new()),
+            v1,
+            v2,
+            empty_v1,
+            empty_v2,
+            empty_alloc,
+        ];
+
+        let validator = HobValidator::new(&hob_list);
+        let result = validator.validate_zero_length_ranges();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 3);
+
+        let result = validator.validate();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 3);
+    }
+
+    #[test]
+    fn test_resources_within_cpu_space_ok() {
+        // Descriptors ending at or below the CPU address ceiling must not be
+        // flagged, and IO descriptors are not subject to the memory space limit.
+        let cpu = HobSerDe::Cpu { size_of_memory_space: 36, size_of_io_space: 16 };
+        let v1 = create_v1_hob(0xF_0000_0000, 0x1_0000_0000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, "owner1");
+        let io = create_v1_hob(0x10_0000_0000, 0x1000, EFI_RESOURCE_IO, 0, "owner1");
+        let hob_list = vec![cpu, v1, io];
+
+        let validator = HobValidator::new(&hob_list);
+        let result = validator.validate_resources_within_cpu_space();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 0);
+    }
+
+    #[test]
+    fn test_resources_beyond_cpu_space_are_flagged() {
+        // Descriptors extending past `2^size_of_memory_space` must be flagged.
+        let cpu = HobSerDe::Cpu { size_of_memory_space: 36, size_of_io_space: 16 };
+        let straddling = create_v1_hob(0xF_FFFF_0000, 0x20000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, "owner1");
+        let above = create_v2_hob(0xFF_0000_0000, 0x1000, EFI_RESOURCE_MEMORY_MAPPED_IO, 0x7, "owner1", efi::MEMORY_UC);
+        let hob_list = vec![cpu, straddling, above];
+
+        let validator = HobValidator::new(&hob_list);
+        let result = validator.validate_resources_within_cpu_space();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 2);
+    }
+
+    #[test]
+    fn test_resources_within_cpu_space_without_cpu_hob_is_skipped() {
+        // Without a CPU HOB the check has no ceiling to compare against and must
+        // be skipped.
+        let v1 = create_v1_hob(0xFF_0000_0000, 0x1000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, "owner1");
+        let hob_list = vec![v1];
+
+        let validator = HobValidator::new(&hob_list);
+        let result = validator.validate_resources_within_cpu_space();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 0);
+    }
+
+    #[test]
+    fn test_duplicate_resources_are_flagged() {
+        // Identical descriptors of the same version must be reported as
+        // duplicates, not as overlapping ranges.
+        let v1a = create_v1_hob(0x100000, 0x100000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, "owner1");
+        let v1b = create_v1_hob(0x100000, 0x100000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, "owner1");
+        let v2a = create_v2_hob(0x100000, 0x100000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, "owner1", efi::MEMORY_WB);
+        let v2b = create_v2_hob(0x100000, 0x100000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, "owner1", efi::MEMORY_WB);
+        let hob_list = vec![v1a, v1b, v2a, v2b];
+
+        let validator = HobValidator::new(&hob_list);
+        let result = validator.validate_duplicate_resources();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 2);
+
+        let result = validator.validate_memory_overlap();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 0);
+
+        // Each further copy is paired with the first descriptor of its group
+        let v1c = create_v1_hob(0x100000, 0x100000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, "owner1");
+        let mut hob_list = hob_list;
+        hob_list.push(v1c);
+        let validator = HobValidator::new(&hob_list);
+        let validation_report = validator.validate_duplicate_resources().unwrap();
+        assert_eq!(validation_report.violation_count(), 3);
+    }
+
+    #[test]
+    fn test_duplicate_resources_ok() {
+        // A V1 and V2 pair describing the same range is the expected migration
+        // pattern, and descriptors differing only by owner are overlaps rather
+        // than duplicates.
+        let v1a = create_v1_hob(0x100000, 0x100000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, "owner1");
+        let v1b = create_v1_hob(0x100000, 0x100000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, "owner2");
+        let v2 = create_v2_hob(0x100000, 0x100000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, "owner1", efi::MEMORY_WB);
+        let hob_list = vec![v1a, v1b, v2];
+
+        let validator = HobValidator::new(&hob_list);
+        let result = validator.validate_duplicate_resources();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 0);
+
+        let result = validator.validate_memory_overlap();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 1);
+    }
+
+    #[test]
+    fn test_memory_allocation_type_ok() {
+        // Defined memory types and the OEM/OS reserved ranges must not be flagged.
+        let hob_list = vec![
+            create_memory_hob("reserved".to_string(), 0x100000, 0x1000, efi::RESERVED_MEMORY_TYPE),
+            create_memory_hob("boot_data".to_string(), 0x101000, 0x1000, efi::BOOT_SERVICES_DATA),
+            create_memory_hob("unaccepted".to_string(), 0x102000, 0x1000, efi::UNACCEPTED_MEMORY_TYPE),
+            create_memory_hob("oem".to_string(), 0x103000, 0x1000, 0x70000000),
+            create_memory_hob("os".to_string(), 0x104000, 0x1000, 0xFFFFFFFF),
+        ];
+
+        let validator = HobValidator::new(&hob_list);
+        let result = validator.validate_memory_allocation_type();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 0);
+    }
+
+    #[test]
+    fn test_memory_allocation_type_invalid_is_flagged() {
+        // Memory types between `EfiMaxMemoryType` and the OEM reserved range must
+        // be flagged.
+        let hob_list = vec![
+            create_memory_hob("max".to_string(), 0x100000, 0x1000, EFI_MAX_MEMORY_TYPE as u32),
+            create_memory_hob("below_oem".to_string(), 0x101000, 0x1000, 0x6FFFFFFF),
+        ];
+
+        let validator = HobValidator::new(&hob_list);
+        let result = validator.validate_memory_allocation_type();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 2);
+    }
+
+    #[test]
+    fn test_resource_page_alignment_ok() {
+        // Page-aligned memory descriptors and unaligned IO descriptors must not be
+        // flagged.
+        let v1 = create_v1_hob(0x100000, 0x100000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, "owner1");
+        let v2 = create_v2_hob(0x200000, 0x3000, EFI_RESOURCE_MEMORY_MAPPED_IO, 0x7, "owner1", efi::MEMORY_UC);
+        let io = create_v1_hob(0x60, 0x10, EFI_RESOURCE_IO, 0, "owner1");
+        let io_reserved = create_v2_hob(0xCF8, 0x8, EFI_RESOURCE_IO_RESERVED, 0, "owner1", 0);
+        let hob_list = vec![v1, v2, io, io_reserved];
+
+        let validator = HobValidator::new(&hob_list);
+        let result = validator.validate_resource_page_alignment();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 0);
+    }
+
+    #[test]
+    fn test_resource_page_alignment_unaligned_is_flagged() {
+        // Memory descriptors with an unaligned start or length must be flagged.
+        let unaligned_start = create_v1_hob(0x100800, 0x1000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, "owner1");
+        let unaligned_length =
+            create_v2_hob(0x200000, 0x1800, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, "owner1", efi::MEMORY_WB);
+        let hob_list = vec![unaligned_start, unaligned_length];
+
+        let validator = HobValidator::new(&hob_list);
+        let result = validator.validate_resource_page_alignment();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 2);
+    }
+
+    #[test]
+    fn test_handoff_version_ok() {
+        // Handoff HOBs carrying the supported version must not be flagged.
+        let hob_list = vec![create_handoff_hob(SUPPORTED_HANDOFF_VERSION, HANDOFF_MEMORY, HANDOFF_FREE_MEMORY)];
+
+        let validator = HobValidator::new(&hob_list);
+        let result = validator.validate_handoff_version();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 0);
+    }
+
+    #[test]
+    fn test_handoff_version_legacy_is_warning() {
+        // Handoff HOBs carrying the legacy version must be flagged as a warning.
+        let hob_list = vec![create_handoff_hob(LEGACY_HANDOFF_VERSION, HANDOFF_MEMORY, HANDOFF_FREE_MEMORY)];
+
+        let validator = HobValidator::new(&hob_list);
+        let report = validator.validate_handoff_version().unwrap();
+        let violations: Vec<_> = report.iter_violations().collect();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].0, "LegacyHandoffVersion");
+        assert_eq!(violations[0].1.severity(), Severity::Warning);
+    }
+
+    #[test]
+    fn test_handoff_version_unsupported_is_flagged() {
+        // Handoff HOBs carrying an unknown version must be flagged.
+        let hob_list = vec![create_handoff_hob(0xFFFF_FFFF, HANDOFF_MEMORY, HANDOFF_FREE_MEMORY)];
+
+        let validator = HobValidator::new(&hob_list);
+        let result = validator.validate_handoff_version();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 1);
+    }
+
+    #[test]
+    fn test_resource_attribute_consistency_ok() {
+        // Attribute combinations that satisfy every prerequisite must not be
+        // flagged.
+        let hob_list = vec![
+            create_v1_hob(0x100000, 0x1000, EFI_RESOURCE_SYSTEM_MEMORY, 0, "owner1"),
+            create_v1_hob(0x101000, 0x1000, EFI_RESOURCE_SYSTEM_MEMORY, EFI_RESOURCE_ATTRIBUTE_PRESENT, "owner1"),
+            create_v2_hob(
+                0x102000,
+                0x1000,
+                EFI_RESOURCE_SYSTEM_MEMORY,
+                EFI_RESOURCE_ATTRIBUTE_PRESENT | EFI_RESOURCE_ATTRIBUTE_INITIALIZED | EFI_RESOURCE_ATTRIBUTE_TESTED,
+                "owner1",
+                efi::MEMORY_WB,
+            ),
+        ];
+
+        let validator = HobValidator::new(&hob_list);
+        let result = validator.validate_resource_attribute_consistency();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 0);
+    }
+
+    #[test]
+    fn test_resource_attribute_consistency_is_flagged() {
+        // `INITIALIZED` without `PRESENT` and `TESTED` without `INITIALIZED` must
+        // be flagged.
+        let hob_list = vec![
+            create_v1_hob(0x100000, 0x1000, EFI_RESOURCE_SYSTEM_MEMORY, EFI_RESOURCE_ATTRIBUTE_INITIALIZED, "owner1"),
+            create_v2_hob(
+                0x101000,
+                0x1000,
+                EFI_RESOURCE_SYSTEM_MEMORY,
+                EFI_RESOURCE_ATTRIBUTE_PRESENT | EFI_RESOURCE_ATTRIBUTE_TESTED,
+                "owner1",
+                efi::MEMORY_WB,
+            ),
+        ];
+
+        let validator = HobValidator::new(&hob_list);
+        let result = validator.validate_resource_attribute_consistency();
+        assert!(result.is_ok());
+        let report = result.unwrap();
+        assert_eq!(report.violation_count(), 2);
+
+        // The resolution names each broken dependency
+        let resolutions: Vec<_> = report
+            .iter_violations()
+            .map(|(_, violation)| violation.details()["Violation/Resolution"].clone())
+            .collect();
+        assert_eq!(resolutions[0], "resource_attribute(0x2)\nINITIALIZED(0x2) is set without PRESENT(0x1)");
+        assert_eq!(resolutions[1], "resource_attribute(0x5)\nTESTED(0x4) is set without INITIALIZED(0x2)");
+    }
+
+    #[test]
+    fn test_memory_footprint() {
+        // Overlapping V1 and V2 descriptors must be counted once, and the largest
+        // free region must exclude the allocations.
+        let hob_list = vec![
+            create_v1_hob(0x100000, 0x100000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, "owner1"),
+            create_v2_hob(0x100000, 0x100000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, "owner1", efi::MEMORY_WB),
+            create_v1_hob(0x200000, 0x80000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, "owner1"),
+            create_v1_hob(0x400000, 0x20000, EFI_RESOURCE_MEMORY_RESERVED, 0x7, "owner1"),
+            create_v1_hob(0xFED00000, 0x1000, EFI_RESOURCE_MEMORY_MAPPED_IO, 0x7, "owner1"),
+            create_memory_hob("low".to_string(), 0x100000, 0x10000, 4),
+            create_memory_hob("middle".to_string(), 0x1C0000, 0x60000, 4),
+        ];
+
+        let footprint = HobValidator::new(&hob_list).memory_footprint();
+        assert_eq!(
+            footprint,
+            MemoryFootprint {
+                system_memory: 0x180000,
+                reserved_memory: 0x20000,
+                largest_free_region_start: 0x110000,
+                largest_free_region_length: 0xB0000,
+            }
+        );
+    }
+
+    #[test]
+    fn test_summarize_counts_hob_types() {
+        // The summary must count each HOB variant and must not contribute to the
+        // violation count.
+        let hob_list = vec![
+            create_handoff_hob(SUPPORTED_HANDOFF_VERSION, HANDOFF_MEMORY, HANDOFF_FREE_MEMORY),
+            create_v1_hob(0x100000, 0x100000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, &zero_owner()),
+            create_v2_hob(0x100000, 0x100000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, &zero_owner(), efi::MEMORY_WB),
+            create_v2_hob(0x200000, 0x100000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, &zero_owner(), efi::MEMORY_WB),
+            create_memory_hob(zero_owner(), 0x110000, 0x1000, 4),
+            HobSerDe::Cpu { size_of_memory_space: 36, size_of_io_space: 16 },
+            mem_type_info_hob(Vec::new()),
+            HobSerDe::UnknownHob,
+        ];
+
+        let validator = HobValidator::new(&hob_list);
+        let summary = validator.summarize();
+        assert_eq!(
+            summary,
+            HobSummary {
+                handoff: 1,
+                memory_allocation: 1,
+                resource_descriptor: 1,
+                resource_descriptor_v2: 2,
+                memory_type_information: 1,
+                cpu: 1,
+                unknown: 1,
+                ..Default::default()
+            }
+        );
+
+        let result = validator.validate();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 0);
+    }
+
+    #[test]
+    fn test_allocation_vs_mmio_ok() {
+        // Allocations inside system memory or adjacent to MMIO ranges must not be
+        // flagged.
+        let system = create_v1_hob(0x100000, 0x100000, EFI_RESOURCE_SYSTEM_MEMORY, 0x7, "owner1");
+        let mmio = create_v2_hob(0x200000, 0x10000, EFI_RESOURCE_MEMORY_MAPPED_IO, 0x7, "owner1", efi::MEMORY_UC);
+        let alloc = create_memory_hob("alloc".to_string(), 0x1FF000, 0x1000, 4);
+        let hob_list = vec![system, mmio, alloc];
+
+        let validator = HobValidator::new(&hob_list);
+        let result = validator.validate_allocation_vs_mmio();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 0);
+    }
+
+    #[test]
+    fn test_allocation_vs_mmio_is_flagged() {
+        // Allocations overlapping MMIO or reserved descriptors must be flagged.
+        let mmio = create_v2_hob(0x200000, 0x10000, EFI_RESOURCE_MEMORY_MAPPED_IO, 0x7, "owner1", efi::MEMORY_UC);
+        let reserved = create_v1_hob(0x300000, 0x10000, EFI_RESOURCE_MEMORY_RESERVED, 0x7, "owner1");
+        let in_mmio = create_memory_hob("in_mmio".to_string(), 0x1FF000, 0x2000, 4);
+        let in_reserved = create_memory_hob("in_reserved".to_string(), 0x300000, 0x1000, 0);
+        let hob_list = vec![mmio, reserved, in_mmio, in_reserved];
+
+        let validator = HobValidator::new(&hob_list);
+        let result = validator.validate_allocation_vs_mmio();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 2);
+    }
+
+    #[test]
+    fn test_guid_hob_rules_memory_type_info_guid() {
+        // The required GUID table entry must stay in sync with
+        // `MEMORY_TYPE_INFO_HOB_GUID`.
+        assert!(GUID_HOB_RULES.iter().any(|(guid, rule)| *rule == GuidHobRule::Required
+            && OwnedGuid::try_from_string(guid).is_ok_and(|guid| guid == MEMORY_TYPE_INFO_HOB_GUID)));
+    }
+
+    #[test]
+    fn test_guid_hobs_ok() {
+        // The required Memory Type Information HOB may be captured either as a
+        // raw GUID Extension HOB or as the parsed Memory Type Information HOB.
+        for hob in [
+            HobSerDe::GuidExtension { name: mem_info_owner() },
+            HobSerDe::GuidExtension { name: mem_info_owner().to_lowercase() },
+            mem_type_info_hob(Vec::new()),
+        ] {
+            let hob_list =
+                vec![hob, HobSerDe::GuidExtension { name: "ffffffff-ffff-ffff-ffff-ffffffffffff".to_string() }];
+
+            let validator = HobValidator::new(&hob_list);
+            let result = validator.validate_guid_hobs();
+            assert!(result.is_ok());
+            assert_eq!(result.unwrap().violation_count(), 0);
+        }
+    }
+
+    #[test]
+    fn test_guid_hobs_are_flagged() {
+        // A missing required GUID HOB and each prohibited GUID HOB must be
+        // flagged.
+        let hob_list =
+            vec![HobSerDe::GuidExtension { name: zero_owner() }, HobSerDe::GuidExtension { name: zero_owner() }];
+
+        let validator = HobValidator::new(&hob_list);
+        let result = validator.validate_guid_hobs();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 3);
+    }
+
+    #[test]
+    fn test_memory_type_info_entries_ok() {
+        // Memory Type Information entries with a valid type and a reasonable page
+        // count must not be flagged.
+        let hob_list = vec![mem_type_info_hob(vec![
+            MemoryTypeInfoEntrySerDe { memory_type: efi::RUNTIME_SERVICES_DATA, number_of_pages: 0x100 },
+            MemoryTypeInfoEntrySerDe {
+                memory_type: efi::BOOT_SERVICES_CODE,
+                number_of_pages: MAX_MEMORY_TYPE_INFO_BIN_PAGES,
+            },
+        ])];
+
+        let validator = HobValidator::new(&hob_list);
+        let result = validator.validate_memory_type_info();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 0);
+    }
+
+    #[test]
+    fn test_memory_type_info_raw_payload() {
+        // An out of range type followed by a valid entry, the terminator and
+        // an entry past it. The parsed HOB ends before the out of range type.
+        let payload: Vec<u8> = [
+            (efi::RUNTIME_SERVICES_DATA, 0x100),
+            (EFI_MAX_MEMORY_TYPE as u32 + 1, 0x10),
+            (efi::ACPI_MEMORY_NVS, 0xFFFF_FFFF),
+            (EFI_MAX_MEMORY_TYPE as u32, 0),
+            (EFI_MAX_MEMORY_TYPE as u32 + 2, 0x10),
+        ]
+        .iter()
+        .flat_map(|&(memory_type, number_of_pages): &(u32, u32)| {
+            memory_type.to_le_bytes().into_iter().chain(number_of_pages.to_le_bytes())
+        })
+        .collect();
+        let hob_list = vec![mem_type_info_hob(vec![MemoryTypeInfoEntrySerDe {
+            memory_type: efi::RUNTIME_SERVICES_DATA,
+            number_of_pages: 0x100,
+        }])];
+        let guid_hob_data = [GuidHobDataSerDe { name: MEMORY_TYPE_INFO_HOB_GUID.to_string(), data: payload }];
+
+        let validator = HobValidator::new(&hob_list);
+        assert_eq!(validator.validate_memory_type_info().unwrap().violation_count(), 0);
+
+        let validator = HobValidator::new(&hob_list).with_guid_hob_data(&guid_hob_data);
+        let validation_report = validator.validate_memory_type_info().unwrap();
+        assert_eq!(validation_report.violation_count(), 2);
+        let json = validation_report.to_json().to_string();
+        assert!(json.contains(&format!("memory_type: {}\\n", EFI_MAX_MEMORY_TYPE + 1)), "{}", json);
+    }
+
+    #[test]
+    fn test_memory_type_info_entries_invalid_are_flagged() {
+        // Entries with an out of range type or an oversized page count must be
+        // flagged.
+        let hob_list = vec![mem_type_info_hob(vec![
+            MemoryTypeInfoEntrySerDe { memory_type: EFI_MAX_MEMORY_TYPE as u32, number_of_pages: 0x10 },
+            MemoryTypeInfoEntrySerDe { memory_type: efi::ACPI_MEMORY_NVS, number_of_pages: 0xFFFF_FFFF },
+        ])];
+
+        let validator = HobValidator::new(&hob_list);
+        let result = validator.validate_memory_type_info();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().violation_count(), 2);
+    }
+
+    #[test]
+    fn test_validate_with_filter() {
+        // Without a Handoff HOB the handoff presence check reports a violation
+        let hob_list = vec![mem_type_info_hob(Vec::new())];
+
+        let validator = HobValidator::new(&hob_list);
+        assert_eq!(validator.validate().unwrap().violation_count(), 1);
+
+        let only = KindFilter::new(&["MissingOrDuplicateHandoff".to_string()], &[]).unwrap();
+        let validator = HobValidator::new(&hob_list).with_filter(only);
+        assert_eq!(validator.validate().unwrap().violation_count(), 1);
+
+        let skip = KindFilter::new(&[], &["MissingOrDuplicateHandoff".to_string()]).unwrap();
+        let validator = HobValidator::new(&hob_list).with_filter(skip);
+        assert_eq!(validator.validate().unwrap().violation_count(), 0);
+
+        let only_other = KindFilter::new(&["UsesTraditionalSmm".to_string()], &[]).unwrap();
+        let validator = HobValidator::new(&hob_list).with_filter(only_other);
+        assert_eq!(validator.validate().unwrap().violation_count(), 0);
+    }
+
+    #[test]
+    fn test_validate_allow_empty() {
+        let hob_list = vec![];
+        let validator = HobValidator::new(&hob_list);
+        assert_eq!(validator.validate().unwrap_err(), ValidationAppError::EmptyHobList);
+
+        let validator = HobValidator::new(&hob_list).with_allow_empty(true);
+        let report = validator.validate().unwrap();
+        assert_eq!(report.violation_count(), 0);
+        assert_eq!(report.notes(), [ValidationAppError::EmptyHobList.to_string()]);
+    }
+
+    #[test]
+    fn test_check_hob_overlap_matches_naive() {
+        // Deterministic pseudo-random ranges, dense enough to produce many overlaps
+        let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut next = || {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            seed >> 33
+        };
+        let resources: Vec<ResourceDescriptorSerDe> = (0..4000)
+            .map(|_| ResourceDescriptorSerDe {
+                physical_start: (next() % 0x100_0000) * UEFI_PAGE_SIZE as u64,
+                resource_length: (next() % 0x400 + 1) * UEFI_PAGE_SIZE as u64,
+                resource_type: EFI_RESOURCE_SYSTEM_MEMORY,
+                resource_attribute: 0,
+                owner: zero_owner(),
+            })
+            .collect();
+        let resource_list: Vec<&ResourceDescriptorSerDe> = resources.iter().collect();
+
+        let mut naive = Vec::new();
+        for i in 0..resource_list.len() {
+            for j in (i + 1)..resource_list.len() {
+                if resource_list[i].overlaps(resource_list[j]) {
+                    naive.push((resource_list[i], resource_list[j]));
+                }
+            }
+        }
+
+        let overlaps = HobValidator::check_hob_overlap(&resource_list);
+        assert!(!naive.is_empty());
+        assert_eq!(overlaps.len(), naive.len());
+        assert!(overlaps.iter().zip(&naive).all(|(a, b)| std::ptr::eq(a.0, b.0) && std::ptr::eq(a.1, b.1)));
+    }
+
+    #[test]
+    fn test_check_hob_overlap_between_matches_naive() {
+        let mut seed: u64 = 0x9e37_79b9_7f4a_7c15;
+        let mut next = || {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            seed >> 33
+        };
+        let mut resources = |count| -> Vec<ResourceDescriptorSerDe> {
+            (0..count)
+                .map(|_| ResourceDescriptorSerDe {
+                    physical_start: (next() % 0x10_0000) * UEFI_PAGE_SIZE as u64,
+                    resource_length: (next() % 0x100 + 1) * UEFI_PAGE_SIZE as u64,
+                    resource_type: EFI_RESOURCE_SYSTEM_MEMORY,
+                    resource_attribute: 0,
+                    owner: zero_owner(),
+                })
+                .collect()
+        };
+        let v1_resources = resources(2000);
+        let v2_resources = resources(2000);
+        let list_a: Vec<&ResourceDescriptorSerDe> = v1_resources.iter().collect();
+        let list_b: Vec<&ResourceDescriptorSerDe> = v2_resources.iter().collect();
+
+        let mut naive = Vec::new();
+        for a in &list_a {
+            for b in &list_b {
+                if a.overlaps(b) {
+                    naive.push((*a, *b));
+                }
+            }
+        }
+
+        let overlaps = HobValidator::check_hob_overlap_between(&list_a, &list_b);
+        assert!(!naive.is_empty());
+        assert_eq!(overlaps.len(), naive.len());
+        assert!(overlaps.iter().zip(&naive).all(|(a, b)| std::ptr::eq(a.0, b.0) && std::ptr::eq(a.1, b.1)));
+    }
+
+    #[test]
+    fn test_memory_overlap_clusters() {
+        // Layered reservations over the same range are reported as one cluster,
+        // or as every pair with `verbose_overlaps`. A separate pair stays a pair.
+        let hob_list = vec![
+            create_v1_hob(0x1000, 0x4000, 0, 0, "owner1"),
+            create_v1_hob(0x2000, 0x1000, 0, 0, "owner2"),
+            create_v1_hob(0x4000, 0x2000, 0, 0, "owner3"),
+            create_v1_hob(0x10000, 0x2000, 0, 0, "owner1"),
+            create_v1_hob(0x11000, 0x2000, 0, 0, "owner2"),
+        ];
+
+        let validator = HobValidator::new(&hob_list);
+        let validation_report = validator.validate_memory_overlap().unwrap();
+        assert_eq!(validation_report.violation_count(), 2);
+        let groups: Vec<&str> = validation_report.groups().collect();
+        assert_eq!(groups, ["OverlappingMemoryCluster", "OverlappingMemoryRanges"]);
+        let report = validation_report.to_json().to_string();
+        assert!(report.contains("3 Hobs describe overlapping ranges"), "{}", report);
+        assert!(report.contains("Combined range(4096, 24576)"), "{}", report);
+
+        let validator = HobValidator::new(&hob_list).with_verbose_overlaps(true);
+        let validation_report = validator.validate_memory_overlap().unwrap();
+        assert_eq!(validation_report.violation_count(), 3);
+        assert!(validation_report.groups().eq(["OverlappingMemoryRanges"]));
+    }
+
+    #[test]
+    fn test_cluster_overlaps() {
+        let resources: Vec<ResourceDescriptorSerDe> = [(0x5000, 0x1000), (0x1000, 0x1000), (0x3000, 0x1000)]
+            .into_iter()
+            .map(|(physical_start, resource_length)| ResourceDescriptorSerDe {
+                owner: zero_owner(),
+                resource_type: EFI_RESOURCE_SYSTEM_MEMORY,
+                resource_attribute: 0,
+                physical_start,
+                resource_length,
+            })
+            .collect();
+        let [a, b, c] = [&resources[0], &resources[1], &resources[2]];
+
+        // Chained pairs are a single cluster sorted by range
+        let clusters = HobValidator::cluster_overlaps(&[(a, b), (c, b)]);
+        assert_eq!(clusters.len(), 1);
+        assert!(clusters[0].iter().zip([b, c, a]).all(|(x, y)| std::ptr::eq(*x, y)));
+
+        assert!(HobValidator::cluster_overlaps::<ResourceDescriptorSerDe>(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_overlapping_memory_ranges_row_shows_both_ranges() {
+        let hob_list =
+            vec![create_v1_hob(0x1000, 0x2000, 0, 0, "owner1"), create_v1_hob(0x2000, 0x2000, 0, 0, "owner1")];
+
+        let validator = HobValidator::new(&hob_list);
+        let validation_report = validator.validate_memory_overlap().unwrap();
+        let report = validation_report.to_json().to_string();
+        assert!(report.contains("Hob 1 range(4096, 12288) | Hob 2 range(8192, 16384)"), "{}", report);
+    }
 }
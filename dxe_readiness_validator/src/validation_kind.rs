@@ -6,11 +6,23 @@
 //!
 //! SPDX-License-Identifier: Apache-2.0
 //!
-use patina::pi::serializable::{
-    Interval,
-    serializable_fv::{FirmwareFileSerDe, FirmwareSectionSerDe, FirmwareVolumeSerDe},
-    serializable_hob::{MemAllocDescriptorSerDe, ResourceDescriptorSerDe},
+use crate::{ValidationAppError, guid_names::format_guid_named, validate::RESOURCE_ATTRIBUTE_DEPENDENCIES};
+use clap::ValueEnum;
+use dxe_readiness_capture::fnv1a_64;
+use goblin::pe::header::{COFF_MACHINE_ARM64, COFF_MACHINE_X86_64};
+use patina::{
+    base::UEFI_PAGE_SIZE,
+    efi_types::EFI_MAX_MEMORY_TYPE,
+    pi::serializable::{
+        Interval,
+        serializable_fv::{FirmwareFileSerDe, FirmwareSectionSerDe, FirmwareVolumeSerDe},
+        serializable_hob::{MemAllocDescriptorSerDe, MemoryTypeInfoEntrySerDe, ResourceDescriptorSerDe},
+    },
 };
+use r_efi::efi;
+use serde::Serialize;
+use serde_json::{Map, Value, json};
+use std::{collections::BTreeSet, fmt};
 
 #[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub enum HobValidationKind<'a> {
@@ -20,6 +32,9 @@ pub enum HobValidationKind<'a> {
     // HOBs must not define overlapping memory ranges
     OverlappingMemoryRanges { hob1: &'a ResourceDescriptorSerDe, hob2: &'a ResourceDescriptorSerDe },
 
+    // HOBs must not define overlapping memory ranges, three or more mutually overlapping HOBs
+    OverlappingMemoryCluster { hobs: Vec<&'a ResourceDescriptorSerDe> },
+
     // Page zero must not be described in memory HOBs
     PageZeroMemoryDescribed { alloc_desc: &'a MemAllocDescriptorSerDe },
 
@@ -32,7 +47,7 @@ pub enum HobValidationKind<'a> {
     // V2 resource descriptor must have at most one valid Cacheability attribute set
     V2MissingValidCacheabilityAttribute { hob1: &'a ResourceDescriptorSerDe, attributes: u64 },
 
-    // V2 resource descriptor for io must have no cacheability or memory protection attributes set
+    // V2 resource descriptor for io must have no cacheability attributes set
     V2InvalidIoCacheabilityAttributes { hob1: &'a ResourceDescriptorSerDe, attributes: u64 },
 
     // More than one Resource Descriptor HOB owned by gEfiMemoryTypeInformationGuid is present
@@ -40,6 +55,95 @@ pub enum HobValidationKind<'a> {
 
     // Memory Type Info Resource Descriptor HOB ResourceLength is smaller than the sum of bin sizes
     MemoryTypeInfoResourceLengthTooSmall { hob1: &'a ResourceDescriptorSerDe, required_bytes: u64, actual_bytes: u64 },
+
+    // Exactly one Handoff (PHIT) HOB must be present
+    MissingOrDuplicateHandoff { count: usize },
+
+    // Handoff HOB free memory range must lie within the Handoff HOB memory range
+    InconsistentHandoffBounds { memory_bottom: u64, memory_top: u64, free_memory_bottom: u64, free_memory_top: u64 },
+
+    // Memory allocation HOBs must not describe overlapping memory ranges
+    OverlappingMemoryAllocations { alloc_desc1: &'a MemAllocDescriptorSerDe, alloc_desc2: &'a MemAllocDescriptorSerDe },
+
+    // Memory allocation HOBs must be covered by system memory resource descriptor HOBs
+    AllocationOutsideDeclaredMemory { alloc_desc: &'a MemAllocDescriptorSerDe },
+
+    // Resource descriptor HOB range must not wrap past the end of the 64-bit address space
+    ResourceRangeOverflow { hob1: &'a ResourceDescriptorSerDe },
+
+    // Resource descriptor and memory allocation HOBs must not describe an empty range
+    ZeroLengthRange { range: RangeDescriptor<'a> },
+
+    // Memory resource descriptor HOBs must lie within the address space advertised by the CPU HOB
+    ResourceBeyondCpuAddressSpace { hob1: &'a ResourceDescriptorSerDe, size_of_memory_space: u8 },
+
+    // HOBs must not contain identical resource descriptors
+    DuplicateResourceDescriptor { hob1: &'a ResourceDescriptorSerDe, hob2: &'a ResourceDescriptorSerDe },
+
+    // Memory allocation HOBs must use a defined or reserved EFI_MEMORY_TYPE
+    InvalidMemoryType { alloc_desc: &'a MemAllocDescriptorSerDe },
+
+    // Non-IO resource descriptor HOBs must be page-aligned
+    UnalignedResourceDescriptor { hob1: &'a ResourceDescriptorSerDe },
+
+    // V2 resource descriptor for io must have no memory protection attributes set
+    IoResourceHasProtectionAttribute { hob1: &'a ResourceDescriptorSerDe, attributes: u64 },
+
+    // Handoff HOB must carry a supported version
+    UnsupportedHandoffVersion { version: u32 },
+
+    // Handoff HOB carries the EFI_HOB_HANDOFF_TABLE_VERSION of older PEI cores
+    LegacyHandoffVersion { version: u32 },
+
+    // Resource descriptor attribute bits must have their prerequisite bits set
+    InconsistentResourceAttributeBits { hob1: &'a ResourceDescriptorSerDe, resource_attribute: u32 },
+
+    // Memory allocation HOBs must not overlap MMIO or reserved resource descriptor HOBs
+    AllocationInMmioRange { alloc_desc: &'a MemAllocDescriptorSerDe, hob1: &'a ResourceDescriptorSerDe },
+
+    // GUID Extension HOBs prohibited by Patina must not be present
+    ProhibitedGuidHob { name: &'a str },
+
+    // GUID Extension HOBs required by Patina must be present
+    MissingRequiredGuidHob { name: &'a str },
+
+    // Memory Type Information entries must use a valid memory type and a reasonable page count
+    InvalidMemoryTypeInfoEntry { entry: MemoryTypeInfoEntrySerDe },
+
+    // V2 HOB attributes must only use bits defined by the EFI_MEMORY_* attributes
+    V2ReservedAttributeBitsSet { hob1: &'a ResourceDescriptorSerDe, attributes: u64 },
+
+    // Memory allocation HOBs of different ranges must not share a non-null name GUID
+    DuplicateAllocationName { name: &'a str, count: usize, ranges: Vec<(u64, u64)> },
+
+    // Firmware Volume HOBs must not describe overlapping ranges
+    OverlappingFirmwareVolumes { base1: u64, len1: u64, base2: u64, len2: u64 },
+
+    // System memory must cover every address below the end required by --require-contiguous
+    MemoryGap { gap_start: u64, gap_end: u64 },
+
+    // GUID-typed HOB fields must hold a GUID in the canonical 8-4-4-4-12 format
+    MalformedGuid { field: &'static str, guid: &'a str },
+
+    // HOBs the capture could not classify are reported with --flag-unknown-hobs
+    UnknownHobPresent { count: usize, hob_types: Vec<u16> },
+
+    // IO resource descriptor HOBs must lie within the 16-bit IO port space
+    IoPortRangeOutOfBounds { hob1: &'a ResourceDescriptorSerDe },
+
+    // IO resource descriptor HOBs must not define overlapping port ranges
+    OverlappingIoPortRanges { hob1: &'a ResourceDescriptorSerDe, hob2: &'a ResourceDescriptorSerDe },
+
+    // Captures with V1 resource descriptors must also carry V2 ones with --require-v2
+    NoV2DescriptorsPresent { v1_count: usize },
+}
+
+/// A HOB describing a physical address range, either a resource descriptor or
+/// a memory allocation.
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum RangeDescriptor<'a> {
+    Resource(&'a ResourceDescriptorSerDe),
+    Allocation(&'a MemAllocDescriptorSerDe),
 }
 
 #[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
@@ -63,7 +167,7 @@ pub enum FvValidationKind<'a> {
         file: &'a FirmwareFileSerDe,
     },
 
-    // FV must not contain traditional SMM drivers
+    // FV must not contain combined traditional SMM drivers
     UsesTraditionalSmm {
         fv: &'a FirmwareVolumeSerDe,
         file: &'a FirmwareFileSerDe,
@@ -76,12 +180,244 @@ pub enum FvValidationKind<'a> {
         section: &'a FirmwareSectionSerDe,
         required_alignment: usize,
     },
+
+    // DXE drivers must declare their dispatch dependencies with a depex
+    DriverMissingDepex {
+        fv: &'a FirmwareVolumeSerDe,
+        file: &'a FirmwareFileSerDe,
+    },
+
+    // PE images must target a 64-bit machine supported by Patina
+    UnsupportedPeMachine {
+        fv: &'a FirmwareVolumeSerDe,
+        file: &'a FirmwareFileSerDe,
+        section: &'a FirmwareSectionSerDe,
+        machine: u16,
+    },
+
+    // Firmware files must have a non-zero length that covers all of their sections
+    InconsistentFileLength {
+        fv: &'a FirmwareVolumeSerDe,
+        file: &'a FirmwareFileSerDe,
+    },
+
+    // Firmware file GUIDs must be unique across all firmware volumes
+    DuplicateFileGuid {
+        fv: &'a FirmwareVolumeSerDe,
+        file: &'a FirmwareFileSerDe,
+        other_fvs: Vec<&'a str>,
+    },
+
+    // FV sections must only use the compression types allowed by the platform
+    DisallowedCompression {
+        fv: &'a FirmwareVolumeSerDe,
+        file: &'a FirmwareFileSerDe,
+        section: &'a FirmwareSectionSerDe,
+        compression_type: &'a str,
+    },
+
+    // GUID defined sections should use a section processor known to Patina
+    UnknownGuidedSection {
+        fv: &'a FirmwareVolumeSerDe,
+        file: &'a FirmwareFileSerDe,
+        section: &'a FirmwareSectionSerDe,
+    },
+
+    // Firmware file names must be GUIDs in the canonical 8-4-4-4-12 format
+    MalformedFileGuid {
+        fv: &'a FirmwareVolumeSerDe,
+        file: &'a FirmwareFileSerDe,
+    },
+
+    // MM drivers and MM cores, if present, must be Standalone MM modules
+    NonStandaloneMmPresent {
+        fv: &'a FirmwareVolumeSerDe,
+        file: &'a FirmwareFileSerDe,
+    },
+
+    // Image sections must come before the sections that terminate a file
+    SuspiciousSectionOrdering {
+        fv: &'a FirmwareVolumeSerDe,
+        file: &'a FirmwareFileSerDe,
+    },
+
+    // Driver images must keep their relocations, as the DXE core loads them at any base
+    RelocationsStripped {
+        fv: &'a FirmwareVolumeSerDe,
+        file: &'a FirmwareFileSerDe,
+        section: &'a FirmwareSectionSerDe,
+    },
+
+    // Profiles forbidding MM must not contain any MM module
+    MmPresent {
+        fv: &'a FirmwareVolumeSerDe,
+        file: &'a FirmwareFileSerDe,
+    },
+}
+
+/// Violations found by correlating the HOB list with the FV list of a capture.
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum CrossValidationKind<'a> {
+    // FVs must match the base address and length of the FV HOB that referenced them
+    FvSizeMismatch { fv: &'a FirmwareVolumeSerDe, hob_base_address: u64, hob_length: u64 },
+
+    // Every FV HOB should point at a firmware volume the capture could parse
+    UnparsedFirmwareVolume { base_address: u64, length: u64 },
+}
+
+/// How blocking a violation is. Ordered from least to most severe.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// Worth a look, but not expected to break the DXE core
+    #[default]
+    Info,
+    /// Should be fixed, but the DXE core can usually cope with it
+    Warning,
+    /// Violates a Patina requirement
+    Error,
+}
+
+impl Severity {
+    /// Returns the SARIF `level` matching the severity.
+    pub fn sarif_level(&self) -> &'static str {
+        match self {
+            Severity::Info => "note",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        }
+    }
+
+    /// Returns the penalty points a violation of this severity costs in the
+    /// readiness score.
+    pub fn score_weight(&self) -> u64 {
+        match self {
+            Severity::Info => 1,
+            Severity::Warning => 3,
+            Severity::Error => 10,
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Info => write!(f, "Info"),
+            Severity::Warning => write!(f, "Warning"),
+            Severity::Error => write!(f, "Error"),
+        }
+    }
+}
+
+/// Every `EFI_MEMORY_*` attribute bit defined by UEFI. Other bits in the
+/// attributes of a V2 resource descriptor are reserved.
+pub const KNOWN_V2_ATTRIBUTES_MASK: u64 = efi::MEMORY_UC
+    | efi::MEMORY_WC
+    | efi::MEMORY_WT
+    | efi::MEMORY_WB
+    | efi::MEMORY_UCE
+    | efi::MEMORY_WP
+    | efi::MEMORY_RP
+    | efi::MEMORY_XP
+    | efi::MEMORY_NV
+    | efi::MEMORY_MORE_RELIABLE
+    | efi::MEMORY_RO
+    | efi::MEMORY_SP
+    | efi::MEMORY_CPU_CRYPTO
+    | efi::MEMORY_ISA_MASK
+    | efi::MEMORY_ISA_VALID
+    | efi::MEMORY_RUNTIME;
+
+/// Every name returned by `ValidationKind::name()`.
+pub const VALIDATION_KIND_NAMES: &[&str] = &[
+    "InconsistentMemoryAttributes",
+    "OverlappingMemoryRanges",
+    "OverlappingMemoryCluster",
+    "PageZeroMemoryDescribed",
+    "V1MemoryRangeNotContainedInV2",
+    "V2ContainsUceAttribute",
+    "V2MissingValidCacheabilityAttribute",
+    "V2InvalidIoCacheabilityAttributes",
+    "MemoryTypeInfoMultipleResourceHobs",
+    "MemoryTypeInfoResourceLengthTooSmall",
+    "MissingOrDuplicateHandoff",
+    "InconsistentHandoffBounds",
+    "OverlappingMemoryAllocations",
+    "AllocationOutsideDeclaredMemory",
+    "ResourceRangeOverflow",
+    "ZeroLengthRange",
+    "ResourceBeyondCpuAddressSpace",
+    "DuplicateResourceDescriptor",
+    "InvalidMemoryType",
+    "UnalignedResourceDescriptor",
+    "IoResourceHasProtectionAttribute",
+    "UnsupportedHandoffVersion",
+    "InconsistentResourceAttributeBits",
+    "AllocationInMmioRange",
+    "ProhibitedGuidHob",
+    "MissingRequiredGuidHob",
+    "InvalidMemoryTypeInfoEntry",
+    "V2ReservedAttributeBitsSet",
+    "DuplicateAllocationName",
+    "OverlappingFirmwareVolumes",
+    "MemoryGap",
+    "MalformedGuid",
+    "UnknownHobPresent",
+    "IoPortRangeOutOfBounds",
+    "OverlappingIoPortRanges",
+    "NoV2DescriptorsPresent",
+    "LegacyHandoffVersion",
+    "CombinedDriversPresent",
+    "LzmaCompressedSections",
+    "ProhibitedAprioriFile",
+    "UsesTraditionalSmm",
+    "InvalidSectionAlignment",
+    "DriverMissingDepex",
+    "UnsupportedPeMachine",
+    "InconsistentFileLength",
+    "DuplicateFileGuid",
+    "DisallowedCompression",
+    "UnknownGuidedSection",
+    "MalformedFileGuid",
+    "NonStandaloneMmPresent",
+    "SuspiciousSectionOrdering",
+    "RelocationsStripped",
+    "MmPresent",
+    "FvSizeMismatch",
+    "UnparsedFirmwareVolume",
+];
+
+/// Selects which validation kinds may report violations, by
+/// `ValidationKind::name()`. An empty `only` set allows every kind.
+#[derive(Clone, Debug, Default)]
+pub struct KindFilter {
+    only: BTreeSet<String>,
+    skip: BTreeSet<String>,
+}
+
+impl KindFilter {
+    pub fn new(only: &[String], skip: &[String]) -> Result<Self, ValidationAppError> {
+        if let Some(unknown) = only.iter().chain(skip).find(|name| !VALIDATION_KIND_NAMES.contains(&name.as_str())) {
+            return Err(ValidationAppError::InvalidCommandLine(format!(
+                "unknown validation kind '{}', valid kinds are: {}",
+                unknown,
+                VALIDATION_KIND_NAMES.join(", ")
+            )));
+        }
+
+        Ok(Self { only: only.iter().cloned().collect(), skip: skip.iter().cloned().collect() })
+    }
+
+    pub fn allows(&self, name: &str) -> bool {
+        (self.only.is_empty() || self.only.contains(name)) && !self.skip.contains(name)
+    }
 }
 
 #[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub enum ValidationKind<'a> {
     Hob(HobValidationKind<'a>),
     Fv(FvValidationKind<'a>),
+    Cross(CrossValidationKind<'a>),
 }
 
 impl ValidationKind<'_> {
@@ -90,6 +426,7 @@ impl ValidationKind<'_> {
             ValidationKind::Hob(hob) => match hob {
                 HobValidationKind::InconsistentMemoryAttributes { .. } => "HOB: Inconsistent Memory Attributes",
                 HobValidationKind::OverlappingMemoryRanges { .. } => "HOB: Overlapping Memory Ranges",
+                HobValidationKind::OverlappingMemoryCluster { .. } => "HOB: Overlapping Memory Cluster",
                 HobValidationKind::PageZeroMemoryDescribed { .. } => "HOB: Page Zero Memory Described",
                 HobValidationKind::V1MemoryRangeNotContainedInV2 { .. } => "HOB: V1 Memory Range Not Contained in V2",
                 HobValidationKind::V2ContainsUceAttribute { .. } => "HOB: V2 Range Contains UCE Attribute",
@@ -105,6 +442,41 @@ impl ValidationKind<'_> {
                 HobValidationKind::MemoryTypeInfoResourceLengthTooSmall { .. } => {
                     "HOB: Memory Type Info Resource Descriptor HOB Length Too Small"
                 }
+                HobValidationKind::MissingOrDuplicateHandoff { .. } => "HOB: Missing or Duplicate Handoff HOB",
+                HobValidationKind::InconsistentHandoffBounds { .. } => "HOB: Inconsistent Handoff HOB Memory Bounds",
+                HobValidationKind::OverlappingMemoryAllocations { .. } => "HOB: Overlapping Memory Allocations",
+                HobValidationKind::AllocationOutsideDeclaredMemory { .. } => {
+                    "HOB: Memory Allocation Outside Declared System Memory"
+                }
+                HobValidationKind::ResourceRangeOverflow { .. } => "HOB: Resource Descriptor Range Overflow",
+                HobValidationKind::ZeroLengthRange { .. } => "HOB: Zero Length Range",
+                HobValidationKind::ResourceBeyondCpuAddressSpace { .. } => {
+                    "HOB: Resource Descriptor Beyond CPU Address Space"
+                }
+                HobValidationKind::DuplicateResourceDescriptor { .. } => "HOB: Duplicate Resource Descriptors",
+                HobValidationKind::InvalidMemoryType { .. } => "HOB: Invalid Memory Allocation Type",
+                HobValidationKind::UnalignedResourceDescriptor { .. } => "HOB: Unaligned Resource Descriptor",
+                HobValidationKind::IoResourceHasProtectionAttribute { .. } => {
+                    "HOB: V2 IO Resource Has Memory Protection Attributes"
+                }
+                HobValidationKind::UnsupportedHandoffVersion { .. } => "HOB: Unsupported Handoff HOB Version",
+                HobValidationKind::LegacyHandoffVersion { .. } => "HOB: Legacy Handoff HOB Version",
+                HobValidationKind::InconsistentResourceAttributeBits { .. } => {
+                    "HOB: Inconsistent Resource Attribute Bits"
+                }
+                HobValidationKind::AllocationInMmioRange { .. } => "HOB: Memory Allocation In MMIO Or Reserved Range",
+                HobValidationKind::ProhibitedGuidHob { .. } => "HOB: Prohibited GUID HOB Present",
+                HobValidationKind::MissingRequiredGuidHob { .. } => "HOB: Required GUID HOB Missing",
+                HobValidationKind::InvalidMemoryTypeInfoEntry { .. } => "HOB: Invalid Memory Type Information Entry",
+                HobValidationKind::V2ReservedAttributeBitsSet { .. } => "HOB: V2 Reserved Attribute Bits Set",
+                HobValidationKind::DuplicateAllocationName { .. } => "HOB: Allocation Name Shared By Different Ranges",
+                HobValidationKind::OverlappingFirmwareVolumes { .. } => "HOB: Overlapping Firmware Volume HOBs",
+                HobValidationKind::MemoryGap { .. } => "HOB: Memory Gap Below Required End",
+                HobValidationKind::MalformedGuid { .. } => "HOB: Malformed GUID",
+                HobValidationKind::UnknownHobPresent { .. } => "HOB: Unrecognized HOB Types",
+                HobValidationKind::IoPortRangeOutOfBounds { .. } => "HOB: IO Port Range Out of Bounds",
+                HobValidationKind::OverlappingIoPortRanges { .. } => "HOB: Overlapping IO Port Ranges",
+                HobValidationKind::NoV2DescriptorsPresent { .. } => "HOB: No V2 Resource Descriptors",
             },
             ValidationKind::Fv(fv) => match fv {
                 FvValidationKind::CombinedDriversPresent { .. } => "FV: Combined Drivers Present",
@@ -112,6 +484,21 @@ impl ValidationKind<'_> {
                 FvValidationKind::ProhibitedAprioriFile { .. } => "FV: Prohibited Apriori File Present",
                 FvValidationKind::UsesTraditionalSmm { .. } => "FV: Uses Traditional SMM Driver",
                 FvValidationKind::InvalidSectionAlignment { .. } => "FV: PE Image Invalid Section Alignment",
+                FvValidationKind::DriverMissingDepex { .. } => "FV: DXE Driver Missing Depex",
+                FvValidationKind::UnsupportedPeMachine { .. } => "FV: PE Image Unsupported Machine Type",
+                FvValidationKind::InconsistentFileLength { .. } => "FV: Inconsistent Firmware File Length",
+                FvValidationKind::DuplicateFileGuid { .. } => "FV: Duplicate Firmware File GUID",
+                FvValidationKind::DisallowedCompression { .. } => "FV: Disallowed Compression Type",
+                FvValidationKind::UnknownGuidedSection { .. } => "FV: Unknown GUID Defined Section",
+                FvValidationKind::MalformedFileGuid { .. } => "FV: Malformed Firmware File GUID",
+                FvValidationKind::NonStandaloneMmPresent { .. } => "FV: Non-Standalone MM Module",
+                FvValidationKind::SuspiciousSectionOrdering { .. } => "FV: Suspicious Section Ordering",
+                FvValidationKind::RelocationsStripped { .. } => "FV: PE Image Relocations Stripped",
+                FvValidationKind::MmPresent { .. } => "FV: MM Module Present",
+            },
+            ValidationKind::Cross(cross) => match cross {
+                CrossValidationKind::FvSizeMismatch { .. } => "HOB/FV: Firmware Volume Does Not Match Its HOB",
+                CrossValidationKind::UnparsedFirmwareVolume { .. } => "HOB/FV: Firmware Volume HOB Not Captured",
             },
         }
     }
@@ -124,6 +511,10 @@ impl ValidationKind<'_> {
                 HobValidationKind::OverlappingMemoryRanges { .. } => "   Platforms must produce non-overlapping HOBs by splitting up overlapping HOBs\n   \
                                                                          into multiple HOBs and eliminating duplicates.\n   \
                                                                          Ref: https://opendevicepartnership.github.io/patina/integrate/patina_dxe_core_requirements_checklist.html",
+                HobValidationKind::OverlappingMemoryCluster { .. } => "   Platforms must produce non-overlapping HOBs by splitting up overlapping HOBs\n   \
+                                                                          into multiple HOBs and eliminating duplicates. Use --verbose-overlaps to list\n   \
+                                                                          every overlapping pair within the cluster.\n   \
+                                                                          Ref: https://opendevicepartnership.github.io/patina/integrate/patina_dxe_core_requirements_checklist.html",
                 HobValidationKind::PageZeroMemoryDescribed { .. } => "   Platforms must not allocate page 0.\n   \
                                                                          Ref: https://opendevicepartnership.github.io/patina/integrate/patina_dxe_core_requirements_checklist.html",
                 HobValidationKind::V1MemoryRangeNotContainedInV2 { .. } => "   All V1 HOB ranges should be described/covered by corresponding V2 HOBs.",
@@ -133,13 +524,89 @@ impl ValidationKind<'_> {
                                                                                      fields with the cacheability attribute set as the only additional field in the\n   \
                                                                                      v2 HOB.\n   \
                                                                                      Ref: https://opendevicepartnership.github.io/patina/integrate/patina_dxe_core_requirements_checklist.html",
-                HobValidationKind::V2InvalidIoCacheabilityAttributes { .. } => "   Platforms must produce Resource Descriptor HOB v2s with no cacheability or memory protection\n   \
-                                                                                   attributes set for IO resource types.",
+                HobValidationKind::V2InvalidIoCacheabilityAttributes { .. } => "   Platforms must produce Resource Descriptor HOB v2s with no cacheability attributes\n   \
+                                                                                   set for IO resource types.",
                 HobValidationKind::MemoryTypeInfoMultipleResourceHobs { .. } => "   Only one Resource Descriptor HOB owned by the Memory Type Information GUID is allowed. ",
                 HobValidationKind::MemoryTypeInfoResourceLengthTooSmall { .. } => "   The Memory Type Info Resource Descriptor HOB's ResourceLength must be large enough\n   \
                                                                                     to hold the sum of bin sizes reported in the Memory Type Information GUID HOB.\n   \
                                                                                     Note: the check uses the raw page-count sum. Platforms may need additional space\n   \
                                                                                     for per-bin alignment padding.",
+                HobValidationKind::MissingOrDuplicateHandoff { .. } => "   The HOB list must start with exactly one Phase Handoff Information Table (PHIT) HOB.\n   \
+                                                                           A missing or repeated Handoff HOB indicates a corrupted HOB list produced by PEI.",
+                HobValidationKind::InconsistentHandoffBounds { .. } => "   The free memory range described by the Handoff HOB must lie within its memory range, i.e.\n   \
+                                                                           memory_bottom <= free_memory_bottom <= free_memory_top <= memory_top.\n   \
+                                                                           Platforms must correct the PHIT HOB values produced by PEI.",
+                HobValidationKind::OverlappingMemoryAllocations { .. } => "   Platforms must not produce Memory Allocation HOBs that describe the same physical memory.\n   \
+                                                                              Each allocation must be owned by exactly one producer.",
+                HobValidationKind::AllocationOutsideDeclaredMemory { .. } => "   Every Memory Allocation HOB must describe memory that is covered by system memory\n   \
+                                                                                 (EFI_RESOURCE_SYSTEM_MEMORY) Resource Descriptor HOBs. Platforms must declare\n   \
+                                                                                 the backing system memory or drop the stray allocation.",
+                HobValidationKind::ResourceRangeOverflow { .. } => "   Resource Descriptor HOBs must describe a range that fits in the 64-bit address space.\n   \
+                                                                       A PhysicalStart + ResourceLength that wraps past 0xFFFFFFFFFFFFFFFF indicates\n   \
+                                                                       a corrupted or uninitialized descriptor produced by PEI.",
+                HobValidationKind::ZeroLengthRange { .. } => "   Resource Descriptor and Memory Allocation HOBs must describe a non-empty range.\n   \
+                                                                 Platforms should drop the empty HOB or correct its length.",
+                HobValidationKind::ResourceBeyondCpuAddressSpace { .. } => "   Resource Descriptor HOBs must not describe memory above the address width advertised\n   \
+                                                                               by the CPU HOB SizeOfMemorySpace field. Platforms must either report the correct\n   \
+                                                                               address width or drop descriptors the CPU cannot address.",
+                HobValidationKind::DuplicateResourceDescriptor { .. } => "   Platforms must not produce identical Resource Descriptor HOBs. The duplicate can simply\n   \
+                                                                             be deleted.\n   \
+                                                                             Ref: https://opendevicepartnership.github.io/patina/integrate/patina_dxe_core_requirements_checklist.html",
+                HobValidationKind::InvalidMemoryType { .. } => "   Memory Allocation HOBs must use a memory type defined by EFI_MEMORY_TYPE or one from\n   \
+                                                                   the OEM (0x70000000-0x7FFFFFFF) or OS (0x80000000-0xFFFFFFFF) reserved ranges.",
+                HobValidationKind::UnalignedResourceDescriptor { .. } => "   Platforms must produce memory Resource Descriptor HOBs whose PhysicalStart and\n   \
+                                                                             ResourceLength are multiples of the UEFI page size (0x1000). Unaligned ranges\n   \
+                                                                             cannot be mapped with page granular memory protections.",
+                HobValidationKind::IoResourceHasProtectionAttribute { .. } => "   IO ranges are not memory mapped, so access protections (MEMORY_RO, MEMORY_XP,\n   \
+                                                                                  MEMORY_RP) have no meaning for them. Platforms must produce Resource\n   \
+                                                                                  Descriptor HOB v2s for IO resource types without memory protection attributes.",
+                HobValidationKind::UnsupportedHandoffVersion { .. } => "   The Handoff (PHIT) HOB Version must be 0x00010000. Any value other than 0x00010000 or\n   \
+                                                                           the legacy 0x0009 indicates a corrupted PHIT HOB or an incompatible PEI core.",
+                HobValidationKind::LegacyHandoffVersion { .. } => "   The Handoff (PHIT) HOB Version is EFI_HOB_HANDOFF_TABLE_VERSION (0x0009), as produced\n   \
+                                                                      by older PEI cores. The DXE core accepts it, but the PEI core should be updated\n   \
+                                                                      to produce the 0x00010000 version.",
+                HobValidationKind::InconsistentResourceAttributeBits { .. } => "   Resource Descriptor HOB ResourceAttribute bits must be self consistent. Memory can only be\n   \
+                                                                                   INITIALIZED when it is PRESENT, and only TESTED when it is INITIALIZED.",
+                HobValidationKind::AllocationInMmioRange { .. } => "   Memory Allocation HOBs must not describe memory inside MMIO or reserved Resource Descriptor\n   \
+                                                                       HOBs. Use the allocation memory_type to tell a legitimate reservation (e.g.\n   \
+                                                                       EfiMemoryMappedIO or EfiReservedMemoryType) apart from accidental heap placement.",
+                HobValidationKind::ProhibitedGuidHob { .. } => "   Platforms must not produce GUID Extension HOBs that the Patina DXE Core prohibits.\n   \
+                                                                   Remove the producer of the HOB or replace it with the supported mechanism.",
+                HobValidationKind::MissingRequiredGuidHob { .. } => "   Platforms must produce the GUID Extension HOBs required by the Patina DXE Core.\n   \
+                                                                        Ref: https://opendevicepartnership.github.io/patina/integrate/patina_dxe_core_requirements_checklist.html",
+                HobValidationKind::InvalidMemoryTypeInfoEntry { .. } => "   Each EFI_MEMORY_TYPE_INFORMATION entry must name a memory type below EfiMaxMemoryType and\n   \
+                                                                            request a reasonable number of pages. Malformed bins destabilize the memory map\n   \
+                                                                            across boots and S4 resume.",
+                HobValidationKind::V2ReservedAttributeBitsSet { .. } => "   Resource Descriptor HOB v2 attributes hold EFI_MEMORY_* cacheability and protection\n   \
+                                                                            attributes. Bits outside of those are reserved and usually mean flags meant for\n   \
+                                                                            another field, such as ResourceAttribute, were stored in the attributes.",
+                HobValidationKind::DuplicateAllocationName { .. } => "   A Memory Allocation HOB name GUID identifies what the memory was allocated for. The same\n   \
+                                                                         name on allocations of different ranges usually means a driver allocated twice under\n   \
+                                                                         one identity. Give each allocation its own GUID, or use the all-zero GUID for\n   \
+                                                                         anonymous allocations.",
+                HobValidationKind::OverlappingFirmwareVolumes { .. } => "   Firmware Volume HOBs must describe disjoint ranges of the flash layout. Overlapping FV\n   \
+                                                                            HOBs usually come from a wrong base address or size in the platform FDF or PCDs.",
+                HobValidationKind::MemoryGap { .. } => "   System memory Resource Descriptor HOBs are expected to cover the whole physical address\n   \
+                                                           range below the required end without holes. Describe the missing memory with a\n   \
+                                                           Resource Descriptor HOB, or lower the required end if the hole is intended.",
+                HobValidationKind::MalformedGuid { .. } => "   GUID fields of a capture are expected in the canonical 8-4-4-4-12 hex format. Anything\n   \
+                                                               else means the capture was hand edited or truncated, and validations keyed on the\n   \
+                                                               GUID cannot be trusted. Capture the platform again.",
+                HobValidationKind::UnknownHobPresent { .. } => "   The HOB list contains HOB types the capture tool could not classify, so they were not\n   \
+                                                                   validated. Check whether the DXE core needs them, and report the type codes so the\n   \
+                                                                   tool can learn to parse them.",
+                HobValidationKind::IoPortRangeOutOfBounds { .. } => "   IO resource descriptors describe ranges of the 16-bit IO port space, not of physical\n   \
+                                                                        memory. A range reaching past port 0xFFFF usually holds a memory address or an MMIO\n   \
+                                                                        length by mistake. Describe MMIO with EFI_RESOURCE_MEMORY_MAPPED_IO instead.\n   \
+                                                                        Ref: https://opendevicepartnership.github.io/patina/integrate/patina_dxe_core_requirements_checklist.html",
+                HobValidationKind::OverlappingIoPortRanges { .. } => "   Each IO port must be described by a single IO or IO reserved resource descriptor.\n   \
+                                                                         Platforms must split overlapping IO ranges, e.g. a reserved legacy port range inside\n   \
+                                                                         a larger IO aperture, into non-overlapping HOBs.\n   \
+                                                                         Ref: https://opendevicepartnership.github.io/patina/integrate/patina_dxe_core_requirements_checklist.html",
+                HobValidationKind::NoV2DescriptorsPresent { .. } => "   The platform describes its resources with V1 Resource Descriptor HOBs only, so it has\n   \
+                                                                        not started migrating to the V2 format that carries the memory attributes. Produce a\n   \
+                                                                        V2 HOB for every V1 range; V1MemoryRangeNotContainedInV2 lists the ranges to cover.\n   \
+                                                                        Ref: https://opendevicepartnership.github.io/patina/integrate/patina_dxe_core_requirements_checklist.html",
             },
             ValidationKind::Fv(fv) => match fv {
                 FvValidationKind::CombinedDriversPresent { .. } => "   Firmware volume contains prohibited combined drivers. \nBelow file types are prohibited\n- COMBINED_MM_DXE(0x0C)\n- COMBINED_PEIM_DRIVER(0x08).\n   \
@@ -161,7 +628,56 @@ impl ValidationKind<'_> {
                 FvValidationKind::InvalidSectionAlignment { .. } => "   All PE images must have section alignment that is a multiple of page size. \n   \
                                                                         This is not a PI spec requirement, but is a Patina requirement.\n    \
                                                                         Platforms should drop unaligned images or re-build images to ensure section alignment is page-aligned.    \n
-                                                                        Ref: https://opendevicepartnership.github.io/patina/integrate/patina_dxe_core_requirements_checklist.html"
+                                                                        Ref: https://opendevicepartnership.github.io/patina/integrate/patina_dxe_core_requirements_checklist.html",
+                FvValidationKind::DriverMissingDepex { .. } => "   A Priori files are not supported, so DXE drivers must order their dispatch with depex\n   \
+                                                                   statements. A driver without a DXE_DEPEX section is only dispatched once every\n   \
+                                                                   architectural protocol is installed. Add a depex (TRUE if it has no dependencies).\n   \
+                                                                   Ref: https://opendevicepartnership.github.io/patina/integrate/patina_dxe_core_requirements_checklist.html",
+                FvValidationKind::UnsupportedPeMachine { .. } => "   Patina DXE is 64-bit only, so PE images must be built for x64 or AArch64. A 32-bit\n   \
+                                                                     (IA32/ARM) image in a firmware volume is usually a packaging mistake and cannot be\n   \
+                                                                     loaded. Remove the image from the FDF or rebuild it for the platform architecture.\n   \
+                                                                     Ref: https://opendevicepartnership.github.io/patina/integrate/patina_dxe_core_requirements_checklist.html",
+                FvValidationKind::InconsistentFileLength { .. } => "   A firmware file with a zero length, or with sections that add up to more than the\n   \
+                                                                       file length, indicates a corrupted or truncated firmware volume. Rebuild the FV\n   \
+                                                                       and check that the flash region holding it was fully programmed.\n   \
+                                                                       Ref: https://opendevicepartnership.github.io/patina/integrate/patina_dxe_core_requirements_checklist.html",
+                FvValidationKind::DuplicateFileGuid { .. } => "   Firmware files sharing a GUID make dispatch nondeterministic, since which copy is\n   \
+                                                                  loaded depends on the order the firmware volumes are discovered. Remove the\n   \
+                                                                  duplicate from the FDF or give each module its own FILE_GUID.\n   \
+                                                                  Ref: https://opendevicepartnership.github.io/patina/integrate/patina_dxe_core_requirements_checklist.html",
+                FvValidationKind::DisallowedCompression { .. } => "   The section is compressed with a type outside of the allowed compression set given\n   \
+                                                                      with --allowed-compression. Rebuild the section with one of the allowed types.\n   \
+                                                                      Ref: https://opendevicepartnership.github.io/patina/integrate/patina_dxe_core_requirements_checklist.html",
+                FvValidationKind::UnknownGuidedSection { .. } => "   The section is processed by a GUID defined section extractor that Patina does not\n   \
+                                                                     provide. Unless the platform registers its own extractor for this GUID, the DXE core\n   \
+                                                                     cannot open the section. Rebuild the section with Brotli, TianoCompress or CRC32.\n   \
+                                                                     Ref: https://opendevicepartnership.github.io/patina/integrate/patina_dxe_core_requirements_checklist.html",
+                FvValidationKind::MalformedFileGuid { .. } => "   Firmware file names are expected in the canonical 8-4-4-4-12 hex GUID format. Anything\n   \
+                                                                  else means the capture was hand edited or truncated, and validations keyed on the\n   \
+                                                                  file GUID cannot be trusted. Capture the platform again.",
+                FvValidationKind::NonStandaloneMmPresent { .. } => "   Patina only supports Standalone MM. Platforms with MM must build their MM drivers as\n   \
+                                                                       MM_STANDALONE and use the MM_CORE_STANDALONE core, while platforms mid-migration\n   \
+                                                                       still carry traditional MM and MM_CORE modules that must be ported.\n   \
+                                                                       Ref: https://opendevicepartnership.github.io/patina/integrate/patina_dxe_core_requirements_checklist.html",
+                FvValidationKind::SuspiciousSectionOrdering { .. } => "   Build tools place the UI and VERSION sections after the image of a module. An image\n   \
+                                                                          section following them points at an FFS file assembled from sections concatenated\n   \
+                                                                          in the wrong order. Check the FDF rule or the tooling that generated the file.",
+                FvValidationKind::RelocationsStripped { .. } => "   The DXE core loads drivers at whatever address it allocates, so their PE images must\n   \
+                                                                    keep their relocations. An image linked with /FIXED has IMAGE_FILE_RELOCS_STRIPPED set\n   \
+                                                                    and cannot be loaded anywhere but its preferred base. Rebuild the driver without /FIXED.\n   \
+                                                                    Ref: https://opendevicepartnership.github.io/patina/integrate/patina_dxe_core_requirements_checklist.html",
+                FvValidationKind::MmPresent { .. } => "   The selected profile is for platforms that boot without MM. Remove the MM core and the\n   \
+                                                          MM drivers from the FDF, or select a profile that allows Standalone MM.",
+            },
+            ValidationKind::Cross(cross) => match cross {
+                CrossValidationKind::FvSizeMismatch { .. } => "   The firmware volume header disagrees with the FV HOB describing it, which usually means\n   \
+                                                                  the FV was relocated without updating its HOB, or the same FV is counted twice. Make\n   \
+                                                                  the FV HOB base address and length match the firmware volume.\n   \
+                                                                  Ref: https://opendevicepartnership.github.io/patina/integrate/patina_dxe_core_requirements_checklist.html",
+                CrossValidationKind::UnparsedFirmwareVolume { .. } => "   An FV HOB points at memory the capture tool could not parse as a firmware volume, so\n   \
+                                                                          its files were not validated and the DXE core will not be able to dispatch them either.\n   \
+                                                                          Check that the flash region was fully programmed and that the HOB describes a real FV.\n   \
+                                                                          Ref: https://opendevicepartnership.github.io/patina/integrate/patina_dxe_core_requirements_checklist.html",
             },
         }
     }
@@ -171,6 +687,7 @@ impl ValidationKind<'_> {
             ValidationKind::Hob(hob) => match hob {
                 HobValidationKind::InconsistentMemoryAttributes { .. } => "InconsistentMemoryAttributes".to_string(),
                 HobValidationKind::OverlappingMemoryRanges { .. } => "OverlappingMemoryRanges".to_string(),
+                HobValidationKind::OverlappingMemoryCluster { .. } => "OverlappingMemoryCluster".to_string(),
                 HobValidationKind::PageZeroMemoryDescribed { .. } => "PageZeroMemoryDescribed".to_string(),
                 HobValidationKind::V1MemoryRangeNotContainedInV2 { .. } => "V1MemoryRangeNotContainedInV2".to_string(),
                 HobValidationKind::V2ContainsUceAttribute { .. } => "V2ContainsUceAttribute".to_string(),
@@ -186,6 +703,39 @@ impl ValidationKind<'_> {
                 HobValidationKind::MemoryTypeInfoResourceLengthTooSmall { .. } => {
                     "MemoryTypeInfoResourceLengthTooSmall".to_string()
                 }
+                HobValidationKind::MissingOrDuplicateHandoff { .. } => "MissingOrDuplicateHandoff".to_string(),
+                HobValidationKind::InconsistentHandoffBounds { .. } => "InconsistentHandoffBounds".to_string(),
+                HobValidationKind::OverlappingMemoryAllocations { .. } => "OverlappingMemoryAllocations".to_string(),
+                HobValidationKind::AllocationOutsideDeclaredMemory { .. } => {
+                    "AllocationOutsideDeclaredMemory".to_string()
+                }
+                HobValidationKind::ResourceRangeOverflow { .. } => "ResourceRangeOverflow".to_string(),
+                HobValidationKind::ZeroLengthRange { .. } => "ZeroLengthRange".to_string(),
+                HobValidationKind::ResourceBeyondCpuAddressSpace { .. } => "ResourceBeyondCpuAddressSpace".to_string(),
+                HobValidationKind::DuplicateResourceDescriptor { .. } => "DuplicateResourceDescriptor".to_string(),
+                HobValidationKind::InvalidMemoryType { .. } => "InvalidMemoryType".to_string(),
+                HobValidationKind::UnalignedResourceDescriptor { .. } => "UnalignedResourceDescriptor".to_string(),
+                HobValidationKind::IoResourceHasProtectionAttribute { .. } => {
+                    "IoResourceHasProtectionAttribute".to_string()
+                }
+                HobValidationKind::UnsupportedHandoffVersion { .. } => "UnsupportedHandoffVersion".to_string(),
+                HobValidationKind::LegacyHandoffVersion { .. } => "LegacyHandoffVersion".to_string(),
+                HobValidationKind::InconsistentResourceAttributeBits { .. } => {
+                    "InconsistentResourceAttributeBits".to_string()
+                }
+                HobValidationKind::AllocationInMmioRange { .. } => "AllocationInMmioRange".to_string(),
+                HobValidationKind::ProhibitedGuidHob { .. } => "ProhibitedGuidHob".to_string(),
+                HobValidationKind::MissingRequiredGuidHob { .. } => "MissingRequiredGuidHob".to_string(),
+                HobValidationKind::InvalidMemoryTypeInfoEntry { .. } => "InvalidMemoryTypeInfoEntry".to_string(),
+                HobValidationKind::V2ReservedAttributeBitsSet { .. } => "V2ReservedAttributeBitsSet".to_string(),
+                HobValidationKind::DuplicateAllocationName { .. } => "DuplicateAllocationName".to_string(),
+                HobValidationKind::OverlappingFirmwareVolumes { .. } => "OverlappingFirmwareVolumes".to_string(),
+                HobValidationKind::MemoryGap { .. } => "MemoryGap".to_string(),
+                HobValidationKind::MalformedGuid { .. } => "MalformedGuid".to_string(),
+                HobValidationKind::UnknownHobPresent { .. } => "UnknownHobPresent".to_string(),
+                HobValidationKind::IoPortRangeOutOfBounds { .. } => "IoPortRangeOutOfBounds".to_string(),
+                HobValidationKind::OverlappingIoPortRanges { .. } => "OverlappingIoPortRanges".to_string(),
+                HobValidationKind::NoV2DescriptorsPresent { .. } => "NoV2DescriptorsPresent".to_string(),
             },
             ValidationKind::Fv(fv) => match fv {
                 FvValidationKind::CombinedDriversPresent { .. } => "CombinedDriversPresent".to_string(),
@@ -193,9 +743,195 @@ impl ValidationKind<'_> {
                 FvValidationKind::ProhibitedAprioriFile { .. } => "ProhibitedAprioriFile".to_string(),
                 FvValidationKind::UsesTraditionalSmm { .. } => "UsesTraditionalSmm".to_string(),
                 FvValidationKind::InvalidSectionAlignment { .. } => "InvalidSectionAlignment".to_string(),
+                FvValidationKind::DriverMissingDepex { .. } => "DriverMissingDepex".to_string(),
+                FvValidationKind::UnsupportedPeMachine { .. } => "UnsupportedPeMachine".to_string(),
+                FvValidationKind::InconsistentFileLength { .. } => "InconsistentFileLength".to_string(),
+                FvValidationKind::DuplicateFileGuid { .. } => "DuplicateFileGuid".to_string(),
+                FvValidationKind::DisallowedCompression { .. } => "DisallowedCompression".to_string(),
+                FvValidationKind::UnknownGuidedSection { .. } => "UnknownGuidedSection".to_string(),
+                FvValidationKind::MalformedFileGuid { .. } => "MalformedFileGuid".to_string(),
+                FvValidationKind::NonStandaloneMmPresent { .. } => "NonStandaloneMmPresent".to_string(),
+                FvValidationKind::SuspiciousSectionOrdering { .. } => "SuspiciousSectionOrdering".to_string(),
+                FvValidationKind::RelocationsStripped { .. } => "RelocationsStripped".to_string(),
+                FvValidationKind::MmPresent { .. } => "MmPresent".to_string(),
+            },
+            ValidationKind::Cross(cross) => match cross {
+                CrossValidationKind::FvSizeMismatch { .. } => "FvSizeMismatch".to_string(),
+                CrossValidationKind::UnparsedFirmwareVolume { .. } => "UnparsedFirmwareVolume".to_string(),
             },
         }
     }
+
+    pub fn severity(&self) -> Severity {
+        match self {
+            ValidationKind::Hob(hob) => match hob {
+                HobValidationKind::V1MemoryRangeNotContainedInV2 { .. }
+                | HobValidationKind::ZeroLengthRange { .. }
+                | HobValidationKind::DuplicateResourceDescriptor { .. }
+                | HobValidationKind::UnalignedResourceDescriptor { .. }
+                | HobValidationKind::IoResourceHasProtectionAttribute { .. }
+                | HobValidationKind::InconsistentResourceAttributeBits { .. }
+                | HobValidationKind::ProhibitedGuidHob { .. }
+                | HobValidationKind::DuplicateAllocationName { .. }
+                | HobValidationKind::V2ReservedAttributeBitsSet { .. }
+                | HobValidationKind::UnknownHobPresent { .. }
+                | HobValidationKind::LegacyHandoffVersion { .. } => Severity::Warning,
+                HobValidationKind::InvalidMemoryTypeInfoEntry { .. } => Severity::Info,
+                HobValidationKind::InconsistentMemoryAttributes { .. }
+                | HobValidationKind::OverlappingMemoryRanges { .. }
+                | HobValidationKind::OverlappingMemoryCluster { .. }
+                | HobValidationKind::PageZeroMemoryDescribed { .. }
+                | HobValidationKind::V2ContainsUceAttribute { .. }
+                | HobValidationKind::V2MissingValidCacheabilityAttribute { .. }
+                | HobValidationKind::V2InvalidIoCacheabilityAttributes { .. }
+                | HobValidationKind::MemoryTypeInfoMultipleResourceHobs { .. }
+                | HobValidationKind::MemoryTypeInfoResourceLengthTooSmall { .. }
+                | HobValidationKind::MissingOrDuplicateHandoff { .. }
+                | HobValidationKind::InconsistentHandoffBounds { .. }
+                | HobValidationKind::OverlappingMemoryAllocations { .. }
+                | HobValidationKind::AllocationOutsideDeclaredMemory { .. }
+                | HobValidationKind::ResourceRangeOverflow { .. }
+                | HobValidationKind::ResourceBeyondCpuAddressSpace { .. }
+                | HobValidationKind::InvalidMemoryType { .. }
+                | HobValidationKind::UnsupportedHandoffVersion { .. }
+                | HobValidationKind::AllocationInMmioRange { .. }
+                | HobValidationKind::MissingRequiredGuidHob { .. }
+                | HobValidationKind::OverlappingFirmwareVolumes { .. }
+                | HobValidationKind::MemoryGap { .. }
+                | HobValidationKind::MalformedGuid { .. }
+                | HobValidationKind::IoPortRangeOutOfBounds { .. }
+                | HobValidationKind::OverlappingIoPortRanges { .. }
+                | HobValidationKind::NoV2DescriptorsPresent { .. } => Severity::Error,
+            },
+            ValidationKind::Fv(fv) => match fv {
+                FvValidationKind::CombinedDriversPresent { .. }
+                | FvValidationKind::LzmaCompressedSections { .. }
+                | FvValidationKind::ProhibitedAprioriFile { .. }
+                | FvValidationKind::UsesTraditionalSmm { .. }
+                | FvValidationKind::InvalidSectionAlignment { .. }
+                | FvValidationKind::UnsupportedPeMachine { .. }
+                | FvValidationKind::InconsistentFileLength { .. }
+                | FvValidationKind::DuplicateFileGuid { .. }
+                | FvValidationKind::DisallowedCompression { .. }
+                | FvValidationKind::MalformedFileGuid { .. }
+                | FvValidationKind::NonStandaloneMmPresent { .. }
+                | FvValidationKind::RelocationsStripped { .. }
+                | FvValidationKind::MmPresent { .. } => Severity::Error,
+                FvValidationKind::DriverMissingDepex { .. }
+                | FvValidationKind::UnknownGuidedSection { .. }
+                | FvValidationKind::SuspiciousSectionOrdering { .. } => Severity::Warning,
+            },
+            ValidationKind::Cross(cross) => match cross {
+                CrossValidationKind::FvSizeMismatch { .. } => Severity::Error,
+                CrossValidationKind::UnparsedFirmwareVolume { .. } => Severity::Warning,
+            },
+        }
+    }
+
+    /// Returns the table columns of the violation keyed by column name,
+    /// without the leading row number column. Cells holding a serialized
+    /// HOB/FV structure are embedded as JSON rather than as strings.
+    pub fn details(&self) -> Map<String, Value> {
+        self.table_header()
+            .into_iter()
+            .zip(self.table_row(String::new()))
+            .skip(1)
+            .map(|(column, cell)| {
+                let value = match serde_json::from_str::<Value>(&cell) {
+                    Ok(value @ (Value::Object(_) | Value::Array(_))) => value,
+                    _ => Value::String(cell),
+                };
+                (column.to_string(), value)
+            })
+            .collect()
+    }
+
+    /// Returns the complete HOB(s) or firmware file(s) involved in the
+    /// violation as JSON, for `--explain`. Files are given with the header of
+    /// their FV, without its other files. Violations that do not refer to a
+    /// HOB or file structure fall back to their `details()`.
+    pub fn explain(&self) -> Value {
+        fn fv_header(fv: &FirmwareVolumeSerDe) -> Value {
+            let mut fv = serde_json::to_value(fv).unwrap_or_default();
+            if let Value::Object(fields) = &mut fv {
+                fields.remove("files");
+            }
+            fv
+        }
+
+        match self {
+            ValidationKind::Hob(hob) => match hob {
+                HobValidationKind::InconsistentMemoryAttributes { hob1, hob2 }
+                | HobValidationKind::OverlappingMemoryRanges { hob1, hob2 }
+                | HobValidationKind::DuplicateResourceDescriptor { hob1, hob2 }
+                | HobValidationKind::OverlappingIoPortRanges { hob1, hob2 } => json!([hob1, hob2]),
+                HobValidationKind::OverlappingMemoryCluster { hobs } => json!(hobs),
+                HobValidationKind::V1MemoryRangeNotContainedInV2 { hob1 }
+                | HobValidationKind::V2ContainsUceAttribute { hob1, .. }
+                | HobValidationKind::V2MissingValidCacheabilityAttribute { hob1, .. }
+                | HobValidationKind::V2InvalidIoCacheabilityAttributes { hob1, .. }
+                | HobValidationKind::MemoryTypeInfoMultipleResourceHobs { hob1 }
+                | HobValidationKind::MemoryTypeInfoResourceLengthTooSmall { hob1, .. }
+                | HobValidationKind::ResourceRangeOverflow { hob1 }
+                | HobValidationKind::ResourceBeyondCpuAddressSpace { hob1, .. }
+                | HobValidationKind::UnalignedResourceDescriptor { hob1 }
+                | HobValidationKind::IoResourceHasProtectionAttribute { hob1, .. }
+                | HobValidationKind::InconsistentResourceAttributeBits { hob1, .. }
+                | HobValidationKind::V2ReservedAttributeBitsSet { hob1, .. }
+                | HobValidationKind::IoPortRangeOutOfBounds { hob1 } => json!(hob1),
+                HobValidationKind::PageZeroMemoryDescribed { alloc_desc }
+                | HobValidationKind::AllocationOutsideDeclaredMemory { alloc_desc }
+                | HobValidationKind::InvalidMemoryType { alloc_desc } => json!(alloc_desc),
+                HobValidationKind::OverlappingMemoryAllocations { alloc_desc1, alloc_desc2 } => {
+                    json!([alloc_desc1, alloc_desc2])
+                }
+                HobValidationKind::AllocationInMmioRange { alloc_desc, hob1 } => json!([alloc_desc, hob1]),
+                HobValidationKind::ZeroLengthRange { range: RangeDescriptor::Resource(hob1) } => json!(hob1),
+                HobValidationKind::ZeroLengthRange { range: RangeDescriptor::Allocation(alloc_desc) } => {
+                    json!(alloc_desc)
+                }
+                HobValidationKind::InvalidMemoryTypeInfoEntry { entry } => json!(entry),
+                _ => Value::Object(self.details()),
+            },
+            ValidationKind::Fv(fv) => match fv {
+                FvValidationKind::CombinedDriversPresent { fv, file }
+                | FvValidationKind::LzmaCompressedSections { fv, file, .. }
+                | FvValidationKind::ProhibitedAprioriFile { fv, file }
+                | FvValidationKind::UsesTraditionalSmm { fv, file }
+                | FvValidationKind::InvalidSectionAlignment { fv, file, .. }
+                | FvValidationKind::DriverMissingDepex { fv, file }
+                | FvValidationKind::UnsupportedPeMachine { fv, file, .. }
+                | FvValidationKind::InconsistentFileLength { fv, file }
+                | FvValidationKind::DuplicateFileGuid { fv, file, .. }
+                | FvValidationKind::DisallowedCompression { fv, file, .. }
+                | FvValidationKind::UnknownGuidedSection { fv, file, .. }
+                | FvValidationKind::MalformedFileGuid { fv, file }
+                | FvValidationKind::NonStandaloneMmPresent { fv, file }
+                | FvValidationKind::SuspiciousSectionOrdering { fv, file }
+                | FvValidationKind::RelocationsStripped { fv, file, .. }
+                | FvValidationKind::MmPresent { fv, file } => {
+                    json!({ "fv": fv_header(fv), "file": file })
+                }
+            },
+            ValidationKind::Cross(cross) => match cross {
+                CrossValidationKind::FvSizeMismatch { fv, .. } => json!({ "fv": fv_header(fv) }),
+                CrossValidationKind::UnparsedFirmwareVolume { .. } => Value::Object(self.details()),
+            },
+        }
+    }
+
+    /// Returns an identifier of the violation that is stable across runs,
+    /// made of the kind name and a hash of its identifying fields. The fields
+    /// are the details without the resolution text, serialized with sorted
+    /// keys and lowercased so that GUID and hex case do not matter. The row
+    /// number is not part of the details, so the fingerprint does not depend
+    /// on where the offending HOB/FV appears in the capture.
+    pub fn fingerprint(&self) -> String {
+        let mut details = self.details();
+        details.remove("Violation/Resolution");
+        let identifying_fields = Value::Object(details).to_string().to_lowercase();
+        format!("{}:{:016x}", self.name(), fnv1a_64(identifying_fields.as_bytes()))
+    }
 }
 
 pub trait PrettyPrintTable {
@@ -213,6 +949,7 @@ impl PrettyPrintTable for ValidationKind<'_> {
                 HobValidationKind::OverlappingMemoryRanges { .. } => {
                     vec!["#", "Hob 1", "Hob 2", "Violation/Resolution"]
                 }
+                HobValidationKind::OverlappingMemoryCluster { .. } => vec!["#", "Hobs", "Violation/Resolution"],
                 HobValidationKind::PageZeroMemoryDescribed { .. } => {
                     vec!["#", "Memory Allocation Descriptor", "Violation/Resolution"]
                 }
@@ -230,6 +967,76 @@ impl PrettyPrintTable for ValidationKind<'_> {
                 HobValidationKind::MemoryTypeInfoResourceLengthTooSmall { .. } => {
                     vec!["#", "Resource Descriptor Hob", "Violation/Resolution"]
                 }
+                HobValidationKind::MissingOrDuplicateHandoff { .. } => {
+                    vec!["#", "Handoff Hob Count", "Violation/Resolution"]
+                }
+                HobValidationKind::InconsistentHandoffBounds { .. } => {
+                    vec!["#", "Handoff Hob", "Violation/Resolution"]
+                }
+                HobValidationKind::OverlappingMemoryAllocations { .. } => {
+                    vec![
+                        "#",
+                        "Memory Allocation Descriptor 1",
+                        "Memory Allocation Descriptor 2",
+                        "Violation/Resolution",
+                    ]
+                }
+                HobValidationKind::AllocationOutsideDeclaredMemory { .. } => {
+                    vec!["#", "Memory Allocation Descriptor", "Violation/Resolution"]
+                }
+                HobValidationKind::ResourceRangeOverflow { .. } => {
+                    vec!["#", "Resource Descriptor Hob", "Violation/Resolution"]
+                }
+                HobValidationKind::ZeroLengthRange { .. } => vec!["#", "Hob", "Violation/Resolution"],
+                HobValidationKind::ResourceBeyondCpuAddressSpace { .. } => {
+                    vec!["#", "Resource Descriptor Hob", "Violation/Resolution"]
+                }
+                HobValidationKind::DuplicateResourceDescriptor { .. } => {
+                    vec!["#", "Hob 1", "Hob 2", "Violation/Resolution"]
+                }
+                HobValidationKind::InvalidMemoryType { .. } => {
+                    vec!["#", "Memory Allocation Descriptor", "Violation/Resolution"]
+                }
+                HobValidationKind::UnalignedResourceDescriptor { .. } => {
+                    vec!["#", "Resource Descriptor Hob", "Violation/Resolution"]
+                }
+                HobValidationKind::IoResourceHasProtectionAttribute { .. } => {
+                    vec!["#", "V2 Hob", "Violation/Resolution"]
+                }
+                HobValidationKind::UnsupportedHandoffVersion { .. }
+                | HobValidationKind::LegacyHandoffVersion { .. } => {
+                    vec!["#", "Handoff Hob Version", "Violation/Resolution"]
+                }
+                HobValidationKind::InconsistentResourceAttributeBits { .. } => {
+                    vec!["#", "Resource Descriptor Hob", "Violation/Resolution"]
+                }
+                HobValidationKind::AllocationInMmioRange { .. } => {
+                    vec!["#", "Memory Allocation Descriptor", "Resource Descriptor Hob", "Violation/Resolution"]
+                }
+                HobValidationKind::ProhibitedGuidHob { .. } => vec!["#", "GUID Hob Name", "Violation/Resolution"],
+                HobValidationKind::MissingRequiredGuidHob { .. } => {
+                    vec!["#", "GUID Hob Name", "Violation/Resolution"]
+                }
+                HobValidationKind::InvalidMemoryTypeInfoEntry { .. } => {
+                    vec!["#", "Memory Type Information Entry", "Violation/Resolution"]
+                }
+                HobValidationKind::V2ReservedAttributeBitsSet { .. } => vec!["#", "V2 Hob", "Violation/Resolution"],
+                HobValidationKind::DuplicateAllocationName { .. } => {
+                    vec!["#", "Allocation Name", "Ranges", "Violation/Resolution"]
+                }
+                HobValidationKind::OverlappingFirmwareVolumes { .. } => {
+                    vec!["#", "FV Hob 1", "FV Hob 2", "Violation/Resolution"]
+                }
+                HobValidationKind::MemoryGap { .. } => vec!["#", "Gap", "Violation/Resolution"],
+                HobValidationKind::MalformedGuid { .. } => vec!["#", "Field", "Value", "Violation/Resolution"],
+                HobValidationKind::UnknownHobPresent { .. } => vec!["#", "Unknown HOBs", "Violation/Resolution"],
+                HobValidationKind::IoPortRangeOutOfBounds { .. } => vec!["#", "IO Hob", "Violation/Resolution"],
+                HobValidationKind::NoV2DescriptorsPresent { .. } => {
+                    vec!["#", "Resource Descriptors", "Violation/Resolution"]
+                }
+                HobValidationKind::OverlappingIoPortRanges { .. } => {
+                    vec!["#", "IO Hob 1", "IO Hob 2", "Violation/Resolution"]
+                }
             },
             ValidationKind::Fv(fv) => match fv {
                 FvValidationKind::CombinedDriversPresent { .. } => vec!["#", "File", "Violation/Resolution"],
@@ -241,6 +1048,21 @@ impl PrettyPrintTable for ValidationKind<'_> {
                 FvValidationKind::InvalidSectionAlignment { .. } => {
                     vec!["#", "PE Image Section Alignment", "Violation/Resolution"]
                 }
+                FvValidationKind::DriverMissingDepex { .. } => vec!["#", "Driver File", "Violation/Resolution"],
+                FvValidationKind::UnsupportedPeMachine { .. } => vec!["#", "PE Image", "Violation/Resolution"],
+                FvValidationKind::InconsistentFileLength { .. } => vec!["#", "File", "Violation/Resolution"],
+                FvValidationKind::DuplicateFileGuid { .. } => vec!["#", "File", "Violation/Resolution"],
+                FvValidationKind::DisallowedCompression { .. } => vec!["#", "Section", "Violation/Resolution"],
+                FvValidationKind::UnknownGuidedSection { .. } => vec!["#", "Section", "Violation/Resolution"],
+                FvValidationKind::MalformedFileGuid { .. } => vec!["#", "File", "Violation/Resolution"],
+                FvValidationKind::NonStandaloneMmPresent { .. } => vec!["#", "MM Module", "Violation/Resolution"],
+                FvValidationKind::SuspiciousSectionOrdering { .. } => vec!["#", "File", "Violation/Resolution"],
+                FvValidationKind::RelocationsStripped { .. } => vec!["#", "PE Image", "Violation/Resolution"],
+                FvValidationKind::MmPresent { .. } => vec!["#", "MM Module", "Violation/Resolution"],
+            },
+            ValidationKind::Cross(cross) => match cross {
+                CrossValidationKind::FvSizeMismatch { .. } => vec!["#", "Firmware Volume", "Violation/Resolution"],
+                CrossValidationKind::UnparsedFirmwareVolume { .. } => vec!["#", "FV HOB", "Violation/Resolution"],
             },
         }
     }
@@ -276,12 +1098,25 @@ impl PrettyPrintTable for ValidationKind<'_> {
                     let resolution = format!(
                         "Hob 1 range should not overlap with Hob 2 range\nHob 1 range({}, {}) | Hob 2 range({}, {})",
                         hob1.start(),
-                        hob1.start(),
+                        hob1.end(),
                         hob2.start(),
                         hob2.end()
                     );
                     vec![row_num, hob1_column, hob2_column, resolution]
                 }
+                HobValidationKind::OverlappingMemoryCluster { hobs } => {
+                    let hobs_column =
+                        serde_json::to_string_pretty(hobs).unwrap_or("hobs serialization failed!".to_string());
+                    let start = hobs.iter().map(|hob| hob.start()).min().unwrap_or_default();
+                    let end = hobs.iter().map(|hob| hob.end()).max().unwrap_or_default();
+                    let resolution = format!(
+                        "{} Hobs describe overlapping ranges\nand should be split into non-overlapping Hobs\nCombined range({}, {})",
+                        hobs.len(),
+                        start,
+                        end
+                    );
+                    vec![row_num, hobs_column, resolution]
+                }
                 HobValidationKind::PageZeroMemoryDescribed { alloc_desc } => {
                     let mem_alloc_desc_column = serde_json::to_string_pretty(alloc_desc)
                         .unwrap_or("Memory Allocation Descriptor\nserialization failed!".to_string());
@@ -318,10 +1153,8 @@ impl PrettyPrintTable for ValidationKind<'_> {
                 HobValidationKind::V2InvalidIoCacheabilityAttributes { hob1, attributes } => {
                     let hob1_column =
                         serde_json::to_string_pretty(hob1).unwrap_or("hob 1 serialization failed!".to_string());
-                    let resolution = format!(
-                        "V2 Hob should not contain cacheability or memory protection attributes(0x{:X}) for IO ranges",
-                        attributes
-                    );
+                    let resolution =
+                        format!("V2 Hob should not contain cacheability attributes(0x{:X}) for IO ranges", attributes);
                     vec![row_num, hob1_column, resolution]
                 }
                 HobValidationKind::MemoryTypeInfoMultipleResourceHobs { hob1 } => {
@@ -341,10 +1174,276 @@ impl PrettyPrintTable for ValidationKind<'_> {
                     );
                     vec![row_num, hob_column, resolution]
                 }
+                HobValidationKind::MissingOrDuplicateHandoff { count } => {
+                    let resolution = format!("Found {} Handoff HOB(s)\nExactly one Handoff HOB is required", count);
+                    vec![row_num, count.to_string(), resolution]
+                }
+                HobValidationKind::InconsistentHandoffBounds {
+                    memory_bottom,
+                    memory_top,
+                    free_memory_bottom,
+                    free_memory_top,
+                } => {
+                    let handoff_column = format!(
+                        "memory_bottom: 0x{:X}\nmemory_top: 0x{:X}\nfree_memory_bottom: 0x{:X}\nfree_memory_top: 0x{:X}",
+                        memory_bottom, memory_top, free_memory_bottom, free_memory_top
+                    );
+                    let mut broken = Vec::new();
+                    if free_memory_bottom < memory_bottom {
+                        broken.push("free_memory_bottom is below memory_bottom");
+                    }
+                    if free_memory_top > memory_top {
+                        broken.push("free_memory_top is above memory_top");
+                    }
+                    if free_memory_bottom > free_memory_top {
+                        broken.push("free_memory_bottom is above free_memory_top");
+                    }
+                    vec![row_num, handoff_column, broken.join("\n")]
+                }
+                HobValidationKind::OverlappingMemoryAllocations { alloc_desc1, alloc_desc2 } => {
+                    let alloc_desc1_column = serde_json::to_string_pretty(alloc_desc1)
+                        .unwrap_or("Memory Allocation Descriptor 1\nserialization failed!".to_string());
+                    let alloc_desc2_column = serde_json::to_string_pretty(alloc_desc2)
+                        .unwrap_or("Memory Allocation Descriptor 2\nserialization failed!".to_string());
+                    let resolution = format!(
+                        "Allocation 1 range should not overlap with Allocation 2 range\nAllocation 1 range({}, {}) | Allocation 2 range({}, {})",
+                        alloc_desc1.start(),
+                        alloc_desc1.end(),
+                        alloc_desc2.start(),
+                        alloc_desc2.end()
+                    );
+                    vec![row_num, alloc_desc1_column, alloc_desc2_column, resolution]
+                }
+                HobValidationKind::AllocationOutsideDeclaredMemory { alloc_desc } => {
+                    let mem_alloc_desc_column = serde_json::to_string_pretty(alloc_desc)
+                        .unwrap_or("Memory Allocation Descriptor\nserialization failed!".to_string());
+                    let resolution = format!(
+                        "Memory allocation range({}, {}) should be\ncovered by system memory\nResource Descriptor Hobs",
+                        alloc_desc.start(),
+                        alloc_desc.end()
+                    );
+                    vec![row_num, mem_alloc_desc_column, resolution]
+                }
+                HobValidationKind::ResourceRangeOverflow { hob1 } => {
+                    let hob_column =
+                        serde_json::to_string_pretty(hob1).unwrap_or("hob serialization failed!".to_string());
+                    let resolution = format!(
+                        "physical_start(0x{:X}) + resource_length(0x{:X})\nwraps past the end of the 64-bit address space",
+                        hob1.physical_start, hob1.resource_length
+                    );
+                    vec![row_num, hob_column, resolution]
+                }
+                HobValidationKind::ZeroLengthRange { range } => {
+                    let (hob_column, resolution) = match range {
+                        RangeDescriptor::Resource(hob1) => (
+                            serde_json::to_string_pretty(hob1).unwrap_or("hob serialization failed!".to_string()),
+                            "Resource Descriptor Hob\nresource_length should not be 0".to_string(),
+                        ),
+                        RangeDescriptor::Allocation(alloc_desc) => (
+                            serde_json::to_string_pretty(alloc_desc)
+                                .unwrap_or("Memory Allocation Descriptor\nserialization failed!".to_string()),
+                            "Memory Allocation Hob\nmemory_length should not be 0".to_string(),
+                        ),
+                    };
+                    vec![row_num, hob_column, resolution]
+                }
+                HobValidationKind::ResourceBeyondCpuAddressSpace { hob1, size_of_memory_space } => {
+                    let hob_column =
+                        serde_json::to_string_pretty(hob1).unwrap_or("hob serialization failed!".to_string());
+                    let resolution = format!(
+                        "Resource range({}, {}) exceeds the\n{}-bit CPU address space ceiling(0x{:X})",
+                        hob1.start(),
+                        hob1.end(),
+                        size_of_memory_space,
+                        1u64 << size_of_memory_space
+                    );
+                    vec![row_num, hob_column, resolution]
+                }
+                HobValidationKind::DuplicateResourceDescriptor { hob1, hob2 } => {
+                    let hob1_column =
+                        serde_json::to_string_pretty(hob1).unwrap_or("hob 1 serialization failed!".to_string());
+                    let hob2_column =
+                        serde_json::to_string_pretty(hob2).unwrap_or("hob 2 serialization failed!".to_string());
+                    let resolution = format!(
+                        "Hob 1 and Hob 2 are identical\nrange({}, {})\nDelete one of them",
+                        hob1.start(),
+                        hob1.end()
+                    );
+                    vec![row_num, hob1_column, hob2_column, resolution]
+                }
+                HobValidationKind::InvalidMemoryType { alloc_desc } => {
+                    let mem_alloc_desc_column = serde_json::to_string_pretty(alloc_desc)
+                        .unwrap_or("Memory Allocation Descriptor\nserialization failed!".to_string());
+                    let resolution = format!(
+                        "memory_type({}, 0x{:X}) is not a defined\nEFI_MEMORY_TYPE or OEM/OS reserved value",
+                        alloc_desc.memory_type, alloc_desc.memory_type
+                    );
+                    vec![row_num, mem_alloc_desc_column, resolution]
+                }
+                HobValidationKind::UnalignedResourceDescriptor { hob1 } => {
+                    let hob_column =
+                        serde_json::to_string_pretty(hob1).unwrap_or("hob serialization failed!".to_string());
+                    let resolution = format!(
+                        "physical_start(0x{:X}) and resource_length(0x{:X})\nshould be multiples of the page size(0x{:X})",
+                        hob1.physical_start, hob1.resource_length, UEFI_PAGE_SIZE
+                    );
+                    vec![row_num, hob_column, resolution]
+                }
+                HobValidationKind::IoResourceHasProtectionAttribute { hob1, attributes } => {
+                    let hob1_column =
+                        serde_json::to_string_pretty(hob1).unwrap_or("hob 1 serialization failed!".to_string());
+                    let resolution = format!(
+                        "Attributes(0x{:X}) of an IO range should not contain\nmemory protection attributes\n - MEMORY_RP(0x2000)\n - MEMORY_XP(0x4000)\n - MEMORY_RO(0x20000)",
+                        attributes
+                    );
+                    vec![row_num, hob1_column, resolution]
+                }
+                HobValidationKind::UnsupportedHandoffVersion { version } => {
+                    let resolution = format!("Handoff HOB version(0x{:X}) is not supported\nExpected 0x10000", version);
+                    vec![row_num, format!("0x{:X}", version), resolution]
+                }
+                HobValidationKind::LegacyHandoffVersion { version } => {
+                    let resolution =
+                        format!("Handoff HOB version(0x{:X}) is the legacy version\nExpected 0x10000", version);
+                    vec![row_num, format!("0x{:X}", version), resolution]
+                }
+                HobValidationKind::InconsistentResourceAttributeBits { hob1, resource_attribute } => {
+                    let hob_column =
+                        serde_json::to_string_pretty(hob1).unwrap_or("hob serialization failed!".to_string());
+                    let mut broken = vec![format!("resource_attribute(0x{:X})", resource_attribute)];
+                    for ((bit, bit_name), (prerequisite, prerequisite_name)) in RESOURCE_ATTRIBUTE_DEPENDENCIES {
+                        if resource_attribute & bit != 0 && resource_attribute & prerequisite == 0 {
+                            broken.push(format!(
+                                "{}(0x{:X}) is set without {}(0x{:X})",
+                                bit_name, bit, prerequisite_name, prerequisite
+                            ));
+                        }
+                    }
+                    vec![row_num, hob_column, broken.join("\n")]
+                }
+                HobValidationKind::AllocationInMmioRange { alloc_desc, hob1 } => {
+                    let mem_alloc_desc_column = serde_json::to_string_pretty(alloc_desc)
+                        .unwrap_or("Memory Allocation Descriptor\nserialization failed!".to_string());
+                    let hob_column =
+                        serde_json::to_string_pretty(hob1).unwrap_or("hob serialization failed!".to_string());
+                    let resolution = format!(
+                        "Memory allocation range({}, {}) of memory_type({})\noverlaps resource_type({}) range({}, {})",
+                        alloc_desc.start(),
+                        alloc_desc.end(),
+                        alloc_desc.memory_type,
+                        hob1.resource_type,
+                        hob1.start(),
+                        hob1.end()
+                    );
+                    vec![row_num, mem_alloc_desc_column, hob_column, resolution]
+                }
+                HobValidationKind::ProhibitedGuidHob { name } => {
+                    vec![row_num, format_guid_named(name), "GUID Extension HOB should not be produced".to_string()]
+                }
+                HobValidationKind::MissingRequiredGuidHob { name } => {
+                    vec![row_num, format_guid_named(name), "GUID Extension HOB should be produced".to_string()]
+                }
+                HobValidationKind::InvalidMemoryTypeInfoEntry { entry } => {
+                    let entry_column =
+                        format!("memory_type: {}\nnumber_of_pages: 0x{:X}", entry.memory_type, entry.number_of_pages);
+                    let resolution = format!(
+                        "memory_type should be below {}\nnumber_of_pages should not exceed 0x40000 (1 GiB)",
+                        EFI_MAX_MEMORY_TYPE
+                    );
+                    vec![row_num, entry_column, resolution]
+                }
+                HobValidationKind::V2ReservedAttributeBitsSet { hob1, attributes } => {
+                    let hob1_column =
+                        serde_json::to_string_pretty(hob1).unwrap_or("hob 1 serialization failed!".to_string());
+                    let resolution = format!(
+                        "Attributes(0x{:X}) set reserved bits(0x{:X})\nOnly EFI_MEMORY_* attribute bits should be set",
+                        attributes,
+                        attributes & !KNOWN_V2_ATTRIBUTES_MASK
+                    );
+                    vec![row_num, hob1_column, resolution]
+                }
+                HobValidationKind::DuplicateAllocationName { name, count, ranges } => {
+                    let ranges_column = ranges
+                        .iter()
+                        .map(|(base, length)| format!("Base: 0x{:X} Length: 0x{:X}", base, length))
+                        .collect::<Vec<_>>()
+                        .join("\n");
+                    let resolution =
+                        format!("{} different ranges share this name\nEach allocation should have its own name", count);
+                    vec![row_num, format_guid_named(name), ranges_column, resolution]
+                }
+                HobValidationKind::OverlappingFirmwareVolumes { base1, len1, base2, len2 } => {
+                    let fv1_column = format!("Base: 0x{:X}\nLength: 0x{:X}", base1, len1);
+                    let fv2_column = format!("Base: 0x{:X}\nLength: 0x{:X}", base2, len2);
+                    let resolution = format!(
+                        "FV HOBs overlap in [0x{:X}, 0x{:X})\nFV HOB ranges should not overlap",
+                        base1.max(base2),
+                        (base1 + len1).min(base2 + len2)
+                    );
+                    vec![row_num, fv1_column, fv2_column, resolution]
+                }
+                HobValidationKind::MemoryGap { gap_start, gap_end } => {
+                    let gap_column = format!("Start: 0x{:X}\nEnd: 0x{:X}", gap_start, gap_end);
+                    let resolution = format!(
+                        "0x{:X} bytes of memory are not described by any\nsystem memory Resource Descriptor HOB",
+                        gap_end - gap_start
+                    );
+                    vec![row_num, gap_column, resolution]
+                }
+                HobValidationKind::MalformedGuid { field, guid } => vec![
+                    row_num,
+                    field.to_string(),
+                    format!("{:?}", guid),
+                    "Value is not a GUID in the 8-4-4-4-12 hex format".to_string(),
+                ],
+                HobValidationKind::UnknownHobPresent { count, hob_types } => {
+                    let hob_types = if hob_types.is_empty() {
+                        "not recorded by the capture".to_string()
+                    } else {
+                        hob_types.iter().map(|hob_type| format!("0x{:04X}", hob_type)).collect::<Vec<_>>().join(", ")
+                    };
+                    let hobs_column = format!("Count: {}\nTypes: {}", count, hob_types);
+                    let resolution = "HOB types the tool does not understand were not validated".to_string();
+                    vec![row_num, hobs_column, resolution]
+                }
+                HobValidationKind::IoPortRangeOutOfBounds { hob1 } => {
+                    let hob_column =
+                        serde_json::to_string_pretty(hob1).unwrap_or("hob serialization failed!".to_string());
+                    let resolution = format!(
+                        "IO ports 0x{:X}-0x{:X} should lie within the IO port space (0x0-0xFFFF)",
+                        hob1.start(),
+                        hob1.end() - 1
+                    );
+                    vec![row_num, hob_column, resolution]
+                }
+                HobValidationKind::NoV2DescriptorsPresent { v1_count } => {
+                    let descriptors_column = format!("V1: {}\nV2: 0", v1_count);
+                    let resolution = "Platform has not migrated to V2 Resource Descriptor HOBs".to_string();
+                    vec![row_num, descriptors_column, resolution]
+                }
+                HobValidationKind::OverlappingIoPortRanges { hob1, hob2 } => {
+                    let hob1_column =
+                        serde_json::to_string_pretty(hob1).unwrap_or("hob 1 serialization failed!".to_string());
+                    let hob2_column =
+                        serde_json::to_string_pretty(hob2).unwrap_or("hob 2 serialization failed!".to_string());
+                    let resolution = format!(
+                        "IO Hob 1 ports should not overlap with IO Hob 2 ports\nIO Hob 1 ports(0x{:X}-0x{:X}) | IO Hob 2 ports(0x{:X}-0x{:X})",
+                        hob1.start(),
+                        hob1.end() - 1,
+                        hob2.start(),
+                        hob2.end() - 1
+                    );
+                    vec![row_num, hob1_column, hob2_column, resolution]
+                }
             },
             ValidationKind::Fv(fv) => match fv {
                 FvValidationKind::CombinedDriversPresent { fv, file } => {
-                    let file_column = format!("FV: {}\nFile: {}\nFile Type: {}", fv.fv_name, file.name, file.file_type);
+                    let file_column = format!(
+                        "FV: {}\nFile: {}\nFile Type: {}",
+                        fv.fv_name,
+                        format_guid_named(&file.name),
+                        file.file_type
+                    );
                     let resolution =
                         "File types should not be\n - COMBINED_MM_DXE(0x0C)\n - COMBINED_PEIM_DRIVER(0x08)."
                             .to_string();
@@ -353,12 +1452,17 @@ impl PrettyPrintTable for ValidationKind<'_> {
                 FvValidationKind::LzmaCompressedSections { fv, file, section } => {
                     let section_json =
                         serde_json::to_string_pretty(section).unwrap_or("section serialization failed!".to_string());
-                    let section_column = format!("FV: {}\nFile: {}\nSection: {}", fv.fv_name, file.name, section_json);
+                    let section_column = format!(
+                        "FV: {}\nFile: {}\nSection: {}",
+                        fv.fv_name,
+                        format_guid_named(&file.name),
+                        section_json
+                    );
                     let resolution = "File section should not be compressed with LZMA.".to_string();
                     vec![row_num, section_column, resolution]
                 }
                 FvValidationKind::ProhibitedAprioriFile { fv, file } => {
-                    let file_column = format!("FV: {}\nFile: {}", fv.fv_name, file.name);
+                    let file_column = format!("FV: {}\nFile: {}", fv.fv_name, format_guid_named(&file.name));
                     let resolution =
                         "Following Apriori Files are not supported\n - PeiAprioriFileNameGuid(1b45cc0a-156a-428a-af62-49864da0e6e6)\n - AprioriGuid(fc510ee7-ffdc-11d4-bd41-0080c73c8881)."
                             .to_string();
@@ -367,10 +1471,12 @@ impl PrettyPrintTable for ValidationKind<'_> {
                 FvValidationKind::UsesTraditionalSmm { fv, file } => {
                     let file_column = format!(
                         "FV: {}\nSMM Driver File: {}\nSMM Driver Type: {}",
-                        fv.fv_name, file.name, file.file_type
+                        fv.fv_name,
+                        format_guid_named(&file.name),
+                        file.file_type
                     );
                     let resolution =
-                        "File types should not be\n - COMBINED_MM_DXE(0x0C)\n - COMBINED_PEIM_DRIVER(0x08)\n - MM(0x0A)\n - MM_CORE(0x0D)."
+                        "File types should not be\n - COMBINED_MM_DXE(0x0C)\n - COMBINED_PEIM_DRIVER(0x08)."
                             .to_string();
                     vec![row_num, file_column, resolution]
                 }
@@ -378,7 +1484,7 @@ impl PrettyPrintTable for ValidationKind<'_> {
                     let file_column = format!(
                         "FV: {}\nFile: {}\nSection Alignment: {}\nRequired Alignment:{}\n",
                         fv.fv_name,
-                        file.name,
+                        format_guid_named(&file.name),
                         section.pe_info.unwrap().section_alignment,
                         required_alignment,
                     );
@@ -387,6 +1493,135 @@ impl PrettyPrintTable for ValidationKind<'_> {
                             .to_string();
                     vec![row_num, file_column, resolution]
                 }
+                FvValidationKind::DriverMissingDepex { fv, file } => {
+                    let file_column = format!("FV: {}\nFile: {}", fv.fv_name, format_guid_named(&file.name));
+                    let resolution = "Driver file has no DXE_DEPEX section.".to_string();
+                    vec![row_num, file_column, resolution]
+                }
+                FvValidationKind::UnsupportedPeMachine { fv, file, section, machine } => {
+                    let file_column = format!(
+                        "FV: {}\nFile: {}\nSection: {}\nMachine: {:#06x}",
+                        fv.fv_name,
+                        format_guid_named(&file.name),
+                        section.section_type,
+                        machine
+                    );
+                    let resolution = format!(
+                        "PE machine type {:#06x} is neither x64 ({:#06x}) nor AArch64 ({:#06x}).",
+                        machine, COFF_MACHINE_X86_64, COFF_MACHINE_ARM64
+                    );
+                    vec![row_num, file_column, resolution]
+                }
+                FvValidationKind::InconsistentFileLength { fv, file } => {
+                    let section_length: usize = file.sections.iter().map(|section| section.length).sum();
+                    let file_column = format!(
+                        "FV: {}\nFile: {}\nType: {}",
+                        fv.fv_name,
+                        format_guid_named(&file.name),
+                        file.file_type
+                    );
+                    let resolution =
+                        format!("File length is {:#x} but its sections add up to {:#x}.", file.length, section_length);
+                    vec![row_num, file_column, resolution]
+                }
+                FvValidationKind::DuplicateFileGuid { fv, file, other_fvs } => {
+                    let file_column = format!(
+                        "FV: {}\nFile: {}\nType: {}",
+                        fv.fv_name,
+                        format_guid_named(&file.name),
+                        file.file_type
+                    );
+                    let resolution = format!("File GUID is also used in FV(s): {}.", other_fvs.join(", "));
+                    vec![row_num, file_column, resolution]
+                }
+                FvValidationKind::DisallowedCompression { fv, file, section, compression_type } => {
+                    let section_column = format!(
+                        "FV: {}\nFile: {}\nSection: {}",
+                        fv.fv_name,
+                        format_guid_named(&file.name),
+                        section.section_type
+                    );
+                    let resolution = format!("Compression type '{}' is not in the allowed set.", compression_type);
+                    vec![row_num, section_column, resolution]
+                }
+                FvValidationKind::UnknownGuidedSection { fv, file, section } => {
+                    let section_column = format!(
+                        "FV: {}\nFile: {}\nSection: {}",
+                        fv.fv_name,
+                        format_guid_named(&file.name),
+                        section.section_type
+                    );
+                    let resolution = format!(
+                        "Section definition GUID {} is not a known section processor.",
+                        format_guid_named(&section.compression_type)
+                    );
+                    vec![row_num, section_column, resolution]
+                }
+                FvValidationKind::MalformedFileGuid { fv, file } => {
+                    let file_column = format!("FV: {}\nFile: {:?}\nType: {}", fv.fv_name, file.name, file.file_type);
+                    let resolution = "File name is not a GUID in the 8-4-4-4-12 hex format.".to_string();
+                    vec![row_num, file_column, resolution]
+                }
+                FvValidationKind::NonStandaloneMmPresent { fv, file } => {
+                    let file_column = format!(
+                        "FV: {}\nFile: {}\nType: {}",
+                        fv.fv_name,
+                        format_guid_named(&file.name),
+                        file.file_type
+                    );
+                    let standalone_type =
+                        if file.file_type == "MmCore" { "MM_CORE_STANDALONE" } else { "MM_STANDALONE" };
+                    let resolution = format!("Module should be built as {}.", standalone_type);
+                    vec![row_num, file_column, resolution]
+                }
+                FvValidationKind::SuspiciousSectionOrdering { fv, file } => {
+                    let sections = file
+                        .sections
+                        .iter()
+                        .map(|section| section.section_type.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    let file_column =
+                        format!("FV: {}\nFile: {}\nSections: {}", fv.fv_name, format_guid_named(&file.name), sections);
+                    let resolution = "Image section follows a UserInterface or Version section.".to_string();
+                    vec![row_num, file_column, resolution]
+                }
+                FvValidationKind::RelocationsStripped { fv, file, section } => {
+                    let file_column = format!(
+                        "FV: {}\nFile: {}\nSection: {}",
+                        fv.fv_name,
+                        format_guid_named(&file.name),
+                        section.section_type
+                    );
+                    let resolution = "PE image has IMAGE_FILE_RELOCS_STRIPPED set and cannot be relocated.".to_string();
+                    vec![row_num, file_column, resolution]
+                }
+                FvValidationKind::MmPresent { fv, file } => {
+                    let file_column = format!(
+                        "FV: {}\nFile: {}\nType: {}",
+                        fv.fv_name,
+                        format_guid_named(&file.name),
+                        file.file_type
+                    );
+                    let resolution = "MM modules are not allowed by the profile.".to_string();
+                    vec![row_num, file_column, resolution]
+                }
+            },
+            ValidationKind::Cross(cross) => match cross {
+                CrossValidationKind::FvSizeMismatch { fv, hob_base_address, hob_length } => {
+                    let fv_column = format!(
+                        "FV: {}\nBase Address: {:#x}\nLength: {:#x}",
+                        fv.fv_name, fv.fv_base_address, fv.fv_length
+                    );
+                    let resolution =
+                        format!("FV HOB describes base address {:#x} and length {:#x}.", hob_base_address, hob_length);
+                    vec![row_num, fv_column, resolution]
+                }
+                CrossValidationKind::UnparsedFirmwareVolume { base_address, length } => {
+                    let hob_column = format!("Base Address: {:#x}\nLength: {:#x}", base_address, length);
+                    let resolution = "No firmware volume was captured at this base address.".to_string();
+                    vec![row_num, hob_column, resolution]
+                }
             },
         }
     }
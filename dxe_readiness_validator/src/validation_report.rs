@@ -6,22 +6,447 @@
 //!
 //! SPDX-License-Identifier: Apache-2.0
 //!
-use crate::validation_kind::PrettyPrintTable;
+use crate::{
+    ValidationAppError,
+    commandline::OutputFormat,
+    errors::ViolationCount,
+    validation_kind::{KindFilter, PrettyPrintTable, Severity},
+};
 use colored::*;
 use comfy_table::{ContentArrangement, Table, presets::UTF8_FULL};
-use std::collections::BTreeMap;
+use dxe_readiness_capture::CaptureMetadata;
+use serde::Serialize;
+use serde_json::{Map, Value, json};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    io::{self, Write},
+    mem,
+};
 
 use crate::validation_kind::ValidationKind;
 
+/// Version of the document produced by `ValidationReport::to_json`. Bump this
+/// whenever a field is renamed, removed or changes meaning.
+pub const JSON_SCHEMA_VERSION: u32 = 1;
+
+/// Version of the document produced by `manifest_document`. Bump this
+/// whenever a field is renamed, removed or changes meaning.
+pub const MANIFEST_SCHEMA_VERSION: u32 = 1;
+
+/// Header row of the CSV output, written once ahead of the rows of every
+/// capture. Columns are only ever appended so spreadsheets keep working.
+pub const CSV_HEADER: &str = "capture_file,name,header,details,fingerprint";
+
+/// SARIF specification version produced by `ValidationReport::to_sarif`.
+const SARIF_VERSION: &str = "2.1.0";
+
+/// Key of `ValidationKind::fingerprint` in the `fingerprints` of SARIF results.
+const SARIF_FINGERPRINT_KEY: &str = "dxeReadiness/v1";
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Creates a table in the style shared by every text report. Styling follows
+/// the same color decision as the `colored` output.
+pub(crate) fn new_table(header: Vec<&str>) -> Table {
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL).set_content_arrangement(ContentArrangement::Dynamic).set_header(header);
+    if !colored::control::SHOULD_COLORIZE.should_colorize() {
+        // Keep wrapping to the terminal width, if any
+        let width = table.width();
+        table.force_no_tty();
+        if let Some(width) = width {
+            table.set_width(width);
+        }
+    }
+    table
+}
+
+/// Set of violation fingerprints that have been explicitly accepted and must
+/// not count as failures. The file lists one fingerprint per line; blank lines
+/// and lines starting with `#` are ignored.
+#[derive(Debug, Default, Clone)]
+pub struct Baseline {
+    fingerprints: BTreeSet<String>,
+}
+
+impl Baseline {
+    pub fn load(path: &str) -> Result<Self, ValidationAppError> {
+        let content = fs::read_to_string(path)
+            .map_err(|err| ValidationAppError::BaselineFileError(path.to_string(), err.to_string()))?;
+        Ok(Self::parse(&content))
+    }
+
+    pub fn parse(content: &str) -> Self {
+        let fingerprints = content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(str::to_string)
+            .collect();
+        Self { fingerprints }
+    }
+
+    pub fn contains(&self, fingerprint: &str) -> bool {
+        self.fingerprints.contains(fingerprint)
+    }
+}
+
+/// Stop condition of `--fail-fast`: a violation at or above the minimum
+/// severity that is not accepted by the baseline.
+#[derive(Debug, Clone)]
+pub struct FailFast {
+    min_severity: Severity,
+    baseline: Option<Baseline>,
+}
+
+impl FailFast {
+    pub fn new(min_severity: Severity, baseline: Option<Baseline>) -> Self {
+        Self { min_severity, baseline }
+    }
+
+    /// Returns true when the report holds a violation the validation stops at.
+    pub fn is_met(&self, report: &ValidationReport) -> bool {
+        report.iter_violations().any(|(_, violation)| {
+            violation.severity() >= self.min_severity
+                && !self.baseline.as_ref().is_some_and(|baseline| baseline.contains(&violation.fingerprint()))
+        })
+    }
+}
+
+/// Wraps the `<testsuite>` elements produced by `ValidationReport::to_junit`
+/// into a complete JUnit XML document.
+pub fn junit_document(suites: &[String]) -> String {
+    let mut document = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    document.push_str(&format!("<testsuites name=\"{}\">\n", env!("CARGO_PKG_NAME")));
+    for suite in suites {
+        document.push_str(suite);
+    }
+    document.push_str("</testsuites>\n");
+    document
+}
+
+/// Builds the manifest written by `--manifest` from the result of every
+/// capture file, `None` standing for a file that could not be parsed or
+/// validated. Version 1 of the manifest has the following shape, with one
+/// entry per capture file keyed by its path:
+///
+/// ```json
+/// {
+///   "schema_version": 1,
+///   "files": {
+///     "platform_a.json": {
+///       "status": "pass", "violation_count": 0, "hob_violations": 0, "fv_violations": 0, "cross_violations": 0
+///     },
+///     "platform_b.json": {
+///       "status": "fail", "violation_count": 3, "hob_violations": 1, "fv_violations": 2, "cross_violations": 0
+///     },
+///     "platform_c.json": {
+///       "status": "error", "violation_count": null, "hob_violations": null, "fv_violations": null,
+///       "cross_violations": null
+///     }
+///   }
+/// }
+/// ```
+///
+/// Only violations at or above `--min-severity` are counted. `status` is
+/// `pass` without violations, `fail` with violations and `error` when the
+/// file could not be parsed or validated.
+pub fn manifest_document(file_results: &[(String, Option<ViolationCount>)]) -> Value {
+    let files: Map<String, Value> = file_results
+        .iter()
+        .map(|(filename, count)| {
+            let entry = match count {
+                Some(count) => json!({
+                    "status": if count.total() == 0 { "pass" } else { "fail" },
+                    "violation_count": count.total(),
+                    "hob_violations": count.hob,
+                    "fv_violations": count.fv,
+                    "cross_violations": count.cross,
+                }),
+                None => json!({
+                    "status": "error",
+                    "violation_count": null,
+                    "hob_violations": null,
+                    "fv_violations": null,
+                    "cross_violations": null,
+                }),
+            };
+            (filename.clone(), entry)
+        })
+        .collect();
+
+    json!({
+        "schema_version": MANIFEST_SCHEMA_VERSION,
+        "files": files,
+    })
+}
+
+/// Escapes a Markdown table cell so pipes and line breaks do not end the cell
+/// or the row early.
+fn markdown_escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('|', "\\|").replace("\r\n", "<br>").replace('\n', "<br>")
+}
+
+/// Renders a GitHub flavored Markdown table.
+fn markdown_table(header: &[&str], rows: &[Vec<String>]) -> String {
+    let mut table =
+        format!("| {} |\n", header.iter().map(|column| markdown_escape(column)).collect::<Vec<_>>().join(" | "));
+    table.push_str(&format!("|{}\n", " --- |".repeat(header.len())));
+    for row in rows {
+        table.push_str(&format!(
+            "| {} |\n",
+            row.iter().map(|cell| markdown_escape(cell)).collect::<Vec<_>>().join(" | ")
+        ));
+    }
+    table
+}
+
+/// Escapes the characters that are not allowed verbatim in XML text and
+/// attribute values.
+fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Quotes a CSV field when it holds a separator, a quote or a line break, as
+/// described in RFC 4180.
+fn csv_escape(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) { format!("\"{}\"", text.replace('"', "\"\"")) } else { text.to_string() }
+}
+
+/// Inline style sheet of the HTML report, so the page has no external assets.
+const HTML_STYLE: &str = "body { font-family: sans-serif; margin: 2em; }
+table { border-collapse: collapse; margin: 1em 0; }
+th, td { border: 1px solid #ccc; padding: 4px 8px; text-align: left; vertical-align: top; }
+td { font-family: monospace; white-space: pre-wrap; }
+th { background: #f0f0f0; }
+details { margin: 1em 0; }
+summary { cursor: pointer; font-weight: bold; }
+.note { color: #8a6d00; }
+.guidance { color: #1f4e8c; white-space: pre-line; }
+";
+
+/// Renders an HTML table with every header and cell value escaped.
+fn html_table(header: &[&str], rows: &[Vec<String>]) -> String {
+    let mut table = String::from("<table>\n<tr>");
+    for column in header {
+        table.push_str(&format!("<th>{}</th>", xml_escape(column)));
+    }
+    table.push_str("</tr>\n");
+    for row in rows {
+        table.push_str("<tr>");
+        for cell in row {
+            table.push_str(&format!("<td>{}</td>", xml_escape(cell)));
+        }
+        table.push_str("</tr>\n");
+    }
+    table.push_str("</table>\n");
+    table
+}
+
+/// Returns the fields of the capture metadata as (name, value) rows.
+fn metadata_rows(metadata: &CaptureMetadata) -> [(&str, &str); 5] {
+    [
+        ("Platform", &metadata.platform_name),
+        ("Architecture", &metadata.target_arch),
+        ("Tool Version", &metadata.tool_version),
+        ("Captured At", metadata.timestamp.as_deref().unwrap_or("unknown")),
+        ("Boot Mode", metadata.boot_mode.as_deref().unwrap_or("unknown")),
+    ]
+}
+
+/// Number of HOBs of each `HobSerDe` variant present in a capture.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct HobSummary {
+    pub handoff: usize,
+    pub memory_allocation: usize,
+    pub resource_descriptor: usize,
+    pub resource_descriptor_v2: usize,
+    pub guid_extension: usize,
+    pub memory_type_information: usize,
+    pub firmware_volume: usize,
+    pub cpu: usize,
+    pub unknown: usize,
+}
+
+impl HobSummary {
+    fn rows(&self) -> [(&str, usize); 9] {
+        [
+            ("Handoff", self.handoff),
+            ("Memory Allocation", self.memory_allocation),
+            ("Resource Descriptor", self.resource_descriptor),
+            ("Resource Descriptor V2", self.resource_descriptor_v2),
+            ("GUID Extension", self.guid_extension),
+            ("Memory Type Information", self.memory_type_information),
+            ("Firmware Volume", self.firmware_volume),
+            ("CPU", self.cpu),
+            ("Unknown", self.unknown),
+        ]
+    }
+
+    fn pretty_print(&self, out: &mut impl Write) -> io::Result<()> {
+        writeln!(out, "{}", "HOB Summary:".bold())?;
+        let mut table = new_table(vec!["HOB Type", "Count"]);
+        for (hob_type, count) in self.rows() {
+            table.add_row(vec![hob_type.to_string(), count.to_string()]);
+        }
+        writeln!(out, "{table}")
+    }
+}
+
+/// Amount of memory declared by the resource descriptors of a capture, in
+/// bytes. Overlapping and duplicated descriptors, such as V1 and V2 HOBs for
+/// the same range, are only counted once.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct MemoryFootprint {
+    pub system_memory: u64,
+    pub reserved_memory: u64,
+    /// Start of the largest range of system memory not covered by a memory
+    /// allocation HOB
+    pub largest_free_region_start: u64,
+    pub largest_free_region_length: u64,
+}
+
+impl MemoryFootprint {
+    fn rows(&self) -> [(&str, String); 3] {
+        [
+            ("System Memory", format_size(self.system_memory)),
+            ("Reserved Memory", format_size(self.reserved_memory)),
+            (
+                "Largest Free Region",
+                format!("{} at 0x{:X}", format_size(self.largest_free_region_length), self.largest_free_region_start),
+            ),
+        ]
+    }
+
+    fn pretty_print(&self, out: &mut impl Write) -> io::Result<()> {
+        writeln!(out, "{}", "Memory Footprint:".bold())?;
+        let mut table = new_table(vec!["Memory", "Size"]);
+        for (memory, size) in self.rows() {
+            table.add_row(vec![memory.to_string(), size]);
+        }
+        writeln!(out, "{table}")
+    }
+}
+
+/// Formats a number of bytes as hex followed by the size in MiB, e.g.
+/// `0x7F800000 (2040 MiB)`.
+fn format_size(bytes: u64) -> String {
+    format!("0x{:X} ({} MiB)", bytes, bytes >> 20)
+}
+
+/// Penalty points of one validation kind in a `ReadinessScore`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ScoreContribution {
+    pub name: String,
+    pub severity: Severity,
+    pub count: usize,
+    pub penalty: u64,
+}
+
+/// Weighted readiness score of a capture, a single number for tracking a
+/// platform over a bring-up cycle rather than for gating.
+///
+/// Every violation costs the `Severity::score_weight` of its kind: 1 for Info,
+/// 3 for Warning and 10 for Error. The score is `100 * 100 / (100 + penalty)`
+/// percent, rounded to one decimal: 100% without violations and 50% at 100
+/// penalty points. Violations suppressed by a baseline do not count.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ReadinessScore {
+    pub percent: f64,
+    pub penalty: u64,
+    /// Contribution of each validation kind, largest penalty first
+    pub contributions: Vec<ScoreContribution>,
+}
+
+impl ReadinessScore {
+    fn pretty_print(&self, out: &mut impl Write) -> io::Result<()> {
+        writeln!(out, "{}", format!("Readiness Score: {:.1}% ({} penalty points)", self.percent, self.penalty).bold())?;
+        if self.contributions.is_empty() {
+            return Ok(());
+        }
+        let mut table = new_table(vec!["Validation Kind", "Severity", "Count", "Penalty"]);
+        for contribution in &self.contributions {
+            table.add_row(vec![
+                contribution.name.clone(),
+                contribution.severity.to_string(),
+                contribution.count.to_string(),
+                contribution.penalty.to_string(),
+            ]);
+        }
+        writeln!(out, "{table}")
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct ValidationReport<'a> {
     // Report is a BTreeMap of Group name and list of violations
     report: BTreeMap<String, Vec<ValidationKind<'a>>>,
+    // Informational HOB counts, printed ahead of the violations
+    hob_summary: Option<HobSummary>,
+    // Informational memory totals, printed ahead of the violations
+    memory_footprint: Option<MemoryFootprint>,
+    // Path of the capture file the report was produced from
+    capture_file: Option<String>,
+    // Metadata recorded by the capture tool, printed as the report header
+    metadata: Option<CaptureMetadata>,
+    // Violations matched by the baseline, excluded from the violation count
+    suppressed: Vec<ValidationKind<'a>>,
+    // Informational notes about the capture, such as an empty HOB or FV list
+    notes: Vec<String>,
+    // Set by --score, the readiness score is added to the text and JSON output
+    show_score: bool,
+    // Set by --explain, the involved HOBs/files are printed below each table
+    explain: bool,
 }
 
 impl<'a> ValidationReport<'a> {
     pub fn new() -> Self {
-        Self { report: BTreeMap::new() }
+        Self {
+            report: BTreeMap::new(),
+            hob_summary: None,
+            memory_footprint: None,
+            capture_file: None,
+            metadata: None,
+            suppressed: Vec::new(),
+            notes: Vec::new(),
+            show_score: false,
+            explain: false,
+        }
+    }
+
+    pub fn set_capture_file(&mut self, capture_file: &str) {
+        self.capture_file = Some(capture_file.to_string());
+    }
+
+    pub fn set_metadata(&mut self, metadata: Option<&CaptureMetadata>) {
+        self.metadata = metadata.cloned();
+    }
+
+    pub fn set_hob_summary(&mut self, hob_summary: HobSummary) {
+        self.hob_summary = Some(hob_summary);
+    }
+
+    pub fn set_memory_footprint(&mut self, memory_footprint: MemoryFootprint) {
+        self.memory_footprint = Some(memory_footprint);
+    }
+
+    pub fn set_show_score(&mut self, show_score: bool) {
+        self.show_score = show_score;
+    }
+
+    pub fn set_explain(&mut self, explain: bool) {
+        self.explain = explain;
     }
 
     pub fn add_violation(&mut self, validation: ValidationKind<'a>) {
@@ -29,43 +454,1050 @@ impl<'a> ValidationReport<'a> {
         self.report.entry(group_name).or_default().push(validation);
     }
 
+    /// Adds a note that is shown with the results but is not a violation.
+    pub fn add_note(&mut self, note: String) {
+        self.notes.push(note);
+    }
+
+    pub fn notes(&self) -> &[String] {
+        &self.notes
+    }
+
     pub fn append_report(&mut self, mut validation_report: ValidationReport<'a>) {
         self.report.append(&mut validation_report.report);
+        if validation_report.hob_summary.is_some() {
+            self.hob_summary = validation_report.hob_summary;
+        }
+        if validation_report.memory_footprint.is_some() {
+            self.memory_footprint = validation_report.memory_footprint;
+        }
+        if validation_report.capture_file.is_some() {
+            self.capture_file = validation_report.capture_file;
+        }
+        if validation_report.metadata.is_some() {
+            self.metadata = validation_report.metadata;
+        }
+        self.suppressed.append(&mut validation_report.suppressed);
+        self.notes.append(&mut validation_report.notes);
+    }
+
+    /// Drops the violations of every kind the filter does not allow.
+    pub fn retain_kinds(&mut self, filter: &KindFilter) {
+        self.report.retain(|group_name, _| filter.allows(group_name));
+    }
+
+    /// Moves every violation whose fingerprint is listed in the baseline out
+    /// of the report and into the suppressed list.
+    pub fn apply_baseline(&mut self, baseline: &Baseline) {
+        for violations in self.report.values_mut() {
+            let (suppressed, kept): (Vec<_>, Vec<_>) =
+                mem::take(violations).into_iter().partition(|violation| baseline.contains(&violation.fingerprint()));
+            *violations = kept;
+            self.suppressed.extend(suppressed);
+        }
+        self.report.retain(|_, violations| !violations.is_empty());
+    }
+
+    /// Names of the validation kinds that have at least one violation, in
+    /// report order.
+    pub fn groups(&self) -> impl Iterator<Item = &str> {
+        self.report.keys().map(String::as_str)
+    }
+
+    /// Every violation in the report together with the name of its group, in
+    /// report order. Violations suppressed by a baseline are not included.
+    pub fn iter_violations(&self) -> impl Iterator<Item = (&str, &ValidationKind<'a>)> {
+        self.report.iter().flat_map(|(group_name, violations)| {
+            violations.iter().map(move |violation| (group_name.as_str(), violation))
+        })
+    }
+
+    pub fn suppressed_count(&self) -> usize {
+        self.suppressed.len()
     }
 
     pub fn violation_count(&self) -> usize {
         self.report.values().map(Vec::len).sum()
     }
 
-    pub fn show_results(&self) {
+    /// Number of HOB, FV and cross violations at or above `min_severity`.
+    pub fn violation_counts_at(&self, min_severity: Severity) -> ViolationCount {
+        let mut count = ViolationCount::default();
+        for violation in self.report.values().flatten().filter(|violation| violation.severity() >= min_severity) {
+            match violation {
+                ValidationKind::Hob(_) => count.hob += 1,
+                ValidationKind::Fv(_) => count.fv += 1,
+                ValidationKind::Cross(_) => count.cross += 1,
+            }
+        }
+        count
+    }
+
+    /// Computes the weighted readiness score of the violations in the report.
+    pub fn readiness_score(&self) -> ReadinessScore {
+        let mut contributions: Vec<ScoreContribution> = self
+            .report
+            .iter()
+            .filter_map(|(group_name, violations)| {
+                let severity = violations.first()?.severity();
+                Some(ScoreContribution {
+                    name: group_name.clone(),
+                    severity,
+                    count: violations.len(),
+                    penalty: severity.score_weight() * violations.len() as u64,
+                })
+            })
+            .collect();
+        contributions.sort_by(|a, b| b.penalty.cmp(&a.penalty).then_with(|| a.name.cmp(&b.name)));
+
+        let penalty: u64 = contributions.iter().map(|contribution| contribution.penalty).sum();
+        let percent = (100_000.0 / (100.0 + penalty as f64)).round() / 10.0;
+        ReadinessScore { percent, penalty, contributions }
+    }
+
+    /// Writes the results in the requested format to `out`. With
+    /// `summary_only`, the text output only lists the violation count of each
+    /// group instead of the full tables.
+    pub fn show_results(&self, output: OutputFormat, summary_only: bool, out: &mut impl Write) -> io::Result<()> {
+        match output {
+            OutputFormat::Json => return writeln!(out, "{}", self.to_json()),
+            OutputFormat::Sarif => return writeln!(out, "{}", self.to_sarif()),
+            // Written by the app once every capture has been validated
+            OutputFormat::Junit => return Ok(()),
+            OutputFormat::Markdown => return write!(out, "{}", self.to_markdown()),
+            OutputFormat::Html => return write!(out, "{}", self.to_html()),
+            OutputFormat::Csv => return write!(out, "{}", self.to_csv()),
+            OutputFormat::Text if summary_only => return self.print_summary(out),
+            OutputFormat::Text => {}
+        }
+
+        if let Some(metadata) = &self.metadata {
+            writeln!(out, "{}", "Capture Metadata:".bold())?;
+            let mut table = new_table(vec!["Field", "Value"]);
+            for (field, value) in metadata_rows(metadata) {
+                table.add_row(vec![field, value]);
+            }
+            writeln!(out, "{table}")?;
+        }
+
+        if let Some(hob_summary) = &self.hob_summary {
+            hob_summary.pretty_print(out)?;
+        }
+
+        if let Some(memory_footprint) = &self.memory_footprint {
+            memory_footprint.pretty_print(out)?;
+        }
+
+        if self.show_score {
+            self.readiness_score().pretty_print(out)?;
+        }
+
+        for note in &self.notes {
+            writeln!(out, "{}", format!("Note: {}", note).yellow())?;
+        }
+
         if self.report.is_empty() {
-            println!("No violations found.");
+            writeln!(out, "No violations found.")?;
         } else {
-            self.pretty_print();
+            self.pretty_print(out)?;
         }
+
+        if !self.suppressed.is_empty() {
+            writeln!(
+                out,
+                "{}",
+                format!("{} violation(s) suppressed by the baseline:", self.suppressed_count()).yellow()
+            )?;
+            for violation in &self.suppressed {
+                writeln!(out, "   {}", violation.fingerprint())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Serializes the report into a JSON document. Each violation carries its
+    /// `name()`, `header()` and `guidance()` along with a `details` object
+    /// keyed by the table column names. Columns holding serialized HOB/FV
+    /// structures are embedded as JSON rather than as strings.
+    pub fn to_json(&self) -> Value {
+        let violations: Vec<Value> = self
+            .report
+            .values()
+            .flatten()
+            .map(|violation| {
+                json!({
+                    "name": violation.name(),
+                    "fingerprint": violation.fingerprint(),
+                    "severity": violation.severity(),
+                    "header": violation.header(),
+                    "guidance": violation.guidance(),
+                    "details": violation.details(),
+                })
+            })
+            .collect();
+        let suppressed: Vec<String> = self.suppressed.iter().map(ValidationKind::fingerprint).collect();
+
+        json!({
+            "schema_version": JSON_SCHEMA_VERSION,
+            "capture_file": self.capture_file,
+            "metadata": self.metadata,
+            "hob_summary": self.hob_summary,
+            "memory_footprint": self.memory_footprint,
+            "score": self.show_score.then(|| self.readiness_score()),
+            "notes": self.notes,
+            "violation_count": self.violation_count(),
+            "violations": violations,
+            "suppressed_count": self.suppressed_count(),
+            "suppressed": suppressed,
+        })
+    }
+
+    /// Serializes the report into a SARIF 2.1.0 log with a single run. Every
+    /// violation group becomes a rule keyed by `name()` and every violation a
+    /// result. Captures have no line numbers, so results point at the capture
+    /// file with a synthetic region on its first line.
+    pub fn to_sarif(&self) -> Value {
+        let rules: Vec<Value> = self
+            .report
+            .values()
+            .filter_map(|violations| violations.first())
+            .map(|violation| {
+                json!({
+                    "id": violation.name(),
+                    "shortDescription": { "text": violation.header() },
+                    "help": { "text": violation.guidance() },
+                    "defaultConfiguration": { "level": violation.severity().sarif_level() },
+                })
+            })
+            .collect();
+
+        let artifact_uri = self.capture_file.as_deref().unwrap_or("capture.json");
+        let results: Vec<Value> = self
+            .report
+            .values()
+            .flatten()
+            .map(|violation| {
+                json!({
+                    "ruleId": violation.name(),
+                    "level": violation.severity().sarif_level(),
+                    "message": { "text": violation.header() },
+                    "locations": [{
+                        "physicalLocation": {
+                            "artifactLocation": { "uri": artifact_uri },
+                            "region": { "startLine": 1 },
+                        }
+                    }],
+                    "fingerprints": { SARIF_FINGERPRINT_KEY: violation.fingerprint() },
+                    "properties": { "details": violation.details() },
+                })
+            })
+            .collect();
+
+        json!({
+            "$schema": SARIF_SCHEMA,
+            "version": SARIF_VERSION,
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": env!("CARGO_PKG_NAME"),
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "informationUri": env!("CARGO_PKG_REPOSITORY"),
+                        "rules": rules,
+                    }
+                },
+                "artifacts": [{ "location": { "uri": artifact_uri } }],
+                "results": results,
+            }],
+        })
     }
 
-    fn pretty_print(&self) {
-        println!("{}", "Validation Results:".red().bold());
+    /// Renders the report as GitHub flavored Markdown. Every violation group is
+    /// a `###` section holding the same table as the text output followed by
+    /// the guidance as a block quote.
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::new();
+        if let Some(capture_file) = &self.capture_file {
+            markdown.push_str(&format!("## Capture: `{}`\n\n", capture_file));
+        }
+
+        if let Some(metadata) = &self.metadata {
+            let rows: Vec<Vec<String>> = metadata_rows(metadata)
+                .into_iter()
+                .map(|(field, value)| vec![field.to_string(), value.to_string()])
+                .collect();
+            markdown.push_str("### Capture Metadata\n\n");
+            markdown.push_str(&markdown_table(&["Field", "Value"], &rows));
+            markdown.push('\n');
+        }
+
+        if let Some(hob_summary) = &self.hob_summary {
+            let rows: Vec<Vec<String>> = hob_summary
+                .rows()
+                .into_iter()
+                .map(|(hob_type, count)| vec![hob_type.to_string(), count.to_string()])
+                .collect();
+            markdown.push_str("### HOB Summary\n\n");
+            markdown.push_str(&markdown_table(&["HOB Type", "Count"], &rows));
+            markdown.push('\n');
+        }
+
+        if let Some(memory_footprint) = &self.memory_footprint {
+            let rows: Vec<Vec<String>> =
+                memory_footprint.rows().into_iter().map(|(memory, size)| vec![memory.to_string(), size]).collect();
+            markdown.push_str("### Memory Footprint\n\n");
+            markdown.push_str(&markdown_table(&["Memory", "Size"], &rows));
+            markdown.push('\n');
+        }
+
+        for note in &self.notes {
+            markdown.push_str(&format!("> **Note:** {}\n\n", markdown_escape(note)));
+        }
+
+        if !self.suppressed.is_empty() {
+            markdown.push_str(&format!("_{} violation(s) suppressed by the baseline._\n\n", self.suppressed_count()));
+        }
+
+        if self.report.is_empty() {
+            markdown.push_str("No violations found.\n");
+            return markdown;
+        }
+
+        for violations in self.report.values() {
+            let Some(first) = violations.first() else {
+                continue;
+            };
+
+            let rows: Vec<Vec<String>> =
+                violations.iter().enumerate().map(|(j, violation)| violation.table_row((j + 1).to_string())).collect();
+            markdown.push_str(&format!("### ❌ [{}] {}\n\n", first.severity(), first.header()));
+            markdown.push_str(&markdown_table(&first.table_header(), &rows));
+            markdown.push_str("\n> 💡 **Guidance:**\n");
+            for line in first.guidance().lines() {
+                markdown.push_str(&format!("> {}\n", line.trim()));
+            }
+            markdown.push('\n');
+        }
+        markdown
+    }
+
+    /// Renders the report as a self-contained HTML page. A summary table of the
+    /// violation count of each group comes first, followed by one collapsible
+    /// `<details>` block per group holding the same table as the text output
+    /// and the guidance.
+    pub fn to_html(&self) -> String {
+        let title = match &self.capture_file {
+            Some(capture_file) => format!("DXE Readiness Report: {}", capture_file),
+            None => "DXE Readiness Report".to_string(),
+        };
+
+        let mut html = String::from("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+        html.push_str(&format!(
+            "<title>{}</title>\n<style>\n{}</style>\n</head>\n<body>\n",
+            xml_escape(&title),
+            HTML_STYLE
+        ));
+        html.push_str(&format!("<h1>{}</h1>\n", xml_escape(&title)));
+
+        if let Some(metadata) = &self.metadata {
+            let rows: Vec<Vec<String>> = metadata_rows(metadata)
+                .into_iter()
+                .map(|(field, value)| vec![field.to_string(), value.to_string()])
+                .collect();
+            html.push_str("<h2>Capture Metadata</h2>\n");
+            html.push_str(&html_table(&["Field", "Value"], &rows));
+        }
+
+        if let Some(hob_summary) = &self.hob_summary {
+            let rows: Vec<Vec<String>> = hob_summary
+                .rows()
+                .into_iter()
+                .map(|(hob_type, count)| vec![hob_type.to_string(), count.to_string()])
+                .collect();
+            html.push_str("<h2>HOB Summary</h2>\n");
+            html.push_str(&html_table(&["HOB Type", "Count"], &rows));
+        }
+
+        if let Some(memory_footprint) = &self.memory_footprint {
+            let rows: Vec<Vec<String>> =
+                memory_footprint.rows().into_iter().map(|(memory, size)| vec![memory.to_string(), size]).collect();
+            html.push_str("<h2>Memory Footprint</h2>\n");
+            html.push_str(&html_table(&["Memory", "Size"], &rows));
+        }
+
+        for note in &self.notes {
+            html.push_str(&format!("<p class=\"note\"><strong>Note:</strong> {}</p>\n", xml_escape(note)));
+        }
+
+        html.push_str("<h2>Summary</h2>\n");
+        let mut rows: Vec<Vec<String>> = self
+            .report
+            .iter()
+            .filter_map(|(group_name, violations)| {
+                let first = violations.first()?;
+                Some(vec![group_name.clone(), first.severity().to_string(), violations.len().to_string()])
+            })
+            .collect();
+        if !self.suppressed.is_empty() {
+            rows.push(vec![
+                "Suppressed by the baseline".to_string(),
+                String::new(),
+                self.suppressed_count().to_string(),
+            ]);
+        }
+        rows.push(vec!["Total".to_string(), String::new(), self.violation_count().to_string()]);
+        html.push_str(&html_table(&["Violation", "Severity", "Count"], &rows));
+
+        if self.report.is_empty() {
+            html.push_str("<p>No violations found.</p>\n");
+        }
+
+        for violations in self.report.values() {
+            let Some(first) = violations.first() else {
+                continue;
+            };
+
+            let rows: Vec<Vec<String>> =
+                violations.iter().enumerate().map(|(j, violation)| violation.table_row((j + 1).to_string())).collect();
+            let guidance = first.guidance().lines().map(str::trim).collect::<Vec<_>>().join("\n");
+            html.push_str(&format!(
+                "<details>\n<summary>[{}] {} ({})</summary>\n",
+                first.severity(),
+                xml_escape(first.header()),
+                violations.len()
+            ));
+            html.push_str(&html_table(&first.table_header(), &rows));
+            html.push_str(&format!(
+                "<p class=\"guidance\"><strong>Guidance:</strong>\n{}</p>\n</details>\n",
+                xml_escape(&guidance)
+            ));
+        }
+
+        html.push_str("</body>\n</html>\n");
+        html
+    }
+
+    /// Serializes the report into CSV rows matching `CSV_HEADER`, one per
+    /// violation. The details column joins the table cells of the violation,
+    /// without the row number, so a row does not change when other violations
+    /// come and go.
+    pub fn to_csv(&self) -> String {
+        let capture_file = self.capture_file.as_deref().unwrap_or("");
+        let mut csv = String::new();
+        for violation in self.report.values().flatten() {
+            let details = violation.table_row(String::new()).into_iter().skip(1).collect::<Vec<_>>().join(" | ");
+            csv.push_str(&format!(
+                "{},{},{},{},{}\r\n",
+                csv_escape(capture_file),
+                csv_escape(&violation.name()),
+                csv_escape(violation.header()),
+                csv_escape(&details),
+                csv_escape(&violation.fingerprint())
+            ));
+        }
+        csv
+    }
+
+    /// Serializes the report into JUnit `<testsuite>` elements, one per
+    /// violation group with a failing `<testcase>` per violation. The failure
+    /// body is the offending HOB/FV. A report without violations produces a
+    /// single passing test case so that dashboards still record the capture.
+    pub fn to_junit(&self) -> String {
+        let classname = xml_escape(self.capture_file.as_deref().unwrap_or("capture.json"));
+
+        if self.report.is_empty() {
+            return format!(
+                "  <testsuite name=\"{0}\" tests=\"1\" failures=\"0\">\n    <testcase classname=\"{0}\" name=\"DXE Readiness\"/>\n  </testsuite>\n",
+                classname
+            );
+        }
+
+        let mut suites = String::new();
+        for (group_name, violations) in &self.report {
+            let group_name = xml_escape(group_name);
+            suites.push_str(&format!(
+                "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\">\n",
+                group_name,
+                violations.len(),
+                violations.len()
+            ));
+            for (j, violation) in violations.iter().enumerate() {
+                let guidance = violation.guidance().lines().map(str::trim).collect::<Vec<_>>().join(" ");
+                let message = format!("{} {}", violation.header(), guidance);
+                let body = serde_json::to_string_pretty(&violation.details())
+                    .unwrap_or("violation serialization failed!".to_string());
+                suites.push_str(&format!(
+                    "    <testcase classname=\"{}\" name=\"{} #{}\">\n      <failure message=\"{}\" type=\"{}\">{}</failure>\n    </testcase>\n",
+                    classname,
+                    group_name,
+                    j + 1,
+                    xml_escape(&message),
+                    group_name,
+                    xml_escape(&body)
+                ));
+            }
+            suites.push_str("  </testsuite>\n");
+        }
+        suites
+    }
+
+    /// Prints one line per violation group with its count, followed by the
+    /// total.
+    fn print_summary(&self, out: &mut impl Write) -> io::Result<()> {
+        for (group_name, violations) in &self.report {
+            writeln!(out, "{}: {}", group_name, violations.len())?;
+        }
+
+        if !self.suppressed.is_empty() {
+            writeln!(out, "{}", format!("Suppressed by the baseline: {}", self.suppressed_count()).yellow())?;
+        }
+        writeln!(out, "{}", format!("Total: {}", self.violation_count()).bold())?;
+        if self.show_score {
+            writeln!(out, "{}", format!("Readiness Score: {:.1}%", self.readiness_score().percent).bold())?;
+        }
+        Ok(())
+    }
+
+    fn pretty_print(&self, out: &mut impl Write) -> io::Result<()> {
+        writeln!(out, "{}", "Validation Results:".red().bold())?;
         for violations in self.report.values() {
             if violations.is_empty() {
                 continue;
             }
 
-            println!("──────────────────────────────────────────────────────────────────");
-            println!("❌ {}", violations.first().unwrap().header().green().bold());
-            let mut table = Table::new();
-            table
-                .load_preset(UTF8_FULL)
-                .set_content_arrangement(ContentArrangement::Dynamic)
-                .set_header(violations.first().unwrap().table_header());
+            writeln!(out, "──────────────────────────────────────────────────────────────────")?;
+            let first = violations.first().unwrap();
+            writeln!(out, "❌ [{}] {}", first.severity(), first.header().green().bold())?;
+            let mut table = new_table(first.table_header());
 
             for (j, violation) in violations.iter().enumerate() {
                 table.add_row(violation.table_row((j + 1).to_string()));
             }
 
-            println!("{table}");
-            println!("💡 {}", format!("Guidance:\n{}", violations.first().unwrap().guidance()).blue().bold());
+            writeln!(out, "{table}")?;
+            if self.explain {
+                for (j, violation) in violations.iter().enumerate() {
+                    let explanation = serde_json::to_string_pretty(&violation.explain())
+                        .unwrap_or("explanation serialization failed!".to_string());
+                    writeln!(out, "🔍 {}", format!("Explain #{}:", j + 1).bold())?;
+                    writeln!(out, "{explanation}")?;
+                }
+            }
+            writeln!(out, "💡 {}", format!("Guidance:\n{}", violations.first().unwrap().guidance()).blue().bold())?;
         }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ValidationAppError,
+        validation_kind::{HobValidationKind, VALIDATION_KIND_NAMES},
+    };
+    use dxe_readiness_capture::fnv1a_64;
+    use patina::pi::serializable::serializable_hob::ResourceDescriptorSerDe;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn test_to_json_schema() {
+        let hob = ResourceDescriptorSerDe {
+            owner: "00000000-0000-0000-0000-000000000000".to_string(),
+            resource_type: 0,
+            resource_attribute: 0,
+            physical_start: 0x1000,
+            resource_length: 0x1000,
+        };
+
+        let mut report = ValidationReport::new();
+        report.set_capture_file("capture.json");
+        report.set_hob_summary(HobSummary { resource_descriptor: 1, ..Default::default() });
+        report.set_memory_footprint(MemoryFootprint { system_memory: 0x1000, ..Default::default() });
+        report.add_violation(ValidationKind::Hob(HobValidationKind::V1MemoryRangeNotContainedInV2 { hob1: &hob }));
+
+        let json = report.to_json();
+        assert_eq!(json["schema_version"], JSON_SCHEMA_VERSION);
+        assert_eq!(json["capture_file"], "capture.json");
+        assert_eq!(json["hob_summary"]["resource_descriptor"], 1);
+        assert_eq!(json["memory_footprint"]["system_memory"], 0x1000);
+        assert_eq!(json["violation_count"], 1);
+
+        let violation = &json["violations"][0];
+        assert_eq!(
+            violation["name"],
+            ValidationKind::Hob(HobValidationKind::V1MemoryRangeNotContainedInV2 { hob1: &hob }).name()
+        );
+        assert!(violation["header"].is_string());
+        assert!(violation["guidance"].is_string());
+        // Serialized HOB columns are embedded as objects, not strings
+        assert!(violation["details"]["V1 Hob"].is_object());
+        assert!(violation["details"].get("#").is_none());
+    }
+
+    #[test]
+    fn test_to_sarif() {
+        let hob = ResourceDescriptorSerDe {
+            owner: "00000000-0000-0000-0000-000000000000".to_string(),
+            resource_type: 0,
+            resource_attribute: 0,
+            physical_start: 0x1000,
+            resource_length: 0x1000,
+        };
+
+        let mut report = ValidationReport::new();
+        report.set_capture_file("capture.json");
+        report.add_violation(ValidationKind::Hob(HobValidationKind::V1MemoryRangeNotContainedInV2 { hob1: &hob }));
+        report.add_violation(ValidationKind::Hob(HobValidationKind::ResourceRangeOverflow { hob1: &hob }));
+        report.add_violation(ValidationKind::Hob(HobValidationKind::ResourceRangeOverflow { hob1: &hob }));
+
+        let sarif = report.to_sarif();
+        assert_eq!(sarif["version"], "2.1.0");
+
+        let run = &sarif["runs"][0];
+        let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(rules.len(), 2);
+        assert_eq!(results.len(), 3);
+
+        // Every result must reference one of the declared rules
+        for result in results {
+            assert!(rules.iter().any(|rule| rule["id"] == result["ruleId"]));
+            assert_eq!(result["locations"][0]["physicalLocation"]["artifactLocation"]["uri"], "capture.json");
+        }
+        let fingerprints: Vec<&Value> =
+            results.iter().map(|result| &result["fingerprints"][SARIF_FINGERPRINT_KEY]).collect();
+        assert_eq!(fingerprints[0], fingerprints[1]);
+        assert_ne!(fingerprints[0], fingerprints[2]);
+    }
+
+    #[test]
+    fn test_to_junit() {
+        let hob = ResourceDescriptorSerDe {
+            owner: "00000000-0000-0000-0000-000000000000".to_string(),
+            resource_type: 0,
+            resource_attribute: 0,
+            physical_start: 0x1000,
+            resource_length: 0x1000,
+        };
+
+        let mut report = ValidationReport::new();
+        report.set_capture_file("a&b.json");
+        assert!(report.to_junit().contains("failures=\"0\""));
+
+        report.add_violation(ValidationKind::Hob(HobValidationKind::ResourceRangeOverflow { hob1: &hob }));
+        report.add_violation(ValidationKind::Hob(HobValidationKind::ResourceRangeOverflow { hob1: &hob }));
+
+        let document = junit_document(&[report.to_junit()]);
+        assert!(document.starts_with("<?xml"));
+        assert_eq!(document.matches("<testsuite ").count(), 1);
+        assert_eq!(document.matches("<failure ").count(), 2);
+        assert!(document.contains("classname=\"a&amp;b.json\""));
+        assert!(!document.contains("a&b"));
+    }
+
+    #[test]
+    fn test_to_markdown() {
+        let hob = ResourceDescriptorSerDe {
+            owner: "00000000-0000-0000-0000-000000000000".to_string(),
+            resource_type: 0,
+            resource_attribute: 0,
+            physical_start: 0x1000,
+            resource_length: 0x1000,
+        };
+
+        let mut report = ValidationReport::new();
+        assert!(report.to_markdown().contains("No violations found."));
+
+        report.add_violation(ValidationKind::Hob(HobValidationKind::ResourceRangeOverflow { hob1: &hob }));
+        let markdown = report.to_markdown();
+        assert!(markdown.contains("### ❌ "));
+        assert!(markdown.contains("> 💡 **Guidance:**"));
+
+        // Every table line must have the same number of unescaped pipes
+        let pipes: Vec<usize> = markdown
+            .lines()
+            .filter(|line| line.starts_with('|'))
+            .map(|line| line.replace("\\|", "").matches('|').count())
+            .collect();
+        assert_eq!(pipes.len(), 3);
+        assert!(pipes.iter().all(|count| *count == pipes[0]));
+    }
+
+    #[test]
+    fn test_to_html() {
+        let hob = ResourceDescriptorSerDe {
+            owner: "<owner>".to_string(),
+            resource_type: 0,
+            resource_attribute: 0,
+            physical_start: 0x1000,
+            resource_length: 0x1000,
+        };
+
+        let mut report = ValidationReport::new();
+        report.set_capture_file("a&b.json");
+        let html = report.to_html();
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("No violations found."));
+        assert!(!html.contains("<details>"));
+
+        report.add_violation(ValidationKind::Hob(HobValidationKind::ResourceRangeOverflow { hob1: &hob }));
+        report.add_violation(ValidationKind::Hob(HobValidationKind::ResourceRangeOverflow { hob1: &hob }));
+        let html = report.to_html();
+        assert_eq!(html.matches("<details>").count(), 1);
+        assert!(html.contains("<td>ResourceRangeOverflow</td>"));
+        assert!(html.contains("<td>Total</td><td></td><td>2</td>"));
+        assert!(html.contains("a&amp;b.json"));
+        assert!(!html.contains("a&b"));
+        assert!(!html.contains("<owner>"));
+        assert!(!html.contains("<link") && !html.contains("<script"));
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let hob = ResourceDescriptorSerDe {
+            owner: "00000000-0000-0000-0000-000000000000".to_string(),
+            resource_type: 0,
+            resource_attribute: 0,
+            physical_start: 0x1000,
+            resource_length: 0x1000,
+        };
+
+        let mut report = ValidationReport::new();
+        report.set_capture_file("q35.json");
+        assert!(report.to_csv().is_empty());
+
+        report.add_violation(ValidationKind::Hob(HobValidationKind::ResourceRangeOverflow { hob1: &hob }));
+        report.add_violation(ValidationKind::Hob(HobValidationKind::ResourceRangeOverflow { hob1: &hob }));
+        let csv = report.to_csv();
+        let rows: Vec<&str> = csv.split_terminator("\r\n").collect();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0], rows[1]);
+        assert!(rows[0].starts_with("q35.json,ResourceRangeOverflow,"));
+        assert!(rows[0].ends_with(&format!(
+            ",{}",
+            ValidationKind::Hob(HobValidationKind::ResourceRangeOverflow { hob1: &hob }).fingerprint()
+        )));
+    }
+
+    #[test]
+    fn test_manifest_document() {
+        let manifest = manifest_document(&[
+            ("a.json".to_string(), Some(ViolationCount::default())),
+            ("b.json".to_string(), Some(ViolationCount { hob: 1, fv: 2, cross: 1 })),
+            ("c.json".to_string(), None),
+        ]);
+        assert_eq!(manifest["schema_version"], MANIFEST_SCHEMA_VERSION);
+        assert_eq!(manifest["files"]["a.json"]["status"], "pass");
+        assert_eq!(manifest["files"]["b.json"]["status"], "fail");
+        assert_eq!(manifest["files"]["b.json"]["violation_count"], 4);
+        assert_eq!(manifest["files"]["b.json"]["fv_violations"], 2);
+        assert_eq!(manifest["files"]["b.json"]["cross_violations"], 1);
+        assert_eq!(manifest["files"]["c.json"]["status"], "error");
+        assert!(manifest["files"]["c.json"]["violation_count"].is_null());
+    }
+
+    #[test]
+    fn test_csv_escape() {
+        assert_eq!(csv_escape("plain"), "plain");
+        assert_eq!(csv_escape("a,b"), "\"a,b\"");
+        assert_eq!(csv_escape("say \"hi\"\nbye"), "\"say \"\"hi\"\"\nbye\"");
+    }
+
+    #[test]
+    fn test_markdown_escape() {
+        assert_eq!(markdown_escape("a|b\nc\r\nd"), "a\\|b<br>c<br>d");
+    }
+
+    #[test]
+    fn test_kind_filter() {
+        let filter = KindFilter::new(&[], &[]).unwrap();
+        assert!(VALIDATION_KIND_NAMES.iter().all(|name| filter.allows(name)));
+
+        let filter = KindFilter::new(
+            &["InvalidSectionAlignment".to_string(), "UsesTraditionalSmm".to_string()],
+            &["UsesTraditionalSmm".to_string()],
+        )
+        .unwrap();
+        assert!(filter.allows("InvalidSectionAlignment"));
+        assert!(!filter.allows("UsesTraditionalSmm"));
+        assert!(!filter.allows("OverlappingMemoryRanges"));
+
+        let err = KindFilter::new(&["NotAKind".to_string()], &[]).unwrap_err();
+        let ValidationAppError::InvalidCommandLine(message) = err else {
+            panic!("unexpected error {:?}", err);
+        };
+        assert!(message.contains("NotAKind"));
+        assert!(message.contains("OverlappingMemoryRanges"));
+
+        let unique: BTreeSet<_> = VALIDATION_KIND_NAMES.iter().collect();
+        assert_eq!(unique.len(), VALIDATION_KIND_NAMES.len());
+    }
+
+    #[test]
+    fn test_retain_kinds() {
+        let hob = ResourceDescriptorSerDe {
+            owner: "00000000-0000-0000-0000-000000000000".to_string(),
+            resource_type: 0,
+            resource_attribute: 0,
+            physical_start: 0x1000,
+            resource_length: 0x1000,
+        };
+
+        let mut report = ValidationReport::new();
+        report.add_violation(ValidationKind::Hob(HobValidationKind::ResourceRangeOverflow { hob1: &hob }));
+        report.add_violation(ValidationKind::Hob(HobValidationKind::V1MemoryRangeNotContainedInV2 { hob1: &hob }));
+
+        report.retain_kinds(&KindFilter::new(&[], &["ResourceRangeOverflow".to_string()]).unwrap());
+        assert_eq!(report.violation_count(), 1);
+        assert_eq!(report.to_json()["violations"][0]["name"], "V1MemoryRangeNotContainedInV2");
+    }
+
+    #[test]
+    fn test_iter_violations() {
+        let hob = ResourceDescriptorSerDe {
+            owner: "00000000-0000-0000-0000-000000000000".to_string(),
+            resource_type: 0,
+            resource_attribute: 0,
+            physical_start: 0x1000,
+            resource_length: 0x1000,
+        };
+
+        let mut report = ValidationReport::new();
+        assert_eq!(report.groups().count(), 0);
+        assert_eq!(report.iter_violations().count(), 0);
+
+        report.add_violation(ValidationKind::Hob(HobValidationKind::V1MemoryRangeNotContainedInV2 { hob1: &hob }));
+        report.add_violation(ValidationKind::Hob(HobValidationKind::ResourceRangeOverflow { hob1: &hob }));
+        report.add_violation(ValidationKind::Hob(HobValidationKind::ResourceRangeOverflow { hob1: &hob }));
+
+        assert_eq!(report.groups().collect::<Vec<_>>(), ["ResourceRangeOverflow", "V1MemoryRangeNotContainedInV2"]);
+
+        let violations: Vec<_> = report.iter_violations().collect();
+        assert_eq!(violations.len(), report.violation_count());
+        assert_eq!(violations[0].0, "ResourceRangeOverflow");
+        assert_eq!(violations[2].0, "V1MemoryRangeNotContainedInV2");
+        assert!(violations.iter().all(|(group_name, violation)| *group_name == violation.name()));
+    }
+
+    #[test]
+    fn test_fnv1a_64() {
+        // Reference values from the FNV specification
+        assert_eq!(fnv1a_64(b""), 0xcbf29ce484222325);
+        assert_eq!(fnv1a_64(b"a"), 0xaf63dc4c8601ec8c);
+        assert_eq!(fnv1a_64(b"foobar"), 0x85944171f73967e8);
+    }
+
+    #[test]
+    fn test_fingerprint_is_stable() {
+        let hob = ResourceDescriptorSerDe {
+            owner: "8C8CE578-8A3D-4F1C-9935-896185C32DD3".to_string(),
+            resource_type: 0,
+            resource_attribute: 0,
+            physical_start: 0x1000,
+            resource_length: 0x1000,
+        };
+        let lowercase = ResourceDescriptorSerDe { owner: hob.owner.to_lowercase(), ..hob.clone() };
+        let violation = ValidationKind::Hob(HobValidationKind::ResourceRangeOverflow { hob1: &hob });
+
+        // The same violation reported again, or with a differently cased GUID,
+        // keeps its fingerprint
+        assert_eq!(violation.fingerprint(), violation.fingerprint());
+        assert_eq!(
+            violation.fingerprint(),
+            ValidationKind::Hob(HobValidationKind::ResourceRangeOverflow { hob1: &lowercase }).fingerprint()
+        );
+
+        // The resolution text is not an identifying field
+        let details = violation.details();
+        assert!(details.contains_key("Violation/Resolution"));
+        let mut identifying = details.clone();
+        identifying.remove("Violation/Resolution");
+        let expected = fnv1a_64(Value::Object(identifying).to_string().to_lowercase().as_bytes());
+        assert_eq!(violation.fingerprint(), format!("ResourceRangeOverflow:{:016x}", expected));
+    }
+
+    #[test]
+    fn test_apply_baseline() {
+        let hob = ResourceDescriptorSerDe {
+            owner: "00000000-0000-0000-0000-000000000000".to_string(),
+            resource_type: 0,
+            resource_attribute: 0,
+            physical_start: 0x1000,
+            resource_length: 0x1000,
+        };
+        let other = ResourceDescriptorSerDe { physical_start: 0x2000, ..hob.clone() };
+
+        let accepted = ValidationKind::Hob(HobValidationKind::ResourceRangeOverflow { hob1: &hob });
+        let fingerprint = accepted.fingerprint();
+        assert!(fingerprint.starts_with("ResourceRangeOverflow:"));
+        assert_ne!(
+            fingerprint,
+            ValidationKind::Hob(HobValidationKind::ResourceRangeOverflow { hob1: &other }).fingerprint()
+        );
+
+        let baseline = Baseline::parse(&format!("# accepted\n\n  {}  \n", fingerprint));
+
+        let mut report = ValidationReport::new();
+        report.add_violation(accepted);
+        report.add_violation(ValidationKind::Hob(HobValidationKind::ResourceRangeOverflow { hob1: &other }));
+        report.apply_baseline(&baseline);
+
+        assert_eq!(report.violation_count(), 1);
+        assert_eq!(report.suppressed_count(), 1);
+        assert_eq!(report.to_json()["suppressed"][0], fingerprint.as_str());
+    }
+
+    #[test]
+    fn test_violation_counts_at() {
+        let hob = ResourceDescriptorSerDe {
+            owner: "00000000-0000-0000-0000-000000000000".to_string(),
+            resource_type: 0,
+            resource_attribute: 0,
+            physical_start: 0x1000,
+            resource_length: 0x1000,
+        };
+
+        let mut report = ValidationReport::new();
+        report.add_violation(ValidationKind::Hob(HobValidationKind::ResourceRangeOverflow { hob1: &hob }));
+        report.add_violation(ValidationKind::Hob(HobValidationKind::V1MemoryRangeNotContainedInV2 { hob1: &hob }));
+
+        assert_eq!(report.violation_counts_at(Severity::Info), ViolationCount { hob: 2, ..Default::default() });
+        assert_eq!(report.violation_counts_at(Severity::Warning), ViolationCount { hob: 2, ..Default::default() });
+        assert_eq!(report.violation_counts_at(Severity::Error), ViolationCount { hob: 1, ..Default::default() });
+        assert_eq!(report.violation_count(), 2);
+
+        let json = report.to_json();
+        assert_eq!(json["violations"][0]["severity"], "error");
+        assert_eq!(json["violations"][1]["severity"], "warning");
+        assert_eq!(report.to_sarif()["runs"][0]["results"][1]["level"], "warning");
+    }
+
+    #[test]
+    fn test_readiness_score() {
+        let hob = ResourceDescriptorSerDe {
+            owner: "00000000-0000-0000-0000-000000000000".to_string(),
+            resource_type: 0,
+            resource_attribute: 0,
+            physical_start: 0x1000,
+            resource_length: 0x1000,
+        };
+
+        let mut report = ValidationReport::new();
+        assert_eq!(report.readiness_score(), ReadinessScore { percent: 100.0, penalty: 0, contributions: vec![] });
+        assert!(report.to_json()["score"].is_null());
+
+        report.add_violation(ValidationKind::Hob(HobValidationKind::V1MemoryRangeNotContainedInV2 { hob1: &hob }));
+        report.add_violation(ValidationKind::Hob(HobValidationKind::ResourceRangeOverflow { hob1: &hob }));
+        report.add_violation(ValidationKind::Hob(HobValidationKind::ResourceRangeOverflow { hob1: &hob }));
+        let score = report.readiness_score();
+        assert_eq!(score.penalty, 23);
+        assert_eq!(score.percent, 81.3);
+        assert_eq!(score.contributions[0].name, "ResourceRangeOverflow");
+        assert_eq!(score.contributions[0].penalty, 20);
+        assert_eq!(score.contributions[1].penalty, 3);
+
+        report.set_show_score(true);
+        let json = report.to_json();
+        assert_eq!(json["score"]["percent"], 81.3);
+        assert_eq!(json["score"]["contributions"][0]["severity"], "error");
+
+        let mut out = Vec::new();
+        report.show_results(OutputFormat::Text, false, &mut out).unwrap();
+        assert!(String::from_utf8(out).unwrap().contains("Readiness Score: 81.3% (23 penalty points)"));
+    }
+
+    #[test]
+    fn test_explain() {
+        let hob = ResourceDescriptorSerDe {
+            owner: "00000000-0000-0000-0000-000000000000".to_string(),
+            resource_type: 0,
+            resource_attribute: 0,
+            physical_start: 0x1000,
+            resource_length: 0x1000,
+        };
+        let violation = ValidationKind::Hob(HobValidationKind::OverlappingMemoryRanges { hob1: &hob, hob2: &hob });
+        assert_eq!(violation.explain()[1]["physical_start"], "0x1000");
+        let violation = ValidationKind::Hob(HobValidationKind::MissingOrDuplicateHandoff { count: 2 });
+        assert_eq!(Value::Object(violation.details()), violation.explain());
+
+        let mut report = ValidationReport::new();
+        report.add_violation(ValidationKind::Hob(HobValidationKind::ResourceRangeOverflow { hob1: &hob }));
+        let mut out = Vec::new();
+        report.show_results(OutputFormat::Text, false, &mut out).unwrap();
+        assert!(!String::from_utf8(out).unwrap().contains("Explain #1:"));
+
+        report.set_explain(true);
+        let mut out = Vec::new();
+        report.show_results(OutputFormat::Text, false, &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert!(text.contains("Explain #1:"));
+        assert!(text.contains("\"physical_start\": \"0x1000\""));
+        assert!(text.contains("Guidance:"));
+    }
+
+    #[test]
+    fn test_show_results_text() {
+        let hob = ResourceDescriptorSerDe {
+            owner: "00000000-0000-0000-0000-000000000000".to_string(),
+            resource_type: 0,
+            resource_attribute: 0,
+            physical_start: 0x1000,
+            resource_length: 0x1000,
+        };
+
+        let mut out = Vec::new();
+        let mut report = ValidationReport::new();
+        report.show_results(OutputFormat::Text, false, &mut out).unwrap();
+        assert!(String::from_utf8(out).unwrap().contains("No violations found."));
+
+        let mut out = Vec::new();
+        report.add_violation(ValidationKind::Hob(HobValidationKind::ResourceRangeOverflow { hob1: &hob }));
+        report.show_results(OutputFormat::Text, false, &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert!(text.contains("Validation Results:"));
+        assert!(text.contains("Guidance:"));
+
+        let mut out = Vec::new();
+        report.show_results(OutputFormat::Json, false, &mut out).unwrap();
+        assert_eq!(serde_json::from_slice::<Value>(&out).unwrap(), report.to_json());
+
+        let mut out = Vec::new();
+        report.add_violation(ValidationKind::Hob(HobValidationKind::ResourceRangeOverflow { hob1: &hob }));
+        report.show_results(OutputFormat::Text, true, &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert!(text.contains("ResourceRangeOverflow: 2"));
+        assert!(text.contains("Total: 2"));
+        assert!(!text.contains("Guidance:"));
+
+        let mut out = Vec::new();
+        report.add_note(ValidationAppError::EmptyFvList.to_string());
+        report.show_results(OutputFormat::Text, false, &mut out).unwrap();
+        assert!(String::from_utf8(out).unwrap().contains("Note: The FV list is empty."));
+        assert_eq!(report.to_json()["notes"][0], "The FV list is empty.");
+        assert!(report.to_markdown().contains("> **Note:** The FV list is empty."));
+    }
+
+    #[test]
+    fn test_xml_escape() {
+        assert_eq!(xml_escape("<a href=\"x\">'&'</a>"), "&lt;a href=&quot;x&quot;&gt;&apos;&amp;&apos;&lt;/a&gt;");
+    }
+
+    #[test]
+    fn test_metadata() {
+        let metadata = CaptureMetadata::new("QEMU Q35", None, Some("Boot On S3 Resume (0x11)".to_string()));
+        let mut report = ValidationReport::new();
+        assert!(report.to_json()["metadata"].is_null());
+
+        report.set_metadata(Some(&metadata));
+        assert_eq!(report.to_json()["metadata"]["platform_name"], "QEMU Q35");
+
+        let mut out = Vec::new();
+        report.show_results(OutputFormat::Text, false, &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert!(text.contains("Capture Metadata:"));
+        assert!(text.contains("QEMU Q35"));
+        assert!(report.to_markdown().contains("| Platform | QEMU Q35 |"));
+        assert!(report.to_markdown().contains("| Boot Mode | Boot On S3 Resume (0x11) |"));
+        assert_eq!(report.to_json()["metadata"]["boot_mode"], "Boot On S3 Resume (0x11)");
     }
 }
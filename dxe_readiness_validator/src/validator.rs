@@ -13,7 +13,7 @@ use crate::validate::ValidationResult;
 ///
 /// Types implementing this trait are expected to define domain-specific
 /// validation logic and return the outcome as a [`ValidationResult`] object.
-pub trait Validator {
+pub trait Validator<'a> {
     /// Executes the validation logic and returns a [`ValidationResult`] object.
-    fn validate(&self) -> ValidationResult<'_>;
+    fn validate(&self) -> ValidationResult<'a>;
 }